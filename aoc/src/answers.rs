@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use aoc_core::{read_input, InputError, Part};
+use clap::ValueEnum;
+use thiserror::Error;
+
+use crate::registry::{self, Author, RegistryError};
+
+/// One line of the answers file: what `author`'s solution to `part` of `day`
+/// must print for the puzzle input at `input`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpectedAnswer {
+    pub author: Author,
+    pub day: u8,
+    pub part: Part,
+    /// Relative to the directory holding the answers file.
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+impl fmt::Display for ExpectedAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {} part {} on {}",
+            self.author,
+            self.day,
+            self.part,
+            self.input.display()
+        )
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    #[error("Answers file not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("line {line}: expected `author day part input answer`")]
+    MissingField { line: usize },
+    #[error("line {line}: unknown author {author}")]
+    UnknownAuthor { line: usize, author: String },
+    #[error("line {line}: invalid day {day}")]
+    InvalidDay { line: usize, day: String },
+    #[error("line {line}: {message}")]
+    InvalidPart { line: usize, message: String },
+    #[error("line {line}: duplicates the answer on line {first}")]
+    Duplicate { line: usize, first: usize },
+    #[error("{expected} is already recorded as {recorded}, not {}", .expected.answer)]
+    Conflict {
+        expected: ExpectedAnswer,
+        recorded: String,
+    },
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+#[derive(Error, Debug)]
+pub enum CheckError {
+    #[error("expected {expected} but got {found}")]
+    Mismatch { expected: String, found: String },
+    #[error(transparent)]
+    NotRegistered(#[from] RegistryError),
+    #[error(transparent)]
+    Input(#[from] InputError),
+    #[error("{0}")]
+    Failed(String),
+    #[error("solver panicked")]
+    Panicked,
+}
+
+pub fn load(path: &Path) -> Result<Vec<ExpectedAnswer>, AnswersError> {
+    parse(&read_input(path)?)
+}
+
+/// Parses one answer per line as `author day part input answer`. Blank lines
+/// and lines starting with `#` are skipped, and everything after the input
+/// path is the answer.
+pub fn parse(text: &str) -> Result<Vec<ExpectedAnswer>, AnswersError> {
+    let mut answers = Vec::new();
+    let mut seen: HashMap<(Author, u8, Part, PathBuf), usize> = HashMap::new();
+
+    for (index, raw) in text.lines().enumerate() {
+        let line = index + 1;
+        let raw = raw.trim();
+        if raw.is_empty() || raw.starts_with('#') {
+            continue;
+        }
+
+        let mut rest = raw;
+        let mut next = || {
+            let (field, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            rest = tail.trim_start();
+            Some(field)
+                .filter(|field| !field.is_empty())
+                .ok_or(AnswersError::MissingField { line })
+        };
+        let (author, day, part, input) = (next()?, next()?, next()?, next()?);
+        let answer = Some(rest)
+            .filter(|answer| !answer.is_empty())
+            .ok_or(AnswersError::MissingField { line })?;
+
+        let author = Author::from_str(author, true).map_err(|_| AnswersError::UnknownAuthor {
+            line,
+            author: author.to_owned(),
+        })?;
+        let day = day
+            .parse::<u8>()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| AnswersError::InvalidDay {
+                line,
+                day: day.to_owned(),
+            })?;
+        let part = part
+            .parse::<Part>()
+            .map_err(|message| AnswersError::InvalidPart { line, message })?;
+        let input = PathBuf::from(input);
+
+        if let Some(&first) = seen.get(&(author, day, part, input.clone())) {
+            return Err(AnswersError::Duplicate { line, first });
+        }
+        seen.insert((author, day, part, input.clone()), line);
+
+        answers.push(ExpectedAnswer {
+            author,
+            day,
+            part,
+            input,
+            answer: answer.to_owned(),
+        });
+    }
+
+    Ok(answers)
+}
+
+/// The answer the file at `path` has for the same author, day, part and
+/// input as `answer`, if any. A missing file has none.
+pub fn recorded(path: &Path, answer: &ExpectedAnswer) -> Result<Option<String>, AnswersError> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    Ok(parse(&text)?
+        .into_iter()
+        .find(|expected| {
+            (
+                expected.author,
+                expected.day,
+                expected.part,
+                &expected.input,
+            ) == (answer.author, answer.day, answer.part, &answer.input)
+        })
+        .map(|expected| expected.answer))
+}
+
+/// Adds `answer` to the end of the file at `path`, unless it's there
+/// already. A different answer for the same input is an error, rather than
+/// a second line.
+pub fn record(path: &Path, answer: &ExpectedAnswer) -> Result<(), AnswersError> {
+    match recorded(path, answer)? {
+        Some(recorded) if recorded == answer.answer => return Ok(()),
+        Some(recorded) => {
+            return Err(AnswersError::Conflict {
+                expected: answer.clone(),
+                recorded,
+            })
+        }
+        None => {}
+    }
+
+    let mut text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err.into()),
+    };
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text.push_str(&format!(
+        "{} {} {} {} {}\n",
+        answer.author,
+        answer.day,
+        answer.part,
+        answer.input.display(),
+        answer.answer
+    ));
+    Ok(fs::write(path, text)?)
+}
+
+/// Runs the registered solver on the stored input, resolving the input path
+/// against `root`.
+pub fn check(expected: &ExpectedAnswer, root: &Path) -> Result<(), CheckError> {
+    let solver = registry::find(expected.author, expected.day, expected.part)?;
+    let input_data = read_input(&root.join(&expected.input))?;
+
+    let found = panic::catch_unwind(AssertUnwindSafe(|| {
+        solver.solve(expected.part, &input_data)
+    }))
+    .map_err(|_| CheckError::Panicked)?
+    .map_err(|err| CheckError::Failed(err.to_string()))?
+    .to_string();
+
+    if found == expected.answer {
+        Ok(())
+    } else {
+        Err(CheckError::Mismatch {
+            expected: expected.answer.clone(),
+            found,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn parses_answers_file() {
+        let text = "# author day part input answer\n\nlethalgem 1 1 lethalgem/Day_1/part_1/src/input.txt 54632\nmohammad 14 2  mohammad/day14/src/input_small   64\n";
+        let answers = parse(text).unwrap();
+        assert_eq!(
+            answers,
+            vec![
+                ExpectedAnswer {
+                    author: Author::Lethalgem,
+                    day: 1,
+                    part: Part::One,
+                    input: PathBuf::from("lethalgem/Day_1/part_1/src/input.txt"),
+                    answer: "54632".to_owned(),
+                },
+                ExpectedAnswer {
+                    author: Author::Mohammad,
+                    day: 14,
+                    part: Part::Two,
+                    input: PathBuf::from("mohammad/day14/src/input_small"),
+                    answer: "64".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let error = |text| parse(text).unwrap_err().to_string();
+        assert_eq!(
+            error("lethalgem 1 1 input.txt"),
+            "line 1: expected `author day part input answer`"
+        );
+        assert_eq!(
+            error("\nnobody 1 1 input.txt 3"),
+            "line 2: unknown author nobody"
+        );
+        assert_eq!(error("mohammad 26 1 input.txt 3"), "line 1: invalid day 26");
+        assert_eq!(
+            error("mohammad 1 3 input.txt 3"),
+            "line 1: part must be 1 or 2, got 3"
+        );
+        assert_eq!(
+            error("mohammad 1 1 input.txt 3\nmohammad 1 1 input.txt 4"),
+            "line 2: duplicates the answer on line 1"
+        );
+    }
+
+    #[test]
+    fn records_new_answers_once() {
+        let dir = env::temp_dir().join(format!("aoc-answers-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("answers.txt");
+        fs::write(&path, "# author day part input answer").unwrap();
+
+        let mut answer = ExpectedAnswer {
+            author: Author::Mohammad,
+            day: 3,
+            part: Part::Two,
+            input: PathBuf::from("inputs/day03.txt"),
+            answer: "467835".to_owned(),
+        };
+        assert_eq!(recorded(&path, &answer).unwrap(), None);
+        record(&path, &answer).unwrap();
+        record(&path, &answer).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# author day part input answer\nmohammad 3 2 inputs/day03.txt 467835\n"
+        );
+        assert_eq!(load(&path).unwrap(), vec![answer.clone()]);
+
+        answer.answer = "1".to_owned();
+        assert_eq!(
+            record(&path, &answer).unwrap_err().to_string(),
+            "mohammad day 3 part 2 on inputs/day03.txt is already recorded as 467835, not 1"
+        );
+        assert_eq!(recorded(&dir.join("none.txt"), &answer).unwrap(), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn stored_answers_match() {
+        let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"));
+        let root = path.parent().unwrap();
+        let failures: Vec<String> = load(path)
+            .unwrap()
+            .iter()
+            .filter_map(|expected| {
+                check(expected, root)
+                    .err()
+                    .map(|err| format!("{}: {}", expected, err))
+            })
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
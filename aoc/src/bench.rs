@@ -0,0 +1,283 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_core::{Part, Solver};
+use serde::{Serialize, Serializer};
+
+use crate::registry::{Author, Entry};
+
+/// Once a solution has spent this long on an input it isn't run again, so
+/// slow solutions are measured on fewer runs.
+const BUDGET: Duration = Duration::from_secs(1);
+
+/// How one author's solution fared on one input. Times are the fastest parse
+/// and the fastest solve over all runs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Measurement {
+    #[serde(serialize_with = "author_name")]
+    pub author: Author,
+    pub day: u8,
+    #[serde(serialize_with = "part_number")]
+    pub part: Part,
+    pub input: String,
+    pub runs: u32,
+    pub answer: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+pub(crate) fn author_name<S: Serializer>(
+    author: &Author,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(author)
+}
+
+pub(crate) fn part_number<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(match part {
+        Part::One => 1,
+        Part::Two => 2,
+    })
+}
+
+impl Measurement {
+    pub fn total_ns(&self) -> Option<u64> {
+        Some(self.parse_ns? + self.solve_ns?)
+    }
+}
+
+/// Runs `entry` on `input` up to `runs` times, giving up if it is still
+/// running after `timeout`. A solution that times out keeps running in the
+/// background until the process exits.
+pub fn measure(
+    entry: &Entry,
+    input: &str,
+    label: &str,
+    runs: u32,
+    timeout: Duration,
+) -> Measurement {
+    let (sender, receiver) = mpsc::channel();
+    let solver = entry.solver;
+    let part = entry.part;
+    let input_data: Arc<str> = Arc::from(input);
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            time_runs(solver, part, &input_data, runs)
+        }))
+        .unwrap_or_else(|_| Err("solver panicked".to_owned()));
+        // The receiver is gone if we already gave up on this solver.
+        let _ = sender.send(result);
+    });
+
+    let mut measurement = Measurement {
+        author: entry.author,
+        day: entry.day,
+        part: entry.part,
+        input: label.to_owned(),
+        runs: 0,
+        answer: None,
+        parse_ns: None,
+        solve_ns: None,
+        error: None,
+    };
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok((answer, runs, parse, solve))) => {
+            measurement.runs = runs;
+            measurement.answer = Some(answer);
+            measurement.parse_ns = Some(parse.as_nanos() as u64);
+            measurement.solve_ns = Some(solve.as_nanos() as u64);
+        }
+        Ok(Err(err)) => measurement.error = Some(err),
+        Err(_) => measurement.error = Some(format!("timed out after {:?}", timeout)),
+    }
+    measurement
+}
+
+fn time_runs(
+    solver: &dyn Solver,
+    part: Part,
+    input: &str,
+    runs: u32,
+) -> Result<(String, u32, Duration, Duration), String> {
+    let start = Instant::now();
+    let mut answer = String::new();
+    let mut parse = Duration::MAX;
+    let mut solve = Duration::MAX;
+    let mut done = 0;
+    while done < runs.max(1) && (done == 0 || start.elapsed() < BUDGET) {
+        let timed = solver
+            .solve_timed(part, input)
+            .map_err(|err| err.to_string())?;
+        answer = timed.answer.to_string();
+        parse = parse.min(timed.parse);
+        solve = solve.min(timed.solve);
+        done += 1;
+    }
+    Ok((answer, done, parse, solve))
+}
+
+/// Every author's measurement for one part of a day on one input, fastest
+/// first.
+pub struct Table<'a> {
+    pub measurements: Vec<&'a Measurement>,
+}
+
+impl<'a> Table<'a> {
+    /// Groups `measurements` by day, part and input, keeping their order.
+    pub fn group(measurements: &'a [Measurement]) -> Vec<Table<'a>> {
+        let mut tables: Vec<Table> = Vec::new();
+        for measurement in measurements {
+            let same_run = |table: &Table| {
+                let first = table.measurements[0];
+                (first.day, first.part, &first.input)
+                    == (measurement.day, measurement.part, &measurement.input)
+            };
+            match tables.iter_mut().find(|table| same_run(table)) {
+                Some(table) => table.measurements.push(measurement),
+                None => tables.push(Table {
+                    measurements: vec![measurement],
+                }),
+            }
+        }
+        for table in &mut tables {
+            table
+                .measurements
+                .sort_by_key(|measurement| measurement.total_ns().unwrap_or(u64::MAX));
+        }
+        tables
+    }
+}
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let first = self.measurements[0];
+        write!(
+            f,
+            "day {} part {} on {}",
+            first.day, first.part, first.input
+        )?;
+
+        let fastest = first.total_ns();
+        for measurement in &self.measurements {
+            write!(f, "\n    {:<14}", measurement.author.to_string())?;
+            match (
+                measurement.parse_ns,
+                measurement.solve_ns,
+                &measurement.error,
+            ) {
+                (Some(parse), Some(solve), _) => {
+                    let nanos = |ns| format!("{:.1?}", Duration::from_nanos(ns));
+                    write!(
+                        f,
+                        " parse {:>10}  solve {:>10}  total {:>10}",
+                        nanos(parse),
+                        nanos(solve),
+                        nanos(parse + solve)
+                    )?;
+                    if let Some(fastest) = fastest.filter(|&fastest| fastest > 0) {
+                        write!(f, "  {:>8.1}x", (parse + solve) as f64 / fastest as f64)?;
+                    }
+                }
+                (_, _, Some(err)) => write!(f, " {}", err)?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Answer, Solution, SolutionError};
+
+    use super::*;
+
+    struct SlowParse;
+
+    impl Solution for SlowParse {
+        type Input<'a> = &'a str;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+            thread::sleep(Duration::from_millis(20));
+            Ok(input)
+        }
+
+        fn part_one(&self, input: Self::Input<'_>) -> Result<Answer, SolutionError> {
+            Ok(input.len().into())
+        }
+
+        fn part_two(&self, _input: Self::Input<'_>) -> Result<Answer, SolutionError> {
+            thread::sleep(Duration::from_secs(5));
+            Ok(0.into())
+        }
+    }
+
+    fn entry(author: Author, part: Part) -> Entry {
+        Entry {
+            author,
+            day: 1,
+            part,
+            solver: &SlowParse,
+        }
+    }
+
+    #[test]
+    fn times_parse_and_solve_separately() {
+        let entry = entry(Author::Mohammad, Part::One);
+        let measurement = measure(&entry, "abc", "example", 3, Duration::from_secs(10));
+        assert_eq!(measurement.answer.as_deref(), Some("3"));
+        assert_eq!(measurement.runs, 3);
+        assert!(measurement.parse_ns.unwrap() >= 20_000_000);
+        assert!(measurement.solve_ns.unwrap() < measurement.parse_ns.unwrap());
+
+        let json = serde_json::to_string(&measurement).unwrap();
+        assert!(json.starts_with(
+            r#"{"author":"mohammad","day":1,"part":1,"input":"example","runs":3,"answer":"3","#
+        ));
+    }
+
+    #[test]
+    fn reports_timeouts_after_finished_solutions() {
+        let timed_out = measure(
+            &entry(Author::Lethalgem, Part::Two),
+            "",
+            "example",
+            1,
+            Duration::from_millis(50),
+        );
+        assert_eq!(timed_out.error.as_deref(), Some("timed out after 50ms"));
+
+        let finished = |author, parse_ns, solve_ns| Measurement {
+            author,
+            runs: 1,
+            answer: Some("0".to_owned()),
+            parse_ns: Some(parse_ns),
+            solve_ns: Some(solve_ns),
+            error: None,
+            ..timed_out.clone()
+        };
+        let measurements = [
+            timed_out.clone(),
+            finished(Author::Mattdamachine, 2_000, 28_000),
+            finished(Author::Mohammad, 1_000, 2_000),
+            Measurement {
+                input: "other".to_owned(),
+                ..finished(Author::Mohammad, 1_000, 2_000)
+            },
+        ];
+
+        let tables = Table::group(&measurements);
+        assert_eq!(tables.len(), 2);
+        assert_eq!(
+            tables[0].to_string(),
+            "day 1 part 2 on example
+    mohammad       parse      1.0µs  solve      2.0µs  total      3.0µs       1.0x
+    mattdamachine  parse      2.0µs  solve     28.0µs  total     30.0µs      10.0x
+    lethalgem      timed out after 50ms"
+        );
+    }
+}
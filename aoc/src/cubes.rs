@@ -0,0 +1,90 @@
+use std::error::Error;
+use std::path::Path;
+
+use clap::Subcommand;
+
+use aoc_core::read_input;
+use lethalgem_day02_part2::{extract_games, query, Day2Error, Game, Set};
+
+#[derive(Subcommand)]
+pub enum Query {
+    /// Which games become possible with more cubes of one color
+    Add {
+        #[arg(long)]
+        color: String,
+        #[arg(long)]
+        cubes: u32,
+    },
+    /// The bag with the fewest cubes that makes at least this many games possible
+    Smallest {
+        #[arg(long)]
+        games: usize,
+    },
+    /// The game needing the most cubes of each color
+    Bottlenecks,
+}
+
+pub fn load_games(input: &Path) -> Result<Vec<Game>, Box<dyn Error>> {
+    extract_games(read_input(input)?).map_err(|err| match err {
+        Day2Error::Malformed(diagnostic) => diagnostic.in_file(input).into(),
+        err => err.into(),
+    })
+}
+
+pub fn answer(games: &[Game], bag: &Set, query: Query) -> Vec<String> {
+    match query {
+        Query::Add { color, cubes } => {
+            let ids = query::newly_possible(games, bag, &color, cubes);
+            if ids.is_empty() {
+                return vec![format!("no more games with {} more {}", cubes, color)];
+            }
+            ids.iter().map(|id| format!("game {}", id)).collect()
+        }
+        Query::Smallest { games: at_least } => match query::smallest_bag(games, at_least) {
+            Some(bag) => vec![bag.to_string()],
+            None => vec![format!("there are only {} games", games.len())],
+        },
+        Query::Bottlenecks => query::bottlenecks(games)
+            .iter()
+            .map(|found| {
+                format!(
+                    "{}: game {} needs {}",
+                    found.color, found.game_id, found.count
+                )
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_queries_on_the_example() {
+        let example = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../lethalgem/day_2/part_2/src/example_1.txt"
+        );
+        let games = load_games(Path::new(example)).unwrap();
+        let bag = Set::default_bag();
+
+        let add = Query::Add {
+            color: "red".to_owned(),
+            cubes: 8,
+        };
+        assert_eq!(answer(&games, &bag, add), vec!["game 3"]);
+        assert_eq!(
+            answer(&games, &bag, Query::Smallest { games: 9 }),
+            vec!["there are only 5 games"]
+        );
+        assert_eq!(
+            answer(&games, &bag, Query::Bottlenecks),
+            vec![
+                "blue: game 4 needs 15",
+                "green: game 3 needs 13",
+                "red: game 3 needs 20"
+            ]
+        );
+    }
+}
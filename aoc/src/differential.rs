@@ -0,0 +1,274 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_core::Part;
+
+use crate::answers::ExpectedAnswer;
+use crate::registry::{Author, Entry, SOLUTIONS};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Answer(String),
+    Failed(String),
+    Panicked,
+    TimedOut,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{}", answer),
+            Outcome::Failed(err) => write!(f, "error: {}", err),
+            Outcome::Panicked => write!(f, "panicked"),
+            Outcome::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+/// What each author's solution to one part of a day produced for the same
+/// input.
+#[derive(Debug)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: Part,
+    pub outcomes: Vec<(Author, Outcome)>,
+}
+
+impl Comparison {
+    /// Solutions that timed out are left out, so a slow solution can't hide
+    /// or cause a disagreement.
+    pub fn agrees(&self) -> bool {
+        let mut finished = self
+            .outcomes
+            .iter()
+            .map(|(_, outcome)| outcome)
+            .filter(|outcome| **outcome != Outcome::TimedOut);
+        match finished.next() {
+            Some(first) => finished.all(|outcome| outcome == first),
+            None => true,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} part {}", self.day, self.part)?;
+        for (author, outcome) in &self.outcomes {
+            write!(f, "\n    {:<14} {}", author.to_string(), outcome)?;
+        }
+        Ok(())
+    }
+}
+
+/// Days with more than one author's solution to `part`.
+pub fn shared_days(part: Part) -> Vec<u8> {
+    let mut days: Vec<u8> = SOLUTIONS
+        .iter()
+        .filter(|entry| entry.part == part)
+        .map(|entry| entry.day)
+        .filter(|&day| authors(day, part) > 1)
+        .collect();
+    days.sort();
+    days.dedup();
+    days
+}
+
+fn authors(day: u8, part: Part) -> usize {
+    SOLUTIONS
+        .iter()
+        .filter(|entry| entry.day == day && entry.part == part)
+        .count()
+}
+
+/// Every input with a stored answer for `part` of `day`, whoever's it is.
+pub fn stored_inputs(expected_answers: &[ExpectedAnswer], day: u8, part: Part) -> Vec<PathBuf> {
+    let mut inputs: Vec<PathBuf> = expected_answers
+        .iter()
+        .filter(|expected| expected.day == day && expected.part == part)
+        .map(|expected| expected.input.clone())
+        .collect();
+    inputs.sort();
+    inputs.dedup();
+    inputs
+}
+
+/// Runs every registered solution to `part` of `day` on `input`, each on its
+/// own thread, and gives up on any still running after `timeout`.
+pub fn compare(day: u8, part: Part, input: &str, timeout: Duration) -> Comparison {
+    let entries = SOLUTIONS
+        .iter()
+        .filter(|entry| entry.day == day && entry.part == part);
+    compare_entries(day, part, entries, input, timeout)
+}
+
+fn compare_entries<'a>(
+    day: u8,
+    part: Part,
+    entries: impl Iterator<Item = &'a Entry>,
+    input: &str,
+    timeout: Duration,
+) -> Comparison {
+    let input: Arc<str> = Arc::from(input);
+    let running: Vec<_> = entries
+        .map(|entry| {
+            let (sender, receiver) = mpsc::channel();
+            let solver = entry.solver;
+            let input = Arc::clone(&input);
+            thread::spawn(move || {
+                let outcome =
+                    match panic::catch_unwind(AssertUnwindSafe(|| solver.solve(part, &input))) {
+                        Ok(Ok(answer)) => Outcome::Answer(answer.to_string()),
+                        Ok(Err(err)) => Outcome::Failed(err.to_string()),
+                        Err(_) => Outcome::Panicked,
+                    };
+                // The receiver is gone if we already gave up on this solver.
+                let _ = sender.send(outcome);
+            });
+            (entry.author, receiver)
+        })
+        .collect();
+
+    let deadline = Instant::now() + timeout;
+    let outcomes = running
+        .into_iter()
+        .map(|(author, receiver)| {
+            let outcome = receiver
+                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                .unwrap_or(Outcome::TimedOut);
+            (author, outcome)
+        })
+        .collect();
+
+    Comparison {
+        day,
+        part,
+        outcomes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc_core::{Answer, Solution, SolutionError};
+
+    use super::*;
+    use crate::answers;
+
+    struct Length;
+
+    impl Solution for Length {
+        type Input<'a> = &'a str;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+            Ok(input)
+        }
+
+        fn part_one(&self, input: Self::Input<'_>) -> Result<Answer, SolutionError> {
+            Ok(input.len().into())
+        }
+    }
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        type Input<'a> = &'a str;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+            Ok(input)
+        }
+
+        fn part_one(&self, input: Self::Input<'_>) -> Result<Answer, SolutionError> {
+            Ok(input.lines().count().into())
+        }
+    }
+
+    static ENTRIES: &[Entry] = &[
+        Entry {
+            author: Author::Lethalgem,
+            day: 1,
+            part: Part::One,
+            solver: &Length,
+        },
+        Entry {
+            author: Author::Mohammad,
+            day: 1,
+            part: Part::One,
+            solver: &LineCount,
+        },
+    ];
+
+    #[test]
+    fn reports_disagreement() {
+        let timeout = Duration::from_secs(10);
+        let comparison = compare_entries(1, Part::One, ENTRIES.iter(), "a", timeout);
+        assert!(comparison.agrees());
+
+        let comparison = compare_entries(1, Part::One, ENTRIES.iter(), "ab\ncd", timeout);
+        assert!(!comparison.agrees());
+        assert_eq!(
+            comparison.to_string(),
+            "day 1 part 1\n    lethalgem      5\n    mohammad       2"
+        );
+    }
+
+    #[test]
+    fn ignores_timed_out_solutions() {
+        let comparison = Comparison {
+            day: 1,
+            part: Part::One,
+            outcomes: vec![
+                (Author::Lethalgem, Outcome::Answer("1".to_owned())),
+                (Author::Mohammad, Outcome::TimedOut),
+            ],
+        };
+        assert!(comparison.agrees());
+    }
+
+    #[test]
+    fn authors_agree_on_stored_inputs() {
+        let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"));
+        let root = path.parent().unwrap();
+        let expected_answers = answers::load(path).unwrap();
+
+        let mut disagreements = Vec::new();
+        for part in [Part::One, Part::Two] {
+            for day in shared_days(part) {
+                for input in stored_inputs(&expected_answers, day, part) {
+                    let input_data = aoc_core::read_input(&root.join(&input)).unwrap();
+                    let comparison = compare(day, part, &input_data, Duration::from_secs(10));
+                    if !comparison.agrees() {
+                        disagreements.push(format!("on {}: {}", input.display(), comparison));
+                    }
+                }
+            }
+        }
+        assert!(disagreements.is_empty(), "{}", disagreements.join("\n"));
+    }
+
+    #[test]
+    fn authors_agree_on_generated_inputs() {
+        let mut problems = Vec::new();
+        for generator in aoc_gen::GENERATORS {
+            let size = 10.clamp(generator.min_size, generator.max_size);
+            for seed in 0..3 {
+                let input_data = aoc_gen::generate(generator.day, seed, Some(size)).unwrap();
+                for part in [Part::One, Part::Two] {
+                    let comparison =
+                        compare(generator.day, part, &input_data, Duration::from_secs(10));
+                    let failed = comparison.outcomes.iter().any(|(_, outcome)| {
+                        matches!(outcome, Outcome::Failed(_) | Outcome::Panicked)
+                    });
+                    if failed || !comparison.agrees() {
+                        problems.push(format!("on seed {} size {}: {}", seed, size, comparison));
+                    }
+                }
+            }
+        }
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+    }
+}
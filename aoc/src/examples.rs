@@ -0,0 +1,397 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::Part;
+use thiserror::Error;
+
+use crate::registry::Author;
+use crate::scaffold::{self, NewCrate, ScaffoldError};
+
+const TEST_TEMPLATE: &str = include_str!("../templates/examples_test.rs.tmpl");
+
+#[derive(Error, Debug)]
+pub enum ExamplesError {
+    #[error("no example in {}; save the puzzle page once its description is showing", .0.display())]
+    NoExamples(PathBuf),
+    #[error("{author} has no crates for day {day}; create them with `aoc new` first")]
+    NoCrates { author: Author, day: u8 },
+    #[error("{} already holds something else; pass --force to replace it", .0.display())]
+    WouldOverwrite(PathBuf),
+    #[error(transparent)]
+    Scaffold(#[from] ScaffoldError),
+}
+
+/// The example a part's description works through, and the answer it gives
+/// if the page emphasises one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+/// Finds each part's example in a saved puzzle page. Each part is described
+/// in its own `<article>`; the example is the last `<pre><code>` block before
+/// the answer, which is the last emphasised `<code>` in the article. A part
+/// without a block of its own reuses the one before.
+pub fn extract(page: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+    for (article, part) in articles(page).into_iter().zip([Part::One, Part::Two]) {
+        let answer = last_between(
+            article,
+            &[
+                ("<code><em>", "</em></code>"),
+                ("<em><code>", "</code></em>"),
+            ],
+        );
+        let before_answer = answer.as_ref().map_or(article, |(at, _)| &article[..*at]);
+        let input = last_between(before_answer, &[("<pre><code>", "</code></pre>")])
+            .map(|(_, input)| input)
+            .or_else(|| examples.last().map(|example| example.input.clone()));
+
+        if let Some(input) = input {
+            examples.push(Example {
+                part,
+                input,
+                answer: answer.map(|(_, answer)| answer),
+            });
+        }
+    }
+    examples
+}
+
+/// The descriptions of each part, or the whole page if it has no articles.
+fn articles(page: &str) -> Vec<&str> {
+    let articles: Vec<&str> = page
+        .split("<article")
+        .skip(1)
+        .filter_map(|rest| rest.split_once('>'))
+        .map(|(_, rest)| rest.split_once("</article>").map_or(rest, |(body, _)| body))
+        .collect();
+    if articles.is_empty() {
+        vec![page]
+    } else {
+        articles
+    }
+}
+
+/// The text of the last element opened and closed by any of `tags`, with
+/// where it starts.
+fn last_between(html: &str, tags: &[(&str, &str)]) -> Option<(usize, String)> {
+    tags.iter()
+        .filter_map(|(open, close)| {
+            let at = html.rfind(open)?;
+            let inner = &html[at + open.len()..];
+            let inner = inner.split_once(close).map_or(inner, |(inner, _)| inner);
+            Some((at, text(inner)))
+        })
+        .max_by_key(|(at, _)| *at)
+}
+
+/// Strips the markup inside an element, such as the `<em>` the site uses to
+/// highlight parts of an example, and decodes entities.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// `author`'s crates for `day`, as the runner depends on them.
+pub fn day_crates(root: &Path, author: Author, day: u8) -> Result<Vec<NewCrate>, ExamplesError> {
+    let manifest = scaffold::read(&root.join("aoc/Cargo.toml"))?;
+    let prefix = format!("{}_day{:02}", author, day);
+    let crates: Vec<NewCrate> = manifest
+        .lines()
+        .filter_map(|line| {
+            let (name, dependency) = line.split_once('=')?;
+            let name = name.trim();
+            if !name.starts_with(&prefix) {
+                return None;
+            }
+            let (_, dir) = dependency.split_once("path = \"../")?;
+            let (dir, _) = dir.split_once('"')?;
+            let parts = match name.strip_prefix(&prefix)? {
+                "_part1" => vec![Part::One],
+                "_part2" => vec![Part::Two],
+                "" => vec![Part::One, Part::Two],
+                _ => return None,
+            };
+            Some(NewCrate {
+                dir: dir.to_owned(),
+                name: name.to_owned(),
+                parts,
+            })
+        })
+        .collect();
+
+    if crates.is_empty() {
+        return Err(ExamplesError::NoCrates { author, day });
+    }
+    Ok(crates)
+}
+
+/// Writes the examples from `page` into `author`'s crates for `day`, and a
+/// test per part checking the solution gets the example's answer. Returns
+/// the files written, relative to `root`.
+pub fn write_examples(
+    root: &Path,
+    page: &Path,
+    author: Author,
+    day: u8,
+    force: bool,
+) -> Result<Vec<PathBuf>, ExamplesError> {
+    let examples = extract(&scaffold::read(page)?);
+    if examples.is_empty() {
+        return Err(ExamplesError::NoExamples(page.to_owned()));
+    }
+
+    let mut files = Vec::new();
+    for day_crate in day_crates(root, author, day)? {
+        let dir = Path::new(&day_crate.dir);
+        let mut inputs: Vec<(PathBuf, &str)> = Vec::new();
+        let mut tests = String::new();
+        for example in examples
+            .iter()
+            .filter(|example| day_crate.parts.contains(&example.part))
+        {
+            let path = match inputs.iter().find(|(_, input)| *input == example.input) {
+                Some((path, _)) => path.clone(),
+                None => {
+                    let path = numbered(scaffold::example_path(author), inputs.len() + 1);
+                    inputs.push((path.clone(), &example.input));
+                    path
+                }
+            };
+            if let Some(answer) = &example.answer {
+                tests += &test(day, example.part, &path, answer);
+            }
+        }
+
+        for (path, input) in inputs {
+            files.push((dir.join(path), input.to_owned()));
+        }
+        if !tests.is_empty() {
+            let test_file = TEST_TEMPLATE
+                .replace("{{crate}}", &day_crate.name)
+                .replace("{{day}}", &day.to_string())
+                .replace("{{tests}}", &tests);
+            files.push((dir.join("tests/examples.rs"), test_file));
+        }
+    }
+
+    if !force {
+        for (path, contents) in &files {
+            let existing = scaffold::read(&root.join(path)).unwrap_or_default();
+            if !existing.is_empty() && existing != *contents {
+                return Err(ExamplesError::WouldOverwrite(root.join(path)));
+            }
+        }
+    }
+    for (path, contents) in &files {
+        scaffold::write(&root.join(path), contents)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// The `n`th example file: the author's usual one first, then numbered ones
+/// when a crate's parts have different examples.
+fn numbered(path: &str, n: usize) -> PathBuf {
+    if n == 1 {
+        return PathBuf::from(path);
+    }
+    let path = Path::new(path);
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{}{}.{}", stem, n, extension.to_string_lossy()),
+        None => format!("{}{}", stem, n),
+    };
+    path.with_file_name(name)
+}
+
+fn test(day: u8, part: Part, input: &Path, answer: &str) -> String {
+    let (name, variant) = match part {
+        Part::One => ("part_one", "One"),
+        Part::Two => ("part_two", "Two"),
+    };
+    format!(
+        r#"
+#[test]
+fn {name}_example() {{
+    let answer = Day{day}.solve(Part::{variant}, include_str!("../{input}"));
+    assert_eq!(answer.unwrap().to_string(), {answer:?});
+}}
+"#,
+        input = input.display(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    use super::*;
+
+    const DAY_1: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>The newly-improved calibration document consists of lines of text; each line originally contained a specific <em>calibration value</em> that the Elves now need to recover.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54304</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Your calculation isn't quite right.</p>
+<pre><code>two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+</code></pre>
+<p>In this example, the calibration values are <code>29</code>, <code>83</code>, <code>13</code>, <code>24</code>, <code>42</code>, <code>14</code>, and <code>76</code>. Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main>"#;
+
+    /// Part two works through the same example as part one, whose grid is
+    /// full of characters the page has to escape.
+    const SHARED_EXAMPLE: &str = r#"<article class="day-desc"><h2>--- Day 16 ---</h2>
+<pre><code>.|...\....
+|.-.\.....
+.....|-...
+<em>&gt;</em>..&lt;&amp;...
+</code></pre>
+<p>In total, <code><em>46</em></code> tiles become energized.</p></article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using the contraption above, this configuration energizes <em><code>51</code></em> tiles.</p></article>"#;
+
+    fn root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-examples-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+        let manifest = concat!(
+            "[dependencies]\n",
+            "lethalgem_day01_part1 = { path = \"../lethalgem/Day_1/part_1\" }\n",
+            "lethalgem_day01_part2 = { path = \"../lethalgem/Day_1/Part_2\" }\n",
+            "lethalgem_day11_part1 = { path = \"../lethalgem/day_11/part_1\" }\n",
+            "mohammad_day01 = { path = \"../mohammad/day01\" }\n",
+        );
+        scaffold::write(&root.join("aoc/Cargo.toml"), manifest).unwrap();
+        root
+    }
+
+    #[test]
+    fn finds_each_parts_example_and_answer() {
+        let examples = extract(DAY_1);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].part, Part::One);
+        assert!(examples[0].input.starts_with("1abc2\npqr3stu8vwx\n"));
+        assert_eq!(examples[0].answer.as_deref(), Some("142"));
+        assert_eq!(examples[1].part, Part::Two);
+        assert!(examples[1].input.starts_with("two1nine\n"));
+        assert!(examples[1].input.ends_with("7pqrstsixteen\n"));
+        assert_eq!(examples[1].answer.as_deref(), Some("281"));
+    }
+
+    #[test]
+    fn reuses_the_example_and_decodes_it() {
+        let examples = extract(SHARED_EXAMPLE);
+        let input = ".|...\\....\n|.-.\\.....\n.....|-...\n>..<&...\n";
+        assert_eq!(
+            examples,
+            [
+                Example {
+                    part: Part::One,
+                    input: input.to_owned(),
+                    answer: Some("46".to_owned()),
+                },
+                Example {
+                    part: Part::Two,
+                    input: input.to_owned(),
+                    answer: Some("51".to_owned()),
+                },
+            ]
+        );
+        assert!(extract("<p>No puzzle here</p>").is_empty());
+    }
+
+    #[test]
+    fn writes_examples_and_tests_into_each_crate() {
+        let root = root("write");
+        let page = root.join("day01.html");
+        scaffold::write(&page, DAY_1).unwrap();
+
+        let written = write_examples(&root, &page, Author::Lethalgem, 1, false).unwrap();
+        assert_eq!(
+            written,
+            [
+                "lethalgem/Day_1/part_1/src/example.txt",
+                "lethalgem/Day_1/part_1/tests/examples.rs",
+                "lethalgem/Day_1/Part_2/src/example.txt",
+                "lethalgem/Day_1/Part_2/tests/examples.rs",
+            ]
+            .map(PathBuf::from)
+        );
+        let test =
+            fs::read_to_string(root.join("lethalgem/Day_1/Part_2/tests/examples.rs")).unwrap();
+        assert!(test.contains("use lethalgem_day01_part2::Day1;"));
+        assert!(test.contains(
+            "Day1.solve(Part::Two, include_str!(\"../src/example.txt\"));\n    assert_eq!(answer.unwrap().to_string(), \"281\");"
+        ));
+        assert!(!test.contains("Part::One"));
+
+        // Both parts live in one crate, so the second example gets a file of its own.
+        let written = write_examples(&root, &page, Author::Mohammad, 1, false).unwrap();
+        assert_eq!(
+            written,
+            [
+                "mohammad/day01/src/input_small",
+                "mohammad/day01/src/input_small2",
+                "mohammad/day01/tests/examples.rs",
+            ]
+            .map(PathBuf::from)
+        );
+        let test = fs::read_to_string(root.join("mohammad/day01/tests/examples.rs")).unwrap();
+        assert!(test.contains("Day1.solve(Part::Two, include_str!(\"../src/input_small2\"));"));
+    }
+
+    #[test]
+    fn keeps_hand_written_examples_unless_forced() {
+        let root = root("force");
+        let page = root.join("day01.html");
+        scaffold::write(&page, DAY_1).unwrap();
+        let example = root.join("lethalgem/Day_1/part_1/src/example.txt");
+        scaffold::write(&example, "hand copied\n").unwrap();
+
+        assert!(matches!(
+            write_examples(&root, &page, Author::Lethalgem, 1, false),
+            Err(ExamplesError::WouldOverwrite(path)) if path == example
+        ));
+        assert!(!root.join("lethalgem/Day_1/Part_2/src/example.txt").exists());
+
+        write_examples(&root, &page, Author::Lethalgem, 1, true).unwrap();
+        assert!(fs::read_to_string(&example).unwrap().starts_with("1abc2"));
+        // Writing the same examples again needs no forcing.
+        write_examples(&root, &page, Author::Lethalgem, 1, false).unwrap();
+
+        assert!(matches!(
+            write_examples(&root, &page, Author::Mattdamachine, 1, false),
+            Err(ExamplesError::NoCrates { .. })
+        ));
+    }
+}
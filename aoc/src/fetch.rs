@@ -0,0 +1,404 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_core::Part;
+use thiserror::Error;
+
+pub const SITE: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2023;
+
+/// Environment variable holding the `session` cookie from a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding where inputs are cached.
+pub const CACHE_VAR: &str = "AOC_CACHE_DIR";
+
+const LEADERBOARD_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Error, Debug)]
+pub enum FetchError {
+    #[error(
+        "no session token for the puzzle site; set {SESSION_VAR} or save the `session` cookie in {}",
+        .0.display()
+    )]
+    NoSession(PathBuf),
+    #[error("no cache directory for puzzle inputs on this system; set {CACHE_VAR}")]
+    NoCacheDir,
+    #[error("the session token was turned down; log in again and save the new `session` cookie")]
+    BadSession,
+    #[error("day {0} has not unlocked yet")]
+    NotUnlocked(u8),
+    #[error("no private leaderboard {0} that you can see")]
+    NoLeaderboard(u64),
+    #[error("the puzzle site answered {status}: {message}")]
+    Status { status: u16, message: String },
+    #[error("cannot reach the puzzle site: {0}")]
+    Unreachable(String),
+    #[error("{}: {source}", .path.display())]
+    Io { path: PathBuf, source: io::Error },
+}
+
+/// Downloads puzzle inputs, keeping each one in a per-user cache so a day is
+/// only ever downloaded once.
+pub struct Fetcher {
+    site: String,
+    cache_dir: PathBuf,
+    session: Option<String>,
+    /// Where the session token was looked for, to point at when it's missing.
+    session_file: PathBuf,
+}
+
+impl Fetcher {
+    pub fn new(site: &str, cache_dir: PathBuf, session: Option<String>) -> Fetcher {
+        Fetcher {
+            site: site.trim_end_matches('/').to_owned(),
+            cache_dir,
+            session,
+            session_file: PathBuf::from("session"),
+        }
+    }
+
+    /// Takes the session token from `AOC_SESSION`, or failing that from the
+    /// `aoc/session` file in the user's config directory, and caches inputs in
+    /// `AOC_CACHE_DIR` or the user's cache directory.
+    pub fn from_env() -> Result<Fetcher, FetchError> {
+        let cache_dir = match env::var_os(CACHE_VAR) {
+            Some(dir) => PathBuf::from(dir),
+            None => dirs::cache_dir().ok_or(FetchError::NoCacheDir)?.join("aoc"),
+        };
+        let session_file = dirs::config_dir()
+            .unwrap_or_default()
+            .join("aoc")
+            .join("session");
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| fs::read_to_string(&session_file).ok())
+            .map(|session| session.trim().to_owned())
+            .filter(|session| !session.is_empty());
+
+        Ok(Fetcher {
+            session_file,
+            ..Fetcher::new(SITE, cache_dir, session)
+        })
+    }
+
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        self.year_dir().join(format!("day{:02}.txt", day))
+    }
+
+    /// The path of `day`'s cached input, downloading it first if it isn't
+    /// cached yet.
+    pub fn input(&self, day: u8) -> Result<PathBuf, FetchError> {
+        let path = self.cached_path(day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let input = self.download(day)?;
+        // Written to the side first, so an interrupted download is never
+        // mistaken for a cached input.
+        let partial = path.with_extension("part");
+        write(&partial, &input)?;
+        fs::rename(&partial, &path).map_err(|source| FetchError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(path)
+    }
+
+    /// Where this user's inputs and submissions for the year are kept.
+    pub fn year_dir(&self) -> PathBuf {
+        self.cache_dir.join(YEAR.to_string())
+    }
+
+    fn download(&self, day: u8) -> Result<String, FetchError> {
+        let request = self.request("GET", &format!("day/{}/input", day))?;
+        self.send(request.call(), FetchError::NotUnlocked(day))
+    }
+
+    /// Posts `answer` to `part` of `day` and returns the page the site
+    /// answers with.
+    pub fn post_answer(&self, day: u8, part: Part, answer: &str) -> Result<String, FetchError> {
+        let request = self.request("POST", &format!("day/{}/answer", day))?;
+        let level = part.to_string();
+        self.send(
+            request.send_form(&[("level", &level), ("answer", answer)]),
+            FetchError::NotUnlocked(day),
+        )
+    }
+
+    /// The path of private leaderboard `id`'s JSON export, downloading it
+    /// again if the copy in the cache is older than the fifteen minutes the
+    /// site asks for between downloads.
+    pub fn leaderboard(&self, id: u64) -> Result<PathBuf, FetchError> {
+        let path = self.year_dir().join(format!("leaderboard-{}.json", id));
+        let fresh = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age < LEADERBOARD_REFRESH);
+        if fresh {
+            return Ok(path);
+        }
+
+        let request = self.request("GET", &format!("leaderboard/private/view/{}.json", id))?;
+        let export = self.send(request.call(), FetchError::NoLeaderboard(id))?;
+        // Anyone who can't see the leaderboard is sent to the leaderboard
+        // page instead.
+        if !export.trim_start().starts_with('{') {
+            return Err(FetchError::NoLeaderboard(id));
+        }
+        write(&path, &export)?;
+        Ok(path)
+    }
+
+    fn request(&self, method: &str, path: &str) -> Result<ureq::Request, FetchError> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| FetchError::NoSession(self.session_file.clone()))?;
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(concat!(
+                "aoc-runner/",
+                env!("CARGO_PKG_VERSION"),
+                " (Advent of Code 2023 workspace)"
+            ))
+            .timeout(Duration::from_secs(30))
+            .build();
+        let url = format!("{}/{}/{}", self.site, YEAR, path);
+        Ok(agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", session)))
+    }
+
+    fn send(
+        &self,
+        response: Result<ureq::Response, ureq::Error>,
+        not_found: FetchError,
+    ) -> Result<String, FetchError> {
+        let read_body = |response: ureq::Response| {
+            response
+                .into_string()
+                .map_err(|err| FetchError::Unreachable(err.to_string()))
+        };
+        match response {
+            Ok(response) => read_body(response),
+            Err(ureq::Error::Status(404, _)) => Err(not_found),
+            Err(ureq::Error::Status(status, response)) => {
+                let message = read_body(response)?.trim().to_owned();
+                // The site answers 400 without a valid session, and 500 for
+                // some malformed ones, asking to log in either way.
+                if message.contains("log in") {
+                    Err(FetchError::BadSession)
+                } else {
+                    Err(FetchError::Status { status, message })
+                }
+            }
+            Err(ureq::Error::Transport(transport)) => {
+                Err(FetchError::Unreachable(transport.to_string()))
+            }
+        }
+    }
+}
+
+pub(crate) fn write(path: &Path, contents: &str) -> Result<(), FetchError> {
+    let io_error = |source| FetchError::Io {
+        path: path.to_owned(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::process;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::*;
+
+    const INPUT: &str = "1abc2\npqr3stu8vwx\n";
+
+    pub(crate) const LOG_IN: &str =
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+
+    /// A request the stub site received: its path, whether it carried the
+    /// `good` session, and its form body.
+    pub(crate) struct Request {
+        pub path: String,
+        pub logged_in: bool,
+        pub body: String,
+    }
+
+    /// Serves `respond`'s status and body to every request on a local port,
+    /// counting the requests.
+    pub(crate) fn serve(
+        respond: impl Fn(&Request) -> (&'static str, String) + Send + 'static,
+    ) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let site = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut reader = BufReader::new(&stream);
+                let mut head = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    head.push(line.to_owned());
+                }
+
+                let header = |name: &str| {
+                    head.iter().find_map(|line| {
+                        let (key, value) = line.split_once(':')?;
+                        key.eq_ignore_ascii_case(name).then(|| value.trim())
+                    })
+                };
+                let length = header("content-length").map_or(0, |len| len.parse().unwrap());
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let request = Request {
+                    path: head[0].split(' ').nth(1).unwrap().to_owned(),
+                    logged_in: header("cookie") == Some("session=good"),
+                    body: String::from_utf8(body).unwrap(),
+                };
+
+                let (status, body) = respond(&request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+
+        (site, requests)
+    }
+
+    /// Answers like the puzzle site: day 1 for the `good` session, a 404 for
+    /// days that haven't unlocked and a 500 for day 2.
+    fn stub_site() -> (String, Arc<AtomicUsize>) {
+        serve(|request| {
+            let (status, body) = match request.path.as_str() {
+                _ if !request.logged_in => ("400 Bad Request", LOG_IN),
+                "/2023/day/1/input" => ("200 OK", INPUT),
+                "/2023/day/2/input" => ("500 Internal Server Error", "Internal Server Error\n"),
+                _ => (
+                    "404 Not Found",
+                    "Please don't repeatedly request this endpoint before it unlocks!\n",
+                ),
+            };
+            (status, body.to_owned())
+        })
+    }
+
+    pub(crate) fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_each_day_once() {
+        let (site, requests) = stub_site();
+        let fetcher = Fetcher::new(&site, cache_dir("once"), Some("good".to_owned()));
+
+        let path = fetcher.input(1).unwrap();
+        assert_eq!(path, fetcher.cached_path(1));
+        assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+
+        assert_eq!(fetcher.input(1).unwrap(), path);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn cached_inputs_need_no_session() {
+        let (site, requests) = stub_site();
+        let dir = cache_dir("cached");
+        Fetcher::new(&site, dir.clone(), Some("good".to_owned()))
+            .input(1)
+            .unwrap();
+
+        let fetcher = Fetcher::new(&site, dir, None);
+        assert!(fetcher.input(1).is_ok());
+        assert!(matches!(fetcher.input(3), Err(FetchError::NoSession(_))));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn reports_what_the_site_turned_down() {
+        let (site, _) = stub_site();
+        let dir = cache_dir("errors");
+
+        let fetcher = Fetcher::new(&site, dir.clone(), Some("stale".to_owned()));
+        assert!(matches!(fetcher.input(1), Err(FetchError::BadSession)));
+
+        let fetcher = Fetcher::new(&site, dir, Some("good".to_owned()));
+        assert!(matches!(
+            fetcher.input(25),
+            Err(FetchError::NotUnlocked(25))
+        ));
+        let Err(FetchError::Status { status, message }) = fetcher.input(2) else {
+            panic!("expected the server error to come through");
+        };
+        assert_eq!((status, message.as_str()), (500, "Internal Server Error"));
+
+        // Nothing is cached for a failed download.
+        assert!(!fetcher.cached_path(2).exists());
+        assert!(!fetcher.cached_path(25).exists());
+    }
+
+    #[test]
+    fn downloads_the_leaderboard_at_most_every_fifteen_minutes() {
+        let (site, requests) = serve(|request| match request.path.as_str() {
+            "/2023/leaderboard/private/view/42.json" => {
+                ("200 OK", r#"{"event":"2023","members":{}}"#.to_owned())
+            }
+            // Someone else's leaderboard sends you to the leaderboard page.
+            _ => (
+                "200 OK",
+                "<!DOCTYPE html>\n<html lang=\"en-us\">".to_owned(),
+            ),
+        });
+        let fetcher = Fetcher::new(&site, cache_dir("leaderboard"), Some("good".to_owned()));
+
+        let path = fetcher.leaderboard(42).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("members"));
+        assert_eq!(fetcher.leaderboard(42).unwrap(), path);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert!(matches!(
+            fetcher.leaderboard(7),
+            Err(FetchError::NoLeaderboard(7))
+        ));
+        assert!(!fetcher.year_dir().join("leaderboard-7.json").exists());
+    }
+
+    #[test]
+    fn reports_an_unreachable_site() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let site = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let fetcher = Fetcher::new(&site, cache_dir("unreachable"), Some("good".to_owned()));
+        assert!(matches!(fetcher.input(1), Err(FetchError::Unreachable(_))));
+    }
+}
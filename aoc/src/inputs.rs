@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use aoc_core::{read_input, InputError, Part};
+use clap::Args;
+
+use crate::answers::{self, ExpectedAnswer};
+use crate::differential;
+
+/// Which puzzle inputs a command runs the solutions on.
+#[derive(Args)]
+pub struct InputArgs {
+    /// Inputs to run on, or - for stdin. Defaults to every input in the answers file
+    #[arg(long)]
+    input: Vec<PathBuf>,
+    /// Answers file listing the stored inputs, relative to its directory
+    #[arg(long, default_value = "answers.txt")]
+    answers: PathBuf,
+    /// Run on this many generated inputs per day instead, seeded 0, 1, 2 and so on
+    #[arg(long, conflicts_with = "input")]
+    generated: Option<u64>,
+    /// Size of the generated inputs, kept within each day's range; defaults to about a real input's size
+    #[arg(long, requires = "generated")]
+    size: Option<usize>,
+}
+
+pub enum Inputs {
+    Stored {
+        root: PathBuf,
+        expected_answers: Vec<ExpectedAnswer>,
+    },
+    /// Read once up front so stdin can be used for every day and part.
+    Files(Vec<(String, String)>),
+    Generated {
+        count: u64,
+        size: Option<usize>,
+    },
+}
+
+impl Inputs {
+    pub fn load(args: InputArgs) -> Result<Self, Box<dyn Error>> {
+        if let Some(count) = args.generated {
+            return Ok(Inputs::Generated {
+                count,
+                size: args.size,
+            });
+        }
+
+        if args.input.is_empty() {
+            let root = args.answers.parent().unwrap_or(Path::new("")).to_owned();
+            let expected_answers = answers::load(&args.answers)?;
+            return Ok(Inputs::Stored {
+                root,
+                expected_answers,
+            });
+        }
+
+        let files = args
+            .input
+            .iter()
+            .map(|file| Ok((file.display().to_string(), read_input(file)?)))
+            .collect::<Result<_, InputError>>()?;
+        Ok(Inputs::Files(files))
+    }
+
+    /// The inputs for `part` of `day`, each labelled with where it came from.
+    pub fn for_day(&self, day: u8, part: Part) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+        match self {
+            Inputs::Stored {
+                root,
+                expected_answers,
+            } => Ok(differential::stored_inputs(expected_answers, day, part)
+                .into_iter()
+                .map(|input| {
+                    let input_data = read_input(&root.join(&input))?;
+                    Ok((input.display().to_string(), input_data))
+                })
+                .collect::<Result<_, InputError>>()?),
+            Inputs::Files(files) => Ok(files.clone()),
+            Inputs::Generated { count, size } => {
+                let generator = aoc_gen::find(day)?;
+                let size = size.map(|size| size.clamp(generator.min_size, generator.max_size));
+                Ok((0..*count)
+                    .map(|seed| {
+                        let input_data = aoc_gen::generate(day, seed, size)?;
+                        let size = size.map_or(String::new(), |size| format!(" --size {}", size));
+                        Ok((format!("generate --seed {}{}", seed, size), input_data))
+                    })
+                    .collect::<Result<_, aoc_gen::GenError>>()?)
+            }
+        }
+    }
+}
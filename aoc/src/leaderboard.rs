@@ -0,0 +1,479 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use clap::ValueEnum;
+use serde::Deserialize;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum LeaderboardError {
+    #[error("not a private leaderboard export: {0}")]
+    Malformed(#[from] serde_json::Error),
+    #[error("not a private leaderboard export: no event year {0:?}")]
+    BadEvent(String),
+}
+
+/// The private leaderboard JSON export, as the site serves it.
+#[derive(Deserialize)]
+struct Export {
+    event: String,
+    members: HashMap<String, ExportMember>,
+}
+
+#[derive(Deserialize)]
+struct ExportMember {
+    id: u64,
+    name: Option<String>,
+    local_score: u64,
+    /// Stars by day, then by part, as strings.
+    completion_day_level: HashMap<String, HashMap<String, ExportStar>>,
+}
+
+#[derive(Deserialize)]
+struct ExportStar {
+    get_star_ts: i64,
+    /// Breaks ties between stars in the same second.
+    #[serde(default)]
+    star_index: u64,
+}
+
+pub struct Member {
+    pub name: String,
+    /// The score the site reports.
+    pub site_score: u64,
+    /// The score worked out again from the star timestamps.
+    pub score: u64,
+    /// When each star was earned, by day, as seconds after the day unlocked.
+    pub days: BTreeMap<u8, [Option<i64>; 2]>,
+}
+
+impl Member {
+    pub fn stars(&self) -> usize {
+        self.days.values().flatten().flatten().count()
+    }
+
+    /// The most days in a row with both stars.
+    pub fn longest_streak(&self) -> u32 {
+        let mut longest = 0;
+        let mut streak = 0;
+        for day in 1..=25 {
+            match self.days.get(&day) {
+                Some([Some(_), Some(_)]) => {
+                    streak += 1;
+                    longest = longest.max(streak);
+                }
+                _ => streak = 0,
+            }
+        }
+        longest
+    }
+}
+
+/// A private leaderboard's members, highest score first.
+pub struct Leaderboard {
+    pub year: i32,
+    pub members: Vec<Member>,
+}
+
+pub fn parse(json: &str) -> Result<Leaderboard, LeaderboardError> {
+    let export: Export = serde_json::from_str(json)?;
+    let year: i32 = export
+        .event
+        .parse()
+        .map_err(|_| LeaderboardError::BadEvent(export.event.clone()))?;
+
+    let mut export_members: Vec<ExportMember> = export.members.into_values().collect();
+    export_members.sort_by_key(|member| member.id);
+    let scores = local_scores(&export_members);
+
+    let mut members: Vec<Member> = export_members
+        .iter()
+        .zip(scores)
+        .map(|(member, score)| {
+            let mut days = BTreeMap::new();
+            for (day, parts) in &member.completion_day_level {
+                let Ok(day) = day.parse::<u8>() else {
+                    continue;
+                };
+                let mut stars = [None, None];
+                for (part, star) in parts {
+                    if let Some(slot) = part
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|part| stars.get_mut(part.wrapping_sub(1)))
+                    {
+                        *slot = Some(star.get_star_ts - unlock(year, day));
+                    }
+                }
+                days.insert(day, stars);
+            }
+            Member {
+                name: member
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+                site_score: member.local_score,
+                score,
+                days,
+            }
+        })
+        .collect();
+    members.sort_by(|a, b| {
+        (b.score, b.stars())
+            .cmp(&(a.score, a.stars()))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    Ok(Leaderboard { year, members })
+}
+
+/// When a star was earned, its tie break, and which member earned it.
+type Earned = (i64, u64, usize);
+
+/// Scores every star the way the site does: the first member to earn it gets
+/// a point for every member of the leaderboard, the next one a point fewer,
+/// and so on.
+fn local_scores(members: &[ExportMember]) -> Vec<u64> {
+    let mut scores = vec![0; members.len()];
+    let mut stars: HashMap<(&str, &str), Vec<Earned>> = HashMap::new();
+    for (index, member) in members.iter().enumerate() {
+        for (day, parts) in &member.completion_day_level {
+            for (part, star) in parts {
+                stars.entry((day, part)).or_default().push((
+                    star.get_star_ts,
+                    star.star_index,
+                    index,
+                ));
+            }
+        }
+    }
+
+    for mut earned in stars.into_values() {
+        earned.sort();
+        for (rank, (_, _, index)) in earned.into_iter().enumerate() {
+            scores[index] += (members.len() - rank) as u64;
+        }
+    }
+    scores
+}
+
+/// When `day`'s puzzle unlocked: midnight US Eastern time, 05:00 UTC.
+fn unlock(year: i32, day: u8) -> i64 {
+    // Days from 1970-01-01 to December 1st, counting from March so leap days
+    // fall at the end of each year.
+    let year = i64::from(year);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (12 - 3) + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let december_first = era * 146_097 + day_of_era - 719_468;
+
+    (december_first + i64::from(day) - 1) * 86_400 + 5 * 3_600
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Terminal,
+    Markdown,
+}
+
+#[derive(Clone, Copy)]
+enum Align {
+    Left,
+    Right,
+}
+
+struct Table {
+    columns: Vec<(&'static str, Align)>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn render(&self, format: Format) -> String {
+        let mut out = String::new();
+        match format {
+            Format::Terminal => {
+                let widths: Vec<usize> = self
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(column, (title, _))| {
+                        self.rows
+                            .iter()
+                            .map(|row| row[column].chars().count())
+                            .chain([title.chars().count()])
+                            .max()
+                            .unwrap_or(0)
+                    })
+                    .collect();
+                let titles = self.columns.iter().map(|(title, _)| title.to_string());
+                for row in std::iter::once(titles.collect()).chain(self.rows.iter().cloned()) {
+                    let cells: Vec<String> = row
+                        .iter()
+                        .zip(&self.columns)
+                        .zip(&widths)
+                        .map(|((cell, (_, align)), &width)| match align {
+                            Align::Left => format!("{:<width$}", cell),
+                            Align::Right => format!("{:>width$}", cell),
+                        })
+                        .collect();
+                    let _ = writeln!(out, "{}", cells.join("  ").trim_end());
+                }
+            }
+            Format::Markdown => {
+                let titles: Vec<&str> = self.columns.iter().map(|(title, _)| *title).collect();
+                let rules: Vec<&str> = self
+                    .columns
+                    .iter()
+                    .map(|(_, align)| match align {
+                        Align::Left => ":---",
+                        Align::Right => "---:",
+                    })
+                    .collect();
+                let _ = writeln!(out, "| {} |", titles.join(" | "));
+                let _ = writeln!(out, "| {} |", rules.join(" | "));
+                for row in &self.rows {
+                    let _ = writeln!(out, "| {} |", row.join(" | "));
+                }
+            }
+        }
+        out
+    }
+}
+
+/// The standings, then every member's star times for each day.
+pub fn report(leaderboard: &Leaderboard, format: Format) -> String {
+    let standings = Table {
+        columns: vec![
+            ("#", Align::Right),
+            ("member", Align::Left),
+            ("score", Align::Right),
+            ("stars", Align::Right),
+            ("streak", Align::Right),
+        ],
+        rows: leaderboard
+            .members
+            .iter()
+            .enumerate()
+            .map(|(rank, member)| {
+                vec![
+                    (rank + 1).to_string(),
+                    member.name.clone(),
+                    member.score.to_string(),
+                    member.stars().to_string(),
+                    member.longest_streak().to_string(),
+                ]
+            })
+            .collect(),
+    };
+
+    let days: Vec<u8> = leaderboard
+        .members
+        .iter()
+        .flat_map(|member| member.days.keys().copied())
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let mut rows = Vec::new();
+    for day in days {
+        let mut earned: Vec<(&Member, [Option<i64>; 2])> = leaderboard
+            .members
+            .iter()
+            .filter_map(|member| Some((member, *member.days.get(&day)?)))
+            .collect();
+        earned.sort_by_key(|(_, [one, two])| (two.unwrap_or(i64::MAX), one.unwrap_or(i64::MAX)));
+        for (member, [one, two]) in earned {
+            let delta = one.zip(two).map(|(one, two)| two - one);
+            rows.push(vec![
+                day.to_string(),
+                member.name.clone(),
+                one.map_or("-".to_owned(), elapsed),
+                two.map_or("-".to_owned(), elapsed),
+                delta.map_or("-".to_owned(), elapsed),
+            ]);
+        }
+    }
+    let times = Table {
+        columns: vec![
+            ("day", Align::Right),
+            ("member", Align::Left),
+            ("part 1", Align::Right),
+            ("part 2", Align::Right),
+            ("1 to 2", Align::Right),
+        ],
+        rows,
+    };
+
+    // The site leaves out stars it has cancelled, which the export still has.
+    let mut standings = standings.render(format);
+    for member in &leaderboard.members {
+        if member.score != member.site_score {
+            let _ = writeln!(
+                standings,
+                "\n{} has {} points on the site, not {}",
+                member.name, member.site_score, member.score
+            );
+        }
+    }
+
+    let heading = match format {
+        Format::Terminal => "",
+        Format::Markdown => "### ",
+    };
+    format!(
+        "{heading}Advent of Code {} standings\n\n{}\n{heading}Time from unlock to each star\n\n{}",
+        leaderboard.year,
+        standings,
+        times.render(format),
+    )
+}
+
+/// `12:03:04`, or `2d 12:03:04` past a day.
+fn elapsed(seconds: i64) -> String {
+    let (days, rest) = (seconds / 86_400, seconds % 86_400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        rest / 3_600,
+        rest % 3_600 / 60,
+        rest % 60
+    );
+    if days > 0 {
+        format!("{}d {}", days, time)
+    } else {
+        time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Days 1 to 3 of a leaderboard with an anonymous member who never
+    /// started. Day 1 unlocked at 1701406800.
+    const EXPORT: &str = r#"{
+  "event": "2023",
+  "owner_id": 1,
+  "members": {
+    "1": {"id": 1, "name": "lethalgem", "stars": 6, "local_score": 18, "global_score": 0, "last_star_ts": 1701580800,
+      "completion_day_level": {
+        "1": {"1": {"get_star_ts": 1701407100, "star_index": 11}, "2": {"get_star_ts": 1701407400, "star_index": 14}},
+        "2": {"1": {"get_star_ts": 1701494200, "star_index": 21}, "2": {"get_star_ts": 1701497800, "star_index": 24}},
+        "3": {"1": {"get_star_ts": 1701580500, "star_index": 32}, "2": {"get_star_ts": 1701580800, "star_index": 34}}
+      }},
+    "2": {"id": 2, "name": "mohammad", "stars": 5, "local_score": 16, "global_score": 0, "last_star_ts": 1701679600,
+      "completion_day_level": {
+        "1": {"1": {"get_star_ts": 1701407000, "star_index": 10}, "2": {"get_star_ts": 1701407700, "star_index": 15}},
+        "2": {"1": {"get_star_ts": 1701494000, "star_index": 20}, "2": {"get_star_ts": 1701494200, "star_index": 22}},
+        "3": {"1": {"get_star_ts": 1701679600, "star_index": 35}}
+      }},
+    "3": {"id": 3, "name": "mattdamachine", "stars": 4, "local_score": 14, "global_score": 0, "last_star_ts": 1701580300,
+      "completion_day_level": {
+        "1": {"1": {"get_star_ts": 1701407200, "star_index": 12}, "2": {"get_star_ts": 1701407300, "star_index": 13}},
+        "3": {"1": {"get_star_ts": 1701580100, "star_index": 30}, "2": {"get_star_ts": 1701580300, "star_index": 31}}
+      }},
+    "4": {"id": 4, "name": null, "stars": 0, "local_score": 0, "global_score": 0, "last_star_ts": 0,
+      "completion_day_level": {}}
+  }
+}"#;
+
+    #[test]
+    fn days_unlock_at_midnight_eastern() {
+        assert_eq!(unlock(2023, 1), 1_701_406_800);
+        assert_eq!(unlock(2023, 25), 1_701_406_800 + 24 * 86_400);
+        assert_eq!(unlock(2020, 1), 1_606_798_800);
+    }
+
+    #[test]
+    fn reconstructs_the_local_scores() {
+        let leaderboard = parse(EXPORT).unwrap();
+        let summary: Vec<(&str, u64, u64, usize, u32)> = leaderboard
+            .members
+            .iter()
+            .map(|member| {
+                (
+                    member.name.as_str(),
+                    member.score,
+                    member.site_score,
+                    member.stars(),
+                    member.longest_streak(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("lethalgem", 18, 18, 6, 3),
+                ("mohammad", 16, 16, 5, 2),
+                ("mattdamachine", 14, 14, 4, 1),
+                ("(anonymous user #4)", 0, 0, 0, 0),
+            ]
+        );
+        assert_eq!(leaderboard.members[1].days[&3], [Some(100_000), None]);
+    }
+
+    #[test]
+    fn reports_standings_and_star_times() {
+        let leaderboard = parse(EXPORT).unwrap();
+        assert_eq!(
+            report(&leaderboard, Format::Terminal),
+            "\
+Advent of Code 2023 standings
+
+#  member               score  stars  streak
+1  lethalgem               18      6       3
+2  mohammad                16      5       2
+3  mattdamachine           14      4       1
+4  (anonymous user #4)      0      0       0
+
+Time from unlock to each star
+
+day  member              part 1    part 2    1 to 2
+  1  mattdamachine     00:06:40  00:08:20  00:01:40
+  1  lethalgem         00:05:00  00:10:00  00:05:00
+  1  mohammad          00:03:20  00:15:00  00:11:40
+  2  mohammad          00:13:20  00:16:40  00:03:20
+  2  lethalgem         00:16:40  01:16:40  01:00:00
+  3  mattdamachine     00:08:20  00:11:40  00:03:20
+  3  lethalgem         00:15:00  00:20:00  00:05:00
+  3  mohammad       1d 03:46:40         -         -
+"
+        );
+    }
+
+    #[test]
+    fn renders_markdown_tables() {
+        let leaderboard = parse(EXPORT).unwrap();
+        let markdown = report(&leaderboard, Format::Markdown);
+        assert!(markdown.starts_with(
+            "\
+### Advent of Code 2023 standings
+
+| # | member | score | stars | streak |
+| ---: | :--- | ---: | ---: | ---: |
+| 1 | lethalgem | 18 | 6 | 3 |
+"
+        ));
+        assert!(markdown.contains("| 3 | mohammad | 1d 03:46:40 | - | - |\n"));
+    }
+
+    #[test]
+    fn points_out_scores_the_site_disagrees_with() {
+        let export = EXPORT.replace(r#""local_score": 14"#, r#""local_score": 12"#);
+        let report = report(&parse(&export).unwrap(), Format::Terminal);
+        assert!(report.contains(
+            "4  (anonymous user #4)      0      0       0\n\nmattdamachine has 12 points on the site, not 14\n\nTime"
+        ));
+    }
+
+    #[test]
+    fn rejects_other_json() {
+        assert!(matches!(
+            parse(r#"{"error": "not logged in"}"#),
+            Err(LeaderboardError::Malformed(_))
+        ));
+        assert!(matches!(
+            parse(r#"{"event": "next", "members": {}}"#),
+            Err(LeaderboardError::BadEvent(_))
+        ));
+    }
+}
@@ -0,0 +1,585 @@
+mod answers;
+mod bench;
+mod cubes;
+mod differential;
+mod examples;
+mod fetch;
+mod inputs;
+mod leaderboard;
+#[cfg(test)]
+mod properties;
+mod registry;
+mod report;
+mod scaffold;
+mod submit;
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use tracing::info_span;
+
+use aoc_core::{read_input, Part};
+use inputs::{InputArgs, Inputs};
+use lethalgem_day02_part2::Set;
+use registry::{Author, SOLUTIONS};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run any author's Advent of Code 2023 solution")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show more of what solutions are doing on stderr: -v for how long each
+    /// step took, -vv and -vvv for their workings
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Only show errors on stderr
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one part of one day with the given author's solution
+    Run {
+        #[arg(long, value_enum)]
+        author: Author,
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: Part,
+        /// Path to the puzzle input, or - to read it from stdin; defaults to
+        /// the day's downloaded input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Print the answer, timings and a hash of the input as one line of
+        /// JSON instead
+        #[arg(long)]
+        json: bool,
+    },
+    /// Run every solution against its stored answers and report any mismatch
+    Check {
+        /// Answers file, with input paths relative to its directory
+        #[arg(long, default_value = "answers.txt")]
+        answers: PathBuf,
+        /// Only check this author's answers
+        #[arg(long, value_enum)]
+        author: Option<Author>,
+        /// Only check answers for this day
+        #[arg(long)]
+        day: Option<u8>,
+    },
+    /// Run every author's solution on the same inputs and report where they disagree
+    Diff {
+        /// Only compare this day, instead of every day solved by more than one author
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only compare this part
+        #[arg(long)]
+        part: Option<Part>,
+        #[command(flatten)]
+        inputs: InputArgs,
+        /// Seconds to wait for each solution before leaving it out of the comparison
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+    },
+    /// Time parsing and solving separately for every author's solutions and compare them
+    Bench {
+        /// Only benchmark this author's solutions
+        #[arg(long, value_enum)]
+        author: Option<Author>,
+        #[arg(long)]
+        day: Option<u8>,
+        #[arg(long)]
+        part: Option<Part>,
+        #[command(flatten)]
+        inputs: InputArgs,
+        /// Most times to run each solution on each input; slow ones stop after a second
+        #[arg(long, default_value_t = 10)]
+        runs: u32,
+        /// Seconds to wait for each solution before giving up on it
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+        /// Also write every measurement to this file as JSON
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Print a random puzzle input for a day, the same one every time for a given seed
+    Generate {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big to make the input, defaulting to about the size of a real one
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Download a day's puzzle input into the cache, unless it's already there, and print its path
+    Fetch {
+        #[arg(long)]
+        day: u8,
+    },
+    /// Submit an answer to the puzzle site, given or worked out by an author's solution
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: Part,
+        #[arg(long, required_unless_present = "author")]
+        answer: Option<String>,
+        /// Whose solution the answer is, worked out by running it unless
+        /// --answer is given; a correct answer is added to the answers file
+        #[arg(long, value_enum)]
+        author: Option<Author>,
+        /// Input for the author's solution; defaults to the day's downloaded input
+        #[arg(long, requires = "author")]
+        input: Option<PathBuf>,
+        /// Answers file a correct answer is added to, with input paths relative to its directory
+        #[arg(long, default_value = "answers.txt")]
+        answers: PathBuf,
+    },
+    /// Copy the examples out of a saved puzzle page into an author's crates, with a test for each
+    Examples {
+        #[arg(long)]
+        day: u8,
+        /// The puzzle's page, saved from the browser
+        #[arg(long)]
+        page: PathBuf,
+        /// Only write into this author's crates, instead of every author's
+        /// crates for the day
+        #[arg(long, value_enum)]
+        author: Option<Author>,
+        /// Root of the repository
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+        /// Replace example files that hold something else
+        #[arg(long)]
+        force: bool,
+    },
+    /// Report a private leaderboard's standings and star times
+    Leaderboard {
+        /// The leaderboard's JSON export, saved from the site
+        #[arg(long, required_unless_present = "id", conflicts_with = "id")]
+        file: Option<PathBuf>,
+        /// Download the export of the private leaderboard with this id instead
+        #[arg(long)]
+        id: Option<u64>,
+        #[arg(long, value_enum, default_value_t = leaderboard::Format::Terminal)]
+        format: leaderboard::Format,
+    },
+    /// Ask what a different bag would change about day 2's games
+    Cubes {
+        /// Path to the puzzle input; defaults to day 2's downloaded input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// The bag, like "12 red, 13 green, 14 blue"; defaults to the puzzle's
+        #[arg(long)]
+        bag: Option<String>,
+        /// Read the bag from this file instead
+        #[arg(long, conflicts_with = "bag")]
+        bag_file: Option<PathBuf>,
+        #[command(subcommand)]
+        query: cubes::Query,
+    },
+    /// Create an author's crates for a new day from their template and register them
+    New {
+        #[arg(long, value_enum)]
+        author: Author,
+        #[arg(long)]
+        day: u8,
+        /// Root of the repository
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_core::trace::init(if cli.quiet {
+        -1
+    } else {
+        cli.verbose.min(3) as i8
+    });
+
+    let result = match cli.command {
+        Command::Run {
+            author,
+            day,
+            part,
+            input,
+            json,
+        } => run(author, day, part, input, json),
+        Command::Check {
+            answers,
+            author,
+            day,
+        } => check(&answers, author, day),
+        Command::Diff {
+            day,
+            part,
+            inputs,
+            timeout,
+        } => Inputs::load(inputs)
+            .and_then(|inputs| diff(day, part, &inputs, Duration::from_secs(timeout))),
+        Command::Bench {
+            author,
+            day,
+            part,
+            inputs,
+            runs,
+            timeout,
+            json,
+        } => Inputs::load(inputs).and_then(|inputs| {
+            let filter = Filter { author, day, part };
+            bench(filter, &inputs, runs, Duration::from_secs(timeout), json)
+        }),
+        Command::Generate { day, seed, size } => generate(day, seed, size),
+        Command::Fetch { day } => fetch(day),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            author,
+            input,
+            answers,
+        } => submit(day, part, answer, author, input, answers),
+        Command::Examples {
+            day,
+            page,
+            author,
+            root,
+            force,
+        } => write_examples(&root, &page, author, day, force),
+        Command::Leaderboard { file, id, format } => show_leaderboard(file, id, format),
+        Command::Cubes {
+            input,
+            bag,
+            bag_file,
+            query,
+        } => ask_about_cubes(input, bag, bag_file, query),
+        Command::New { author, day, root } => new(&root, author, day),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(
+    author: Author,
+    day: u8,
+    part: Part,
+    input: Option<PathBuf>,
+    json: bool,
+) -> Result<(), Box<dyn Error>> {
+    let report = solve(author, day, part, input)?;
+
+    if json {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", report.answer);
+        eprintln!(
+            "time elapsed: {:?}",
+            Duration::from_nanos(report.parse_ns + report.solve_ns)
+        );
+    }
+
+    Ok(())
+}
+
+fn solve(
+    author: Author,
+    day: u8,
+    part: Part,
+    input: Option<PathBuf>,
+) -> Result<report::Report, Box<dyn Error>> {
+    let solver = registry::find(author, day, part)?;
+    let input = match input {
+        Some(input) => input,
+        None => fetch::Fetcher::from_env()?.input(day)?,
+    };
+    let input_data = read_input(&input)?;
+
+    let timed = info_span!("solve", %author, day, %part)
+        .in_scope(|| solver.solve_timed(part, &input_data))
+        .map_err(|err| match err.diagnostic() {
+            Some(diagnostic) if input == Path::new(aoc_core::input::STDIN) => {
+                diagnostic.clone().in_file("<stdin>").into()
+            }
+            Some(diagnostic) => diagnostic.clone().in_file(&input).into(),
+            None => Box::<dyn Error>::from(err),
+        })?;
+
+    Ok(report::Report::new(author, day, part, timed, &input_data))
+}
+
+fn check(answers: &Path, author: Option<Author>, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let root = answers.parent().unwrap_or(Path::new(""));
+    let expected_answers: Vec<_> = answers::load(answers)?
+        .into_iter()
+        .filter(|expected| author.is_none_or(|author| expected.author == author))
+        .filter(|expected| day.is_none_or(|day| expected.day == day))
+        .collect();
+
+    let mut failed = 0;
+    for expected in &expected_answers {
+        let start = Instant::now();
+        match answers::check(expected, root) {
+            Ok(()) => println!("ok      {} ({:?})", expected, start.elapsed()),
+            Err(err) => {
+                failed += 1;
+                println!("FAILED  {}: {}", expected, err);
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed",
+        expected_answers.len() - failed,
+        failed
+    );
+    if failed > 0 {
+        return Err(format!("{} of {} answers failed", failed, expected_answers.len()).into());
+    }
+
+    Ok(())
+}
+
+fn diff(
+    day: Option<u8>,
+    part: Option<Part>,
+    inputs: &Inputs,
+    timeout: Duration,
+) -> Result<(), Box<dyn Error>> {
+    let parts = part.map_or(vec![Part::One, Part::Two], |part| vec![part]);
+    let mut compared = 0;
+    let mut disagreements = 0;
+    for part in parts {
+        let days = day.map_or_else(|| differential::shared_days(part), |day| vec![day]);
+        for day in days {
+            for (input, input_data) in inputs.for_day(day, part)? {
+                let comparison = differential::compare(day, part, &input_data, timeout);
+                compared += 1;
+                if comparison.agrees() {
+                    println!("agree     on {}: {}", input, comparison);
+                } else {
+                    disagreements += 1;
+                    println!("DISAGREE  on {}: {}", input, comparison);
+                }
+            }
+        }
+    }
+
+    println!("{} compared, {} disagreed", compared, disagreements);
+    if disagreements > 0 {
+        return Err(format!(
+            "authors disagreed on {} of {} inputs",
+            disagreements, compared
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+struct Filter {
+    author: Option<Author>,
+    day: Option<u8>,
+    part: Option<Part>,
+}
+
+fn bench(
+    filter: Filter,
+    inputs: &Inputs,
+    runs: u32,
+    timeout: Duration,
+    json: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let mut days: Vec<(u8, Part)> = SOLUTIONS
+        .iter()
+        .filter(|entry| filter.author.is_none_or(|author| entry.author == author))
+        .filter(|entry| filter.day.is_none_or(|day| entry.day == day))
+        .filter(|entry| filter.part.is_none_or(|part| entry.part == part))
+        .map(|entry| (entry.day, entry.part))
+        .collect();
+    days.sort();
+    days.dedup();
+
+    let mut measurements = Vec::new();
+    for (day, part) in days {
+        for (input, input_data) in inputs.for_day(day, part)? {
+            let first = measurements.len();
+            for entry in SOLUTIONS.iter().filter(|entry| {
+                entry.day == day
+                    && entry.part == part
+                    && filter.author.is_none_or(|author| entry.author == author)
+            }) {
+                measurements.push(bench::measure(entry, &input_data, &input, runs, timeout));
+            }
+            for table in bench::Table::group(&measurements[first..]) {
+                println!("{}", table);
+            }
+        }
+    }
+
+    if let Some(json) = json {
+        fs::write(&json, serde_json::to_string_pretty(&measurements)?)?;
+        println!(
+            "wrote {} measurements to {}",
+            measurements.len(),
+            json.display()
+        );
+    }
+
+    Ok(())
+}
+
+fn generate(day: u8, seed: u64, size: Option<usize>) -> Result<(), Box<dyn Error>> {
+    print!("{}", aoc_gen::generate(day, seed, size)?);
+    Ok(())
+}
+
+fn fetch(day: u8) -> Result<(), Box<dyn Error>> {
+    println!("{}", fetch::Fetcher::from_env()?.input(day)?.display());
+    Ok(())
+}
+
+fn submit(
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    author: Option<Author>,
+    input: Option<PathBuf>,
+    answers: PathBuf,
+) -> Result<(), Box<dyn Error>> {
+    let fetcher = fetch::Fetcher::from_env()?;
+    let solved_by = match author {
+        Some(author) => Some((author, input.map_or_else(|| fetcher.input(day), Ok)?)),
+        None => None,
+    };
+    let answer = match (answer, &solved_by) {
+        (Some(answer), _) => answer,
+        (None, Some((author, input))) => solve(*author, day, part, Some(input.clone()))?
+            .answer
+            .to_string(),
+        (None, None) => unreachable!("clap requires --answer or --author"),
+    };
+
+    // Only an input that can be read again is any use to `aoc check`
+    let answers_file = solved_by
+        .filter(|(_, input)| input != Path::new(aoc_core::input::STDIN))
+        .map(|(author, input)| submit::AnswersFile {
+            input: relative_to_answers(&answers, input),
+            path: answers,
+            author,
+        });
+
+    let verdict = submit::submit(&fetcher, day, part, &answer, answers_file.as_ref())?;
+    println!("{}: {}", answer, verdict);
+    if verdict != submit::Verdict::Correct {
+        return Err(format!("day {} part {} not solved yet", day, part).into());
+    }
+    if let Some(answers_file) = answers_file {
+        println!("added to {}", answers_file.path.display());
+    }
+    Ok(())
+}
+
+// Inputs inside the answers file's directory are kept relative to it, so the
+// file works from any checkout
+fn relative_to_answers(answers: &Path, input: PathBuf) -> PathBuf {
+    let root = match answers.parent() {
+        Some(root) if !root.as_os_str().is_empty() => root,
+        _ => Path::new("."),
+    };
+    match (fs::canonicalize(root), fs::canonicalize(&input)) {
+        (Ok(root), Ok(absolute)) => match absolute.strip_prefix(&root) {
+            Ok(relative) => relative.to_owned(),
+            Err(_) => absolute,
+        },
+        _ => input,
+    }
+}
+
+fn write_examples(
+    root: &Path,
+    page: &Path,
+    author: Option<Author>,
+    day: u8,
+    force: bool,
+) -> Result<(), Box<dyn Error>> {
+    let authors: Vec<Author> = match author {
+        Some(author) => vec![author],
+        None => Author::value_variants()
+            .iter()
+            .copied()
+            .filter(|&author| examples::day_crates(root, author, day).is_ok())
+            .collect(),
+    };
+    if authors.is_empty() {
+        return Err(format!("nobody has crates for day {} yet", day).into());
+    }
+
+    for author in authors {
+        for file in examples::write_examples(root, page, author, day, force)? {
+            println!("wrote {}", file.display());
+        }
+    }
+    Ok(())
+}
+
+fn show_leaderboard(
+    file: Option<PathBuf>,
+    id: Option<u64>,
+    format: leaderboard::Format,
+) -> Result<(), Box<dyn Error>> {
+    let file = match (file, id) {
+        (Some(file), _) => file,
+        (None, Some(id)) => fetch::Fetcher::from_env()?.leaderboard(id)?,
+        (None, None) => unreachable!("clap requires --file or --id"),
+    };
+    let leaderboard = leaderboard::parse(&read_input(&file)?)?;
+    print!("{}", leaderboard::report(&leaderboard, format));
+    Ok(())
+}
+
+fn ask_about_cubes(
+    input: Option<PathBuf>,
+    bag: Option<String>,
+    bag_file: Option<PathBuf>,
+    query: cubes::Query,
+) -> Result<(), Box<dyn Error>> {
+    let input = match input {
+        Some(input) => input,
+        None => fetch::Fetcher::from_env()?.input(2)?,
+    };
+    let games = cubes::load_games(&input)?;
+    let bag = match (bag, bag_file) {
+        (Some(bag), _) => Set::parse(&bag)?,
+        (None, Some(bag_file)) => Set::load(&bag_file)?,
+        (None, None) => Set::default_bag(),
+    };
+
+    for line in cubes::answer(&games, &bag, query) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+fn new(root: &Path, author: Author, day: u8) -> Result<(), Box<dyn Error>> {
+    for new_crate in scaffold::new_day(root, author, day)? {
+        println!("created {} in {}", new_crate.name, new_crate.dir);
+    }
+    println!(
+        "registered day {} for {}; rebuild aoc to run it",
+        day, author
+    );
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use std::time::Duration;
+
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+use aoc_core::Part;
+
+use crate::differential::{self, Outcome};
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+fn solved_days() -> Vec<u8> {
+    aoc_gen::GENERATORS
+        .iter()
+        .map(|generator| generator.day)
+        .collect()
+}
+
+/// Whatever a solution makes of `input`, it has to answer or return an error.
+/// Solutions still running after `TIMEOUT` are let off, as nonsense input can
+/// describe an enormous puzzle.
+fn check_no_panics(day: u8, input: &str) -> Result<(), TestCaseError> {
+    for part in [Part::One, Part::Two] {
+        let comparison = differential::compare(day, part, input, TIMEOUT);
+        let panicked = comparison
+            .outcomes
+            .iter()
+            .any(|(_, outcome)| *outcome == Outcome::Panicked);
+        prop_assert!(!panicked, "on {:?}: {}", input, comparison);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Delete,
+    Insert(&'static str),
+    Replace(&'static str),
+}
+
+/// Pieces of puzzle syntax, plus the numbers most likely to overflow.
+const SNIPPETS: &[&str] = &[
+    "0",
+    "1",
+    "9",
+    " ",
+    "\n",
+    "-",
+    ":",
+    ",",
+    ";",
+    "=",
+    ".",
+    "#",
+    "?",
+    "S",
+    "4294967295",
+    "9223372036854775807",
+    "18446744073709551615",
+];
+
+/// Inputs that once made a solution panic, so they're tried on every run.
+const KNOWN_CRASHES: &[(u8, &str)] = &[
+    (
+        18,
+        "R 9223372036854775807 (#70c710)\nD 5 (#0dc571)\nL 2 (#5713f0)\nU 5 (#d2c081)\n",
+    ),
+    (
+        18,
+        "R 4294967295 (#70c710)\nD 4294967295 (#0dc571)\nL 4294967295 (#5713f0)\nU 4294967295 (#d2c081)\n",
+    ),    (4, "Card 1: 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64 65 66 | 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64 65 66\n"),
+    (
+        7,
+        "32T3K 18446744073709551615\nT55J5 18446744073709551615\n",
+    ),
+    (15, "aaaa=4294967295\n"),
+];
+
+/// Inputs that once made a solution run forever, so they're tried on every
+/// run and have to finish in time as well as not panic.
+const KNOWN_HANGS: &[(u8, &str)] = &[(19, "in{x>1:in,A}\n\n{x=2,m=1,a=1,s=1}\n")];
+
+fn any_edit() -> impl Strategy<Value = Edit> {
+    let snippet = prop::sample::select(SNIPPETS);
+    prop_oneof![
+        Just(Edit::Delete),
+        snippet.clone().prop_map(Edit::Insert),
+        snippet.prop_map(Edit::Replace),
+    ]
+}
+
+/// A small generated input for `day` with a few characters deleted, or
+/// snippets inserted or swapped in for them, so the damage lands in otherwise
+/// well-formed input.
+fn damaged_input(day: u8, seed: u64, edits: &[(Index, Edit)]) -> String {
+    let generator = aoc_gen::find(day).unwrap();
+    let size = 5.clamp(generator.min_size, generator.max_size);
+    let mut input = aoc_gen::generate(day, seed, Some(size)).unwrap();
+    for (at, edit) in edits {
+        let boundaries: Vec<usize> = input
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain([input.len()])
+            .collect();
+        let at = at.index(boundaries.len());
+        let start = boundaries[at];
+        let end = boundaries.get(at + 1).copied().unwrap_or(start);
+        match *edit {
+            Edit::Delete => input.replace_range(start..end, ""),
+            Edit::Insert(snippet) => input.insert_str(start, snippet),
+            Edit::Replace(snippet) => input.replace_range(start..end, snippet),
+        }
+    }
+    input
+}
+
+#[test]
+fn known_crashes_never_panic() {
+    for &(day, input) in KNOWN_CRASHES {
+        check_no_panics(day, input).unwrap();
+    }
+}
+
+#[test]
+fn known_hangs_finish() {
+    for &(day, input) in KNOWN_HANGS {
+        for part in [Part::One, Part::Two] {
+            let comparison = differential::compare(day, part, input, TIMEOUT);
+            let finished = comparison
+                .outcomes
+                .iter()
+                .all(|(_, outcome)| matches!(outcome, Outcome::Answer(_) | Outcome::Failed(_)));
+            assert!(finished, "on {:?}: {}", input, comparison);
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn arbitrary_text_never_panics(
+        day in prop::sample::select(solved_days()),
+        input in "[0-9a-zA-Z .#:,;=(){}<>|/\\\\?@-]{0,40}(\n[0-9a-zA-Z .#:,;=(){}<>|/\\\\?@-]{0,40}){0,4}",
+    ) {
+        check_no_panics(day, &input)?;
+    }
+
+    #[test]
+    fn damaged_generated_input_never_panics(
+        day in prop::sample::select(solved_days()),
+        seed in any::<u64>(),
+        edits in prop::collection::vec((any::<Index>(), any_edit()), 1..=3),
+    ) {
+        check_no_panics(day, &damaged_input(day, seed, &edits))?;
+    }
+}
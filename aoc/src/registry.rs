@@ -0,0 +1,189 @@
+use std::fmt;
+
+use aoc_core::{Part, Solver};
+use clap::ValueEnum;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ValueEnum)]
+pub enum Author {
+    Lethalgem,
+    Mattdamachine,
+    Mohammad,
+}
+
+impl fmt::Display for Author {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Author::Lethalgem => "lethalgem",
+            Author::Mattdamachine => "mattdamachine",
+            Author::Mohammad => "mohammad",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+pub struct Entry {
+    pub author: Author,
+    pub day: u8,
+    pub part: Part,
+    pub solver: &'static dyn Solver,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RegistryError {
+    #[error("{author} has not implemented day {day}")]
+    DayNotImplemented { author: Author, day: u8 },
+    #[error("{author} has not implemented part {part} of day {day}")]
+    PartNotImplemented { author: Author, day: u8, part: Part },
+}
+
+pub fn find(author: Author, day: u8, part: Part) -> Result<&'static dyn Solver, RegistryError> {
+    let mut days = SOLUTIONS
+        .iter()
+        .filter(|entry| entry.author == author && entry.day == day)
+        .peekable();
+
+    if days.peek().is_none() {
+        return Err(RegistryError::DayNotImplemented { author, day });
+    }
+
+    days.find(|entry| entry.part == part)
+        .map(|entry| entry.solver)
+        .ok_or(RegistryError::PartNotImplemented { author, day, part })
+}
+
+macro_rules! entry {
+    ($author:ident, $day:literal, $part:ident, $solver:expr) => {
+        Entry {
+            author: Author::$author,
+            day: $day,
+            part: Part::$part,
+            solver: &$solver,
+        }
+    };
+}
+
+pub static SOLUTIONS: &[Entry] = &[
+    // lethalgem
+    entry!(Lethalgem, 1, One, lethalgem_day01_part1::Day1),
+    entry!(Lethalgem, 1, Two, lethalgem_day01_part2::Day1),
+    entry!(Lethalgem, 2, One, lethalgem_day02_part1::Day2),
+    entry!(Lethalgem, 2, Two, lethalgem_day02_part2::Day2),
+    entry!(Lethalgem, 3, One, lethalgem_day03_part1::Day3),
+    entry!(Lethalgem, 3, Two, lethalgem_day03_part2::Day3),
+    entry!(Lethalgem, 4, One, lethalgem_day04_part1::Day4),
+    entry!(Lethalgem, 4, Two, lethalgem_day04_part2::Day4),
+    entry!(Lethalgem, 5, One, lethalgem_day05_part1::Day5),
+    entry!(Lethalgem, 5, Two, lethalgem_day05_part2::Day5),
+    entry!(Lethalgem, 6, One, lethalgem_day06_part1::Day6),
+    entry!(Lethalgem, 6, Two, lethalgem_day06_part2::Day6),
+    entry!(Lethalgem, 7, One, lethalgem_day07_part1::Day7),
+    entry!(Lethalgem, 7, Two, lethalgem_day07_part2::Day7),
+    entry!(Lethalgem, 9, One, lethalgem_day09_part1::Day9),
+    entry!(Lethalgem, 9, Two, lethalgem_day09_part2::Day9),
+    entry!(Lethalgem, 11, One, lethalgem_day11_part1::Day11),
+    entry!(Lethalgem, 11, Two, lethalgem_day11_part2::Day11),
+    entry!(Lethalgem, 15, One, lethalgem_day15_part1::Day15),
+    entry!(Lethalgem, 15, Two, lethalgem_day15_part2::Day15),
+    entry!(Lethalgem, 16, One, lethalgem_day16_part1::Day16),
+    entry!(Lethalgem, 16, Two, lethalgem_day16_part2::Day16),
+    // mattdamachine
+    entry!(Mattdamachine, 1, One, mattdamachine_day01_part1::Day1),
+    entry!(Mattdamachine, 2, One, mattdamachine_day02_part1::Day2),
+    entry!(Mattdamachine, 4, One, mattdamachine_day04_part1::Day4),
+    entry!(Mattdamachine, 5, One, mattdamachine_day05_part1::Day5),
+    entry!(Mattdamachine, 6, One, mattdamachine_day06_part1::Day6),
+    entry!(Mattdamachine, 7, One, mattdamachine_day07_part1::Day7),
+    entry!(Mattdamachine, 8, One, mattdamachine_day08_part1::Day8),
+    entry!(Mattdamachine, 9, One, mattdamachine_day09_part1::Day9),
+    entry!(Mattdamachine, 10, One, mattdamachine_day10_part1::Day10),
+    entry!(Mattdamachine, 11, One, mattdamachine_day11_part1::Day11),
+    // mohammad
+    entry!(Mohammad, 1, One, mohammad_day01::Day1),
+    entry!(Mohammad, 1, Two, mohammad_day01::Day1),
+    entry!(Mohammad, 2, One, mohammad_day02::Day2),
+    entry!(Mohammad, 2, Two, mohammad_day02::Day2),
+    entry!(Mohammad, 3, One, mohammad_day03::Day3),
+    entry!(Mohammad, 3, Two, mohammad_day03::Day3),
+    entry!(Mohammad, 4, One, mohammad_day04::Day4),
+    entry!(Mohammad, 4, Two, mohammad_day04::Day4),
+    entry!(Mohammad, 5, One, mohammad_day05::Day5),
+    entry!(Mohammad, 5, Two, mohammad_day05::Day5),
+    entry!(Mohammad, 6, One, mohammad_day06::Day6),
+    entry!(Mohammad, 6, Two, mohammad_day06::Day6),
+    entry!(Mohammad, 7, One, mohammad_day07_part1::Day7),
+    entry!(Mohammad, 7, Two, mohammad_day07_part2::Day7),
+    entry!(Mohammad, 8, One, mohammad_day08_part1::Day8),
+    entry!(Mohammad, 8, Two, mohammad_day08_part2::Day8),
+    entry!(Mohammad, 9, One, mohammad_day09::Day9),
+    entry!(Mohammad, 9, Two, mohammad_day09::Day9),
+    entry!(Mohammad, 10, One, mohammad_day10::Day10),
+    entry!(Mohammad, 10, Two, mohammad_day10::Day10),
+    entry!(Mohammad, 11, One, mohammad_day11::Day11),
+    entry!(Mohammad, 11, Two, mohammad_day11::Day11),
+    entry!(Mohammad, 12, One, mohammad_day12::Day12),
+    entry!(Mohammad, 13, One, mohammad_day13::Day13),
+    entry!(Mohammad, 13, Two, mohammad_day13::Day13),
+    entry!(Mohammad, 14, One, mohammad_day14::Day14),
+    entry!(Mohammad, 14, Two, mohammad_day14::Day14),
+    entry!(Mohammad, 15, One, mohammad_day15::Day15),
+    entry!(Mohammad, 15, Two, mohammad_day15::Day15),
+    entry!(Mohammad, 16, One, mohammad_day16::Day16),
+    entry!(Mohammad, 16, Two, mohammad_day16::Day16),
+    entry!(Mohammad, 18, One, mohammad_day18::Day18),
+    entry!(Mohammad, 18, Two, mohammad_day18::Day18),
+    entry!(Mohammad, 19, One, mohammad_day19::Day19),
+    entry!(Mohammad, 21, One, mohammad_day21::Day21),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_implemented_part() {
+        let solver = find(Author::Mohammad, 14, Part::Two).unwrap();
+        let input = "O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\nO.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....";
+        assert_eq!(solver.solve(Part::Two, input).unwrap().to_string(), "64");
+    }
+
+    #[test]
+    fn reports_missing_day() {
+        let result = find(Author::Lethalgem, 8, Part::One);
+        assert_eq!(
+            result.err(),
+            Some(RegistryError::DayNotImplemented {
+                author: Author::Lethalgem,
+                day: 8
+            })
+        );
+    }
+
+    #[test]
+    fn reports_missing_part() {
+        let result = find(Author::Mattdamachine, 5, Part::Two);
+        assert_eq!(
+            result.err(),
+            Some(RegistryError::PartNotImplemented {
+                author: Author::Mattdamachine,
+                day: 5,
+                part: Part::Two
+            })
+        );
+    }
+
+    #[test]
+    fn registers_each_part_once() {
+        for entry in SOLUTIONS {
+            let matches = SOLUTIONS
+                .iter()
+                .filter(|other| {
+                    other.author == entry.author
+                        && other.day == entry.day
+                        && other.part == entry.part
+                })
+                .count();
+            assert_eq!(matches, 1);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::Write;
+
+use aoc_core::{Answer, Part, Timed};
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+use crate::bench::{author_name, part_number};
+use crate::registry::Author;
+
+/// One solved part, as `run --json` prints it. The answer keeps its type so
+/// big numbers come out as numbers rather than being rounded or quoted.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    #[serde(serialize_with = "author_name")]
+    pub author: Author,
+    pub day: u8,
+    #[serde(serialize_with = "part_number")]
+    pub part: Part,
+    #[serde(serialize_with = "typed_answer")]
+    pub answer: Answer,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub input_hash: String,
+}
+
+fn typed_answer<S: Serializer>(answer: &Answer, serializer: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        Answer::Signed(value) => serializer.serialize_i64(*value),
+        Answer::Unsigned(value) => serializer.serialize_u64(*value),
+        Answer::Text(value) => serializer.serialize_str(value),
+    }
+}
+
+impl Report {
+    pub fn new(author: Author, day: u8, part: Part, timed: Timed, input: &str) -> Self {
+        Report {
+            author,
+            day,
+            part,
+            answer: timed.answer,
+            parse_ns: timed.parse.as_nanos() as u64,
+            solve_ns: timed.solve.as_nanos() as u64,
+            input_hash: input_hash(input),
+        }
+    }
+}
+
+/// Hex SHA-256 of the input, so runs on the same input can be matched up
+/// without keeping the input itself.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn report(answer: Answer) -> Report {
+        let timed = Timed {
+            answer,
+            parse: Duration::from_nanos(1500),
+            solve: Duration::from_micros(2),
+        };
+        Report::new(Author::Mohammad, 18, Part::Two, timed, "abc")
+    }
+
+    #[test]
+    fn keeps_answer_types() {
+        let big = serde_json::to_string(&report(Answer::Unsigned(u64::MAX))).unwrap();
+        assert_eq!(
+            big,
+            "{\"author\":\"mohammad\",\"day\":18,\"part\":2,\"answer\":18446744073709551615,\
+             \"parse_ns\":1500,\"solve_ns\":2000,\"input_hash\":\
+             \"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\"}"
+        );
+
+        let negative = serde_json::to_value(report(Answer::Signed(-4))).unwrap();
+        assert_eq!(negative["answer"], serde_json::json!(-4));
+        let text = serde_json::to_value(report(Answer::from("LRR"))).unwrap();
+        assert_eq!(text["answer"], serde_json::json!("LRR"));
+    }
+}
@@ -0,0 +1,527 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_core::Part;
+use thiserror::Error;
+
+use crate::registry::{Author, SOLUTIONS};
+
+#[derive(Error, Debug)]
+pub enum ScaffoldError {
+    #[error("there is no day {0}, days run from 1 to 25")]
+    InvalidDay(u8),
+    #[error("{author} already has a solution to day {day}")]
+    AlreadySolved { author: Author, day: u8 },
+    #[error("{} already exists", .0.display())]
+    AlreadyExists(PathBuf),
+    #[error("no {author} crates in {} to add day {day} next to", .file.display())]
+    NowhereToRegister {
+        author: Author,
+        day: u8,
+        file: PathBuf,
+    },
+    #[error("{}: {source}", .path.display())]
+    Io { path: PathBuf, source: io::Error },
+}
+
+struct Template {
+    author: Author,
+    cargo_toml: &'static str,
+    main_rs: &'static str,
+    lib_rs: &'static str,
+    /// Where the example and the puzzle input go, relative to the crate.
+    inputs: &'static [&'static str],
+}
+
+static TEMPLATES: &[Template] = &[
+    Template {
+        author: Author::Lethalgem,
+        cargo_toml: include_str!("../templates/lethalgem/Cargo.toml.tmpl"),
+        main_rs: include_str!("../templates/lethalgem/main.rs.tmpl"),
+        lib_rs: include_str!("../templates/lethalgem/lib.rs.tmpl"),
+        inputs: &["src/example.txt", "src/input.txt"],
+    },
+    Template {
+        author: Author::Mattdamachine,
+        cargo_toml: include_str!("../templates/mattdamachine/Cargo.toml.tmpl"),
+        main_rs: include_str!("../templates/mattdamachine/main.rs.tmpl"),
+        lib_rs: include_str!("../templates/mattdamachine/lib.rs.tmpl"),
+        inputs: &["examples.txt", "input.txt"],
+    },
+    Template {
+        author: Author::Mohammad,
+        cargo_toml: include_str!("../templates/mohammad/Cargo.toml.tmpl"),
+        main_rs: include_str!("../templates/mohammad/main.rs.tmpl"),
+        lib_rs: include_str!("../templates/mohammad/lib.rs.tmpl"),
+        inputs: &["src/input_small"],
+    },
+];
+
+const FUZZ_TARGET: &str = include_str!("../templates/fuzz_target.rs.tmpl");
+const FUZZ_BIN: &str = include_str!("../templates/fuzz_bin.toml.tmpl");
+
+/// One crate of a new day. Mohammad solves both parts in one crate; the
+/// others have a crate per part.
+#[derive(Debug, PartialEq)]
+pub struct NewCrate {
+    pub dir: String,
+    pub name: String,
+    pub parts: Vec<Part>,
+}
+
+pub fn crates(author: Author, day: u8) -> Vec<NewCrate> {
+    match author {
+        Author::Lethalgem => [Part::One, Part::Two]
+            .into_iter()
+            .map(|part| NewCrate {
+                dir: format!("lethalgem/day_{}/part_{}", day, part),
+                name: format!("lethalgem_day{:02}_part{}", day, part),
+                parts: vec![part],
+            })
+            .collect(),
+        Author::Mattdamachine => [Part::One, Part::Two]
+            .into_iter()
+            .map(|part| NewCrate {
+                dir: format!("mattdamachine/Day{:02}/part_0{}", day, part),
+                name: format!("mattdamachine_day{:02}_part{}", day, part),
+                parts: vec![part],
+            })
+            .collect(),
+        Author::Mohammad => vec![NewCrate {
+            dir: format!("mohammad/day{:02}", day),
+            name: format!("mohammad_day{:02}", day),
+            parts: vec![Part::One, Part::Two],
+        }],
+    }
+}
+
+/// Where `author` keeps a day's example, relative to the crate.
+pub fn example_path(author: Author) -> &'static str {
+    TEMPLATES
+        .iter()
+        .find(|template| template.author == author)
+        .map(|template| template.inputs[0])
+        .unwrap()
+}
+
+fn render(template: &str, day: u8, new_crate: &NewCrate) -> String {
+    let depth = new_crate.dir.split('/').count();
+    let part_fn = match new_crate.parts[..] {
+        [Part::Two] => "part_two",
+        _ => "part_one",
+    };
+    template
+        .replace("{{crate}}", &new_crate.name)
+        .replace("{{day}}", &day.to_string())
+        .replace("{{part_fn}}", part_fn)
+        .replace("{{root}}", &vec![".."; depth].join("/"))
+}
+
+/// Creates `author`'s crates for `day` under `root` and registers them with
+/// the workspace, the runner and the fuzz targets. Returns the new crates.
+/// If anything fails, the crates are removed and the registering undone.
+pub fn new_day(root: &Path, author: Author, day: u8) -> Result<Vec<NewCrate>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+    if SOLUTIONS
+        .iter()
+        .any(|entry| entry.author == author && entry.day == day)
+    {
+        return Err(ScaffoldError::AlreadySolved { author, day });
+    }
+    let template = TEMPLATES
+        .iter()
+        .find(|template| template.author == author)
+        .unwrap();
+    let new_crates = crates(author, day);
+    for new_crate in &new_crates {
+        let dir = root.join(&new_crate.dir);
+        if dir.exists() {
+            return Err(ScaffoldError::AlreadyExists(dir));
+        }
+    }
+
+    let edits = register(root, author, day, &new_crates)?;
+    let written = new_crates
+        .iter()
+        .try_for_each(|new_crate| write_crate(root, template, day, new_crate))
+        .and_then(|()| edits.apply());
+    if let Err(error) = written {
+        edits.undo();
+        for new_crate in &new_crates {
+            let dir = root.join(&new_crate.dir);
+            let _ = fs::remove_dir_all(&dir);
+            // Leaves alone a day directory that already held something.
+            if let Some(parent) = dir.parent().filter(|parent| *parent != root) {
+                let _ = fs::remove_dir(parent);
+            }
+        }
+        return Err(error);
+    }
+    Ok(new_crates)
+}
+
+fn write_crate(
+    root: &Path,
+    template: &Template,
+    day: u8,
+    new_crate: &NewCrate,
+) -> Result<(), ScaffoldError> {
+    let dir = root.join(&new_crate.dir);
+    write(
+        &dir.join("Cargo.toml"),
+        &render(template.cargo_toml, day, new_crate),
+    )?;
+    write(
+        &dir.join("src/main.rs"),
+        &render(template.main_rs, day, new_crate),
+    )?;
+    write(
+        &dir.join("src/lib.rs"),
+        &render(template.lib_rs, day, new_crate),
+    )?;
+    for input in template.inputs {
+        write(&dir.join(input), "")?;
+    }
+    Ok(())
+}
+
+/// Changes to files outside the new crates, worked out in full before any of
+/// them is written, so a day that can't be registered leaves the tree alone.
+#[derive(Default)]
+struct Edits {
+    /// Each file's contents before and after, with no contents before for a
+    /// file that doesn't exist yet.
+    files: BTreeMap<PathBuf, (Option<String>, String)>,
+}
+
+impl Edits {
+    /// `path` as edited so far.
+    fn read(&mut self, path: &Path) -> Result<String, ScaffoldError> {
+        if let Some((_, after)) = self.files.get(path) {
+            return Ok(after.clone());
+        }
+        let text = read(path)?;
+        self.files
+            .insert(path.to_owned(), (Some(text.clone()), text.clone()));
+        Ok(text)
+    }
+
+    fn write(&mut self, path: &Path, contents: String) {
+        self.files
+            .entry(path.to_owned())
+            .or_insert((None, String::new()))
+            .1 = contents;
+    }
+
+    fn apply(&self) -> Result<(), ScaffoldError> {
+        for (path, (_, after)) in &self.files {
+            write(path, after)?;
+        }
+        Ok(())
+    }
+
+    /// Puts back every file as it was, as far as it can.
+    fn undo(&self) {
+        for (path, (before, _)) in &self.files {
+            let _ = match before {
+                Some(before) => fs::write(path, before),
+                None => fs::remove_file(path),
+            };
+        }
+    }
+}
+
+fn register(
+    root: &Path,
+    author: Author,
+    day: u8,
+    new_crates: &[NewCrate],
+) -> Result<Edits, ScaffoldError> {
+    let mut edits = Edits::default();
+    let members = new_crates
+        .iter()
+        .map(|new_crate| format!("    \"{}\",", new_crate.dir));
+    edit(&mut edits, root, "Cargo.toml", author, day, |text| {
+        insert_all(text, members, day, &format!("    \"{}/", author))
+    })?;
+
+    // The runner and the fuzz crate are both one directory down from the root.
+    let dependencies: Vec<String> = new_crates
+        .iter()
+        .map(|new_crate| format!("{} = {{ path = \"../{}\" }}", new_crate.name, new_crate.dir))
+        .collect();
+    for manifest in ["aoc/Cargo.toml", "fuzz/Cargo.toml"] {
+        edit(&mut edits, root, manifest, author, day, |text| {
+            let dependencies = dependencies.iter().cloned();
+            insert_all(text, dependencies, day, &format!("{}_day", author))
+        })?;
+    }
+
+    let entries = new_crates.iter().flat_map(|new_crate| {
+        new_crate.parts.iter().map(move |part| {
+            let part = match part {
+                Part::One => "One",
+                Part::Two => "Two",
+            };
+            format!(
+                "    entry!({:?}, {}, {}, {}::Day{}),",
+                author, day, part, new_crate.name, day
+            )
+        })
+    });
+    edit(
+        &mut edits,
+        root,
+        "aoc/src/registry.rs",
+        author,
+        day,
+        |text| insert_all(text, entries, day, &format!("    entry!({:?}, ", author)),
+    )?;
+
+    add_fuzz_target(&mut edits, root, day, new_crates)?;
+    Ok(edits)
+}
+
+/// Adds the new solvers to the day's fuzz target, creating it if this is the
+/// first solution to the day.
+fn add_fuzz_target(
+    edits: &mut Edits,
+    root: &Path,
+    day: u8,
+    new_crates: &[NewCrate],
+) -> Result<(), ScaffoldError> {
+    let target = root.join(format!("fuzz/fuzz_targets/day{:02}.rs", day));
+    let solvers: String = new_crates
+        .iter()
+        .map(|new_crate| format!("            &{}::Day{},\n", new_crate.name, day))
+        .collect();
+
+    if target.exists() {
+        let text = edits.read(&target)?;
+        let end = text.rfind("        ],").unwrap_or(text.len());
+        edits.write(
+            &target,
+            format!("{}{}{}", &text[..end], solvers, &text[end..]),
+        );
+        return Ok(());
+    }
+
+    edits.write(&target, FUZZ_TARGET.replace("{{solvers}}", &solvers));
+    let manifest = root.join("fuzz/Cargo.toml");
+    let text = edits.read(&manifest)?;
+    let bin = FUZZ_BIN.replace("{{day}}", &format!("{:02}", day));
+    edits.write(&manifest, text + &bin);
+    Ok(())
+}
+
+fn edit(
+    edits: &mut Edits,
+    root: &Path,
+    file: &str,
+    author: Author,
+    day: u8,
+    change: impl FnOnce(&str) -> Option<String>,
+) -> Result<(), ScaffoldError> {
+    let path = root.join(file);
+    let text = edits.read(&path)?;
+    let changed = change(&text).ok_or(ScaffoldError::NowhereToRegister {
+        author,
+        day,
+        file: path.clone(),
+    })?;
+    edits.write(&path, changed);
+    Ok(())
+}
+
+fn insert_all(
+    text: &str,
+    mut lines: impl Iterator<Item = String>,
+    day: u8,
+    prefix: &str,
+) -> Option<String> {
+    lines.try_fold(text.to_owned(), |text, line| {
+        insert_in_day_order(&text, &line, day, prefix)
+    })
+}
+
+/// Inserts `line` among the lines starting with `prefix`, after the last one
+/// for a day no later than `day`. `None` if no line starts with `prefix`.
+fn insert_in_day_order(text: &str, line: &str, day: u8, prefix: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let matching: Vec<usize> = (0..lines.len())
+        .filter(|&index| lines[index].starts_with(prefix))
+        .collect();
+    let first = *matching.first()?;
+    let at = matching
+        .iter()
+        .rev()
+        .find(|&&index| day_in(lines[index]).is_some_and(|found| found <= day))
+        .map_or(first, |index| index + 1);
+
+    let mut lines = lines;
+    lines.insert(at, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// The number after the first "day" in `line`, whatever its case and however
+/// it is separated, as in `day_9`, `Day09` or `lethalgem_day09_part1`.
+fn day_in(line: &str) -> Option<u8> {
+    let start = line.to_ascii_lowercase().find("day")? + "day".len();
+    let digits: String = line[start..]
+        .trim_start_matches('_')
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+pub(crate) fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+pub(crate) fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let io_error = |source| ScaffoldError::Io {
+        path: path.to_owned(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn names_crates_like_the_authors_do() {
+        let names: Vec<_> = crates(Author::Lethalgem, 17)
+            .into_iter()
+            .map(|new_crate| (new_crate.dir, new_crate.name))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (
+                    "lethalgem/day_17/part_1".to_owned(),
+                    "lethalgem_day17_part1".to_owned()
+                ),
+                (
+                    "lethalgem/day_17/part_2".to_owned(),
+                    "lethalgem_day17_part2".to_owned()
+                ),
+            ]
+        );
+        assert_eq!(
+            crates(Author::Mohammad, 7),
+            vec![NewCrate {
+                dir: "mohammad/day07".to_owned(),
+                name: "mohammad_day07".to_owned(),
+                parts: vec![Part::One, Part::Two],
+            }]
+        );
+    }
+
+    #[test]
+    fn fills_in_every_placeholder() {
+        for template in TEMPLATES {
+            for new_crate in crates(template.author, 17) {
+                for text in [template.cargo_toml, template.main_rs, template.lib_rs] {
+                    let rendered = render(text, 17, &new_crate);
+                    assert!(!rendered.contains("{{"), "{}", rendered);
+                }
+            }
+        }
+
+        let lethalgem = &crates(Author::Lethalgem, 17)[1];
+        let rendered = render(TEMPLATES[0].lib_rs, 17, lethalgem);
+        assert!(rendered.contains("pub enum Day17Error {"));
+        assert!(rendered.contains("fn part_two(&self"));
+        let rendered = render(TEMPLATES[0].cargo_toml, 17, lethalgem);
+        assert!(rendered.contains("path = \"../../../common/aoc_core\""));
+    }
+
+    #[test]
+    fn registers_in_day_order() {
+        let members = "[workspace]\nmembers = [\n    \"aoc\",\n    \"lethalgem/Day_1/part_1\",\n    \"lethalgem/day_9/part_1\",\n    \"lethalgem/day_16/part_1\",\n    \"mohammad/day01\",\n]\n";
+        let inserted = insert_in_day_order(
+            members,
+            "    \"lethalgem/day_11/part_1\",",
+            11,
+            "    \"lethalgem/",
+        );
+        assert_eq!(
+            inserted.unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"lethalgem/Day_1/part_1\",\n    \"lethalgem/day_9/part_1\",\n    \"lethalgem/day_11/part_1\",\n    \"lethalgem/day_16/part_1\",\n    \"mohammad/day01\",\n]\n"
+        );
+
+        let inserted =
+            insert_in_day_order(members, "    \"mohammad/day00\",", 0, "    \"mohammad/");
+        assert!(inserted
+            .unwrap()
+            .contains("day_16/part_1\",\n    \"mohammad/day00\",\n    \"mohammad/day01\""));
+
+        assert_eq!(
+            insert_in_day_order(members, "x", 1, "    \"mattdamachine/"),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_days_outside_the_calendar() {
+        let root = Path::new("/nonexistent");
+        for day in [0, 26] {
+            assert!(matches!(
+                new_day(root, Author::Mohammad, day),
+                Err(ScaffoldError::InvalidDay(found)) if found == day
+            ));
+        }
+    }
+
+    #[test]
+    fn leaves_nothing_behind_when_registering_fails() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let workspace = "[workspace]\nmembers = [\n    \"aoc\",\n    \"mohammad/day01\",\n]\n";
+        let manifest = "[dependencies]\nmohammad_day01 = { path = \"../mohammad/day01\" }\n";
+        write(&root.join("Cargo.toml"), workspace).unwrap();
+        write(&root.join("aoc/Cargo.toml"), manifest).unwrap();
+        write(&root.join("fuzz/Cargo.toml"), manifest).unwrap();
+        write(&root.join("aoc/src/registry.rs"), "// no entries\n").unwrap();
+
+        assert!(matches!(
+            new_day(&root, Author::Mohammad, 25),
+            Err(ScaffoldError::NowhereToRegister { file, .. }) if file.ends_with("registry.rs")
+        ));
+        assert!(!root.join("mohammad/day25").exists());
+        assert!(!root.join("fuzz/fuzz_targets").exists());
+        assert_eq!(read(&root.join("Cargo.toml")).unwrap(), workspace);
+        assert_eq!(read(&root.join("aoc/Cargo.toml")).unwrap(), manifest);
+        assert_eq!(read(&root.join("fuzz/Cargo.toml")).unwrap(), manifest);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn finds_the_day_in_paths_and_names() {
+        assert_eq!(day_in("    \"lethalgem/Day_1/Part_2\","), Some(1));
+        assert_eq!(
+            day_in("mattdamachine_day05_part1 = { path = \"../mattdamachine/Day05/part_1\" }"),
+            Some(5)
+        );
+        assert_eq!(
+            day_in("    entry!(Mohammad, 21, One, mohammad_day21::Day21),"),
+            Some(21)
+        );
+        assert_eq!(day_in("[workspace]"), None);
+    }
+}
@@ -0,0 +1,595 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_core::Part;
+use thiserror::Error;
+
+use crate::answers::{self, AnswersError, ExpectedAnswer};
+use crate::fetch::{self, FetchError, Fetcher};
+use crate::registry::Author;
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer wasn't looked at, because the last one was too recent.
+    RateLimited(Duration),
+    /// The answer wasn't looked at, because the part is already solved or
+    /// not unlocked yet.
+    WrongLevel,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => {
+                write!(f, "rate limited, try again in {}", minutes(*wait))
+            }
+            Verdict::WrongLevel => write!(f, "already solved or not unlocked yet"),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum SubmitError {
+    #[error(transparent)]
+    Fetch(#[from] FetchError),
+    #[error("{}: {0}", .1.display())]
+    Answers(AnswersError, PathBuf),
+    #[error("{answer} was already submitted for day {day} part {part} and was {verdict}")]
+    KnownWrong {
+        day: u8,
+        part: Part,
+        answer: String,
+        verdict: Verdict,
+    },
+    #[error("{answer} can't be right, {bound} was already {verdict}")]
+    OutOfBounds {
+        answer: String,
+        bound: String,
+        verdict: Verdict,
+    },
+    #[error("day {day} part {part} is already solved, with {answer}")]
+    AlreadySolved { day: u8, part: Part, answer: String },
+    #[error("the site asked to wait before answering again; try again in {}", minutes(*.0))]
+    Throttled(Duration),
+    #[error("can't make out the site's response: {0}")]
+    UnknownResponse(String),
+    #[error("{}: line {line}: expected `day part verdict answer`", .path.display())]
+    BadRecord { path: PathBuf, line: usize },
+    #[error("{}: {source}", .path.display())]
+    Io { path: PathBuf, source: io::Error },
+}
+
+/// Where a correct answer is also written down, as `author`'s answer on
+/// `input`, for `aoc check` to hold the solution to.
+pub struct AnswersFile {
+    pub path: PathBuf,
+    pub author: Author,
+    /// As the answers file has it, relative to its directory.
+    pub input: PathBuf,
+}
+
+impl AnswersFile {
+    fn entry(&self, day: u8, part: Part, answer: &str) -> ExpectedAnswer {
+        ExpectedAnswer {
+            author: self.author,
+            day,
+            part,
+            input: self.input.clone(),
+            answer: answer.to_owned(),
+        }
+    }
+
+    fn error(&self, err: AnswersError) -> SubmitError {
+        SubmitError::Answers(err, self.path.clone())
+    }
+}
+
+/// One answer the site has already judged, as kept in `submissions.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// The answers this user has submitted, and when the site will take the next
+/// one, kept next to their cached inputs.
+pub struct Submissions {
+    dir: PathBuf,
+    submissions: Vec<Submission>,
+    /// Seconds since the epoch.
+    next_allowed: u64,
+}
+
+impl Submissions {
+    pub fn load(dir: &Path) -> Result<Submissions, SubmitError> {
+        let path = dir.join("submissions.txt");
+        let text = read_if_exists(&path)?;
+        let mut submissions = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            submissions.push(
+                parse_submission(line).ok_or_else(|| SubmitError::BadRecord {
+                    path: path.clone(),
+                    line: index + 1,
+                })?,
+            );
+        }
+
+        let next_allowed = read_if_exists(&dir.join("next-submission"))?
+            .trim()
+            .parse()
+            .unwrap_or(0);
+
+        Ok(Submissions {
+            dir: dir.to_owned(),
+            submissions,
+            next_allowed,
+        })
+    }
+
+    /// Why `answer` needn't be sent, if the earlier submissions already
+    /// settle it.
+    pub fn refuse(&self, day: u8, part: Part, answer: &str) -> Option<SubmitError> {
+        let earlier = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.day == day && submission.part == part);
+        for submission in earlier {
+            let known = || submission.answer.clone();
+            match submission.verdict {
+                Verdict::Correct => {
+                    return Some(SubmitError::AlreadySolved {
+                        day,
+                        part,
+                        answer: known(),
+                    })
+                }
+                verdict if submission.answer == answer => {
+                    return Some(SubmitError::KnownWrong {
+                        day,
+                        part,
+                        answer: known(),
+                        verdict,
+                    })
+                }
+                verdict @ (Verdict::TooHigh | Verdict::TooLow) => {
+                    let (Ok(answer_value), Ok(bound)) =
+                        (answer.parse::<i128>(), submission.answer.parse::<i128>())
+                    else {
+                        continue;
+                    };
+                    if (verdict == Verdict::TooHigh && answer_value >= bound)
+                        || (verdict == Verdict::TooLow && answer_value <= bound)
+                    {
+                        return Some(SubmitError::OutOfBounds {
+                            answer: answer.to_owned(),
+                            bound: known(),
+                            verdict,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let wait = self.next_allowed.saturating_sub(now());
+        (wait > 0).then(|| SubmitError::Throttled(Duration::from_secs(wait)))
+    }
+
+    /// Keeps the site's verdict on `answer`, and how long it asked to wait
+    /// before the next one. A correct answer goes in the answers file too,
+    /// before it's kept here, so this never has a correct answer the answers
+    /// file is missing because writing it failed.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        answer: &str,
+        verdict: Verdict,
+        wait: Option<Duration>,
+        answers_file: Option<&AnswersFile>,
+    ) -> Result<(), SubmitError> {
+        if let (Verdict::Correct, Some(answers_file)) = (verdict, answers_file) {
+            answers::record(&answers_file.path, &answers_file.entry(day, part, answer))
+                .map_err(|err| answers_file.error(err))?;
+        }
+
+        if !matches!(verdict, Verdict::RateLimited(_) | Verdict::WrongLevel) {
+            self.submissions.push(Submission {
+                day,
+                part,
+                verdict,
+                answer: answer.to_owned(),
+            });
+            let text: String = self
+                .submissions
+                .iter()
+                .map(|submission| {
+                    format!(
+                        "{} {} {} {}\n",
+                        submission.day,
+                        submission.part,
+                        verdict_name(submission.verdict),
+                        submission.answer
+                    )
+                })
+                .collect();
+            self.write("submissions.txt", &text)?;
+        }
+
+        if let Some(wait) = wait {
+            self.next_allowed = now() + wait.as_secs();
+            self.write("next-submission", &self.next_allowed.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn write(&self, name: &str, contents: &str) -> Result<(), SubmitError> {
+        fetch::write(&self.dir.join(name), contents).map_err(|err| match err {
+            FetchError::Io { path, source } => SubmitError::Io { path, source },
+            err => err.into(),
+        })
+    }
+}
+
+/// Sends `answer` to the site unless an earlier submission, or the answers
+/// file, already settles it, and records the verdict.
+pub fn submit(
+    fetcher: &Fetcher,
+    day: u8,
+    part: Part,
+    answer: &str,
+    answers_file: Option<&AnswersFile>,
+) -> Result<Verdict, SubmitError> {
+    let mut submissions = Submissions::load(&fetcher.year_dir())?;
+    if let Some(refusal) = submissions.refuse(day, part, answer) {
+        return Err(refusal);
+    }
+    if let Some(answers_file) = answers_file {
+        let entry = answers_file.entry(day, part, answer);
+        let recorded =
+            answers::recorded(&answers_file.path, &entry).map_err(|err| answers_file.error(err))?;
+        if let Some(answer) = recorded {
+            return Err(SubmitError::AlreadySolved { day, part, answer });
+        }
+    }
+
+    let page = fetcher.post_answer(day, part, answer)?;
+    let (verdict, wait) = parse_response(&page)?;
+    submissions.record(day, part, answer, verdict, wait, answers_file)?;
+    Ok(verdict)
+}
+
+/// Reads the verdict, and any wait before the next answer, out of the page
+/// the site answers a submission with.
+pub fn parse_response(page: &str) -> Result<(Verdict, Option<Duration>), SubmitError> {
+    let message = article_text(page);
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Verdict::TooHigh
+        } else if message.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::RateLimited(left_to_wait(&message).unwrap_or(Duration::from_secs(60)))
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return Err(SubmitError::UnknownResponse(message));
+    };
+
+    let wait = match verdict {
+        Verdict::RateLimited(wait) => Some(wait),
+        _ => wait_before_retrying(&message),
+    };
+    Ok((verdict, wait))
+}
+
+/// The text of the page's `<article>`, which is where the site explains
+/// itself, with the markup stripped.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(page, |(_, rest)| {
+            rest.split_once("</article>").map_or(rest, |(body, _)| body)
+        });
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "You have 1m 5s left to wait."
+fn left_to_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for amount in wait.split_whitespace() {
+        seconds += match amount.split_at(amount.len().checked_sub(1)?) {
+            (minutes, "m") => minutes.parse::<u64>().ok()? * 60,
+            (secs, "s") => secs.parse::<u64>().ok()?,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Reads "Please wait one minute before trying again." and "please wait 5
+/// minutes before trying again."
+fn wait_before_retrying(message: &str) -> Option<Duration> {
+    let message = message.to_lowercase();
+    let (_, rest) = message.split_once("please wait ")?;
+    let amount = rest.split_whitespace().next()?;
+    let minutes = match amount {
+        "one" => 1,
+        amount => amount.parse::<u64>().ok()?,
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+fn parse_submission(line: &str) -> Option<Submission> {
+    let mut fields = line.splitn(4, ' ');
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let verdict = match fields.next()? {
+        "correct" => Verdict::Correct,
+        "too-high" => Verdict::TooHigh,
+        "too-low" => Verdict::TooLow,
+        "wrong" => Verdict::Wrong,
+        _ => return None,
+    };
+    let answer = fields.next().filter(|answer| !answer.is_empty())?;
+    Some(Submission {
+        day,
+        part,
+        verdict,
+        answer: answer.to_owned(),
+    })
+}
+
+fn verdict_name(verdict: Verdict) -> &'static str {
+    match verdict {
+        Verdict::Correct => "correct",
+        Verdict::TooHigh => "too-high",
+        Verdict::TooLow => "too-low",
+        Verdict::Wrong | Verdict::RateLimited(_) | Verdict::WrongLevel => "wrong",
+    }
+}
+
+fn read_if_exists(path: &Path) -> Result<String, SubmitError> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(source) => Err(SubmitError::Io {
+            path: path.to_owned(),
+            source,
+        }),
+    }
+}
+
+fn minutes(wait: Duration) -> String {
+    let secs = wait.as_secs();
+    match (secs / 60, secs % 60) {
+        (0, secs) => format!("{}s", secs),
+        (minutes, 0) => format!("{}m", minutes),
+        (minutes, secs) => format!("{}m {}s", minutes, secs),
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+    use crate::fetch::tests::{cache_dir, serve, LOG_IN};
+
+    const RIGHT: &str = r#"<main><article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to restoring snow operations. <a href="/2023/day/1#part2">[Continue to Part Two]</a></p></article></main>"#;
+    const TOO_HIGH: &str = r#"<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2023/about">about page</a>, or you can ask for hints on the <a href="https://www.reddit.com/r/adventofcode/" target="_blank">subreddit</a>.  Please wait one minute before trying again. <a href="/2023/day/1">[Return to Day 1]</a></p></article></main>"#;
+    const TOO_LOW: &str = r#"<main><article><p>That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again. <a href="/2023/day/1">[Return to Day 1]</a></p></article></main>"#;
+    const WRONG: &str = r#"<main><article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. <a href="/2023/day/1">[Return to Day 1]</a></p></article></main>"#;
+    const TOO_RECENT: &str = r#"<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 37s left to wait. <a href="/2023/day/1">[Return to Day 1]</a></p></article></main>"#;
+    const WRONG_LEVEL: &str = r#"<main><article><p>You don't seem to be solving the right level.  Did you already complete it? <a href="/2023/day/1">[Return to Day 1]</a></p></article></main>"#;
+
+    /// Judges day 1 part 1 like the puzzle site, with 142 as the answer, and
+    /// turns everything else away.
+    fn stub_site() -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        serve(|request| {
+            if !request.logged_in {
+                return ("400 Bad Request", LOG_IN.to_owned());
+            }
+            let page = match (request.path.as_str(), request.body.as_str()) {
+                ("/2023/day/1/answer", "level=1&answer=142") => RIGHT,
+                ("/2023/day/1/answer", "level=1&answer=200") => TOO_HIGH,
+                ("/2023/day/1/answer", "level=1&answer=100") => TOO_LOW,
+                ("/2023/day/1/answer", body) if body.starts_with("level=1&") => WRONG,
+                ("/2023/day/1/answer", _) => WRONG_LEVEL,
+                ("/2023/day/3/answer", _) => TOO_RECENT,
+                _ => return ("404 Not Found", "404 Not Found\n".to_owned()),
+            };
+            ("200 OK", page.to_owned())
+        })
+    }
+
+    fn fetcher(site: &str, name: &str) -> Fetcher {
+        Fetcher::new(site, cache_dir(name), Some("good".to_owned()))
+    }
+
+    #[test]
+    fn reads_the_verdict_and_wait() {
+        let minute = Some(Duration::from_secs(60));
+        let cases = [
+            (RIGHT, Verdict::Correct, None),
+            (TOO_HIGH, Verdict::TooHigh, minute),
+            (TOO_LOW, Verdict::TooLow, Some(Duration::from_secs(300))),
+            (WRONG, Verdict::Wrong, minute),
+            (WRONG_LEVEL, Verdict::WrongLevel, None),
+        ];
+        for (page, verdict, wait) in cases {
+            assert_eq!(parse_response(page).unwrap(), (verdict, wait));
+        }
+
+        let wait = Duration::from_secs(97);
+        assert_eq!(
+            parse_response(TOO_RECENT).unwrap(),
+            (Verdict::RateLimited(wait), Some(wait))
+        );
+        assert!(matches!(
+            parse_response("<html>Down for maintenance</html>"),
+            Err(SubmitError::UnknownResponse(_))
+        ));
+    }
+
+    #[test]
+    fn refuses_answers_already_ruled_out() {
+        let (site, requests) = stub_site();
+        let fetcher = fetcher(&site, "ruled-out");
+
+        assert_eq!(
+            submit(&fetcher, 1, Part::One, "200", None).unwrap(),
+            Verdict::TooHigh
+        );
+        assert!(matches!(
+            submit(&fetcher, 1, Part::One, "200", None),
+            Err(SubmitError::KnownWrong {
+                verdict: Verdict::TooHigh,
+                ..
+            })
+        ));
+        assert!(matches!(
+            submit(&fetcher, 1, Part::One, "250", None),
+            Err(SubmitError::OutOfBounds { .. })
+        ));
+        // The site asked for a minute's wait after the wrong answer.
+        assert!(matches!(
+            submit(&fetcher, 1, Part::One, "142", None),
+            Err(SubmitError::Throttled(_))
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let submissions = Submissions::load(&fetcher.year_dir()).unwrap();
+        assert_eq!(
+            submissions.submissions,
+            [Submission {
+                day: 1,
+                part: Part::One,
+                verdict: Verdict::TooHigh,
+                answer: "200".to_owned(),
+            }]
+        );
+    }
+
+    fn answers_file(fetcher: &Fetcher) -> AnswersFile {
+        fs::create_dir_all(fetcher.year_dir()).unwrap();
+        AnswersFile {
+            path: fetcher.year_dir().join("answers.txt"),
+            author: Author::Mohammad,
+            input: PathBuf::from("mohammad/day01/src/input_large"),
+        }
+    }
+
+    #[test]
+    fn records_the_right_answer() {
+        let (site, requests) = stub_site();
+        let fetcher = fetcher(&site, "right");
+        let answers_file = answers_file(&fetcher);
+
+        assert_eq!(
+            submit(&fetcher, 1, Part::One, "142", Some(&answers_file)).unwrap(),
+            Verdict::Correct
+        );
+        assert!(matches!(
+            submit(&fetcher, 1, Part::One, "143", None),
+            Err(SubmitError::AlreadySolved { answer, .. }) if answer == "142"
+        ));
+        assert_eq!(
+            submit(&fetcher, 1, Part::Two, "281", Some(&answers_file)).unwrap(),
+            Verdict::WrongLevel
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        let text = fs::read_to_string(fetcher.year_dir().join("submissions.txt")).unwrap();
+        assert_eq!(text, "1 1 correct 142\n");
+        let text = fs::read_to_string(&answers_file.path).unwrap();
+        assert_eq!(text, "mohammad 1 1 mohammad/day01/src/input_large 142\n");
+    }
+
+    #[test]
+    fn goes_by_the_answers_file() {
+        let (site, requests) = stub_site();
+        let fetcher = fetcher(&site, "answers-file");
+        let answers_file = answers_file(&fetcher);
+        fetch::write(
+            &answers_file.path,
+            "mohammad 1 1 mohammad/day01/src/input_large 142\n",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            submit(&fetcher, 1, Part::One, "142", Some(&answers_file)),
+            Err(SubmitError::AlreadySolved { answer, .. }) if answer == "142"
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+        fetch::write(&answers_file.path, "mohammad 1 1\n").unwrap();
+        assert!(matches!(
+            submit(&fetcher, 1, Part::One, "142", Some(&answers_file)),
+            Err(SubmitError::Answers(
+                AnswersError::MissingField { line: 1 },
+                _
+            ))
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn waits_out_the_rate_limit() {
+        let (site, requests) = stub_site();
+        let fetcher = fetcher(&site, "rate-limited");
+
+        let Verdict::RateLimited(wait) = submit(&fetcher, 3, Part::One, "4361", None).unwrap()
+        else {
+            panic!("expected the answer to be turned away");
+        };
+        assert_eq!(wait, Duration::from_secs(97));
+        assert!(matches!(
+            submit(&fetcher, 3, Part::One, "4361", None),
+            Err(SubmitError::Throttled(_))
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+}
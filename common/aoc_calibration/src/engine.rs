@@ -0,0 +1,227 @@
+use std::io::{self, BufRead};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::Mutex;
+use std::thread;
+
+use aoc_core::Diagnostic;
+use thiserror::Error;
+
+use crate::Scanner;
+
+#[derive(Error, Debug)]
+pub enum CalibrationError {
+    #[error("Cannot read calibration document: {0}")]
+    UnableToRead(#[from] io::Error),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+/// Whole lines read from the document, and the index of the first of them.
+struct Batch {
+    first_line: usize,
+    text: String,
+}
+
+/// Sums the calibration values of a document of any size. One thread reads
+/// it in batches of whole lines while the others scan them, and only a few
+/// batches are held at once, so memory stays bounded however big it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Engine {
+    threads: usize,
+    batch_bytes: usize,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Engine {
+            threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            batch_bytes: 1 << 16,
+        }
+    }
+}
+
+impl Engine {
+    /// How many threads scan lines, at least one.
+    pub fn threads(mut self, threads: usize) -> Engine {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Roughly how much of the document goes to a thread at a time. A batch
+    /// always holds at least one line.
+    pub fn batch_bytes(mut self, batch_bytes: usize) -> Engine {
+        self.batch_bytes = batch_bytes;
+        self
+    }
+
+    /// The sum of every line's calibration value for each scanner, in one
+    /// pass over `reader`. A line any scanner finds nothing in is an error,
+    /// and the earliest such line is the one reported.
+    pub fn sums<const N: usize>(
+        &self,
+        reader: impl BufRead,
+        scanners: [&Scanner; N],
+    ) -> Result<[u64; N], CalibrationError> {
+        let (sender, receiver) = mpsc::sync_channel(self.threads * 2);
+        let receiver = Mutex::new(receiver);
+        let failed = AtomicBool::new(false);
+
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|_| scope.spawn(|| scan_batches(&receiver, &scanners, &failed)))
+                .collect();
+            let read = self.read_batches(reader, sender, &failed);
+
+            let mut sums = [0; N];
+            let mut earliest: Option<Diagnostic> = None;
+            for worker in workers {
+                match worker.join().expect("calibration worker panicked") {
+                    Ok(worker_sums) => {
+                        for (sum, worker_sum) in sums.iter_mut().zip(worker_sums) {
+                            *sum += worker_sum;
+                        }
+                    }
+                    Err(diagnostic) => {
+                        if earliest.as_ref().is_none_or(|e| diagnostic.line < e.line) {
+                            earliest = Some(diagnostic);
+                        }
+                    }
+                }
+            }
+
+            read?;
+            match earliest {
+                Some(diagnostic) => Err(diagnostic.into()),
+                None => Ok(sums),
+            }
+        })
+    }
+
+    fn read_batches(
+        &self,
+        mut reader: impl BufRead,
+        sender: SyncSender<Batch>,
+        failed: &AtomicBool,
+    ) -> io::Result<()> {
+        let mut line_count = 0;
+        loop {
+            let mut batch = Batch {
+                first_line: line_count,
+                text: String::new(),
+            };
+            while batch.text.len() < self.batch_bytes.max(1) {
+                if reader.read_line(&mut batch.text)? == 0 {
+                    break;
+                }
+                line_count += 1;
+            }
+
+            // Every batch before a bad line has been sent by now, so the
+            // earliest bad line is still found
+            if batch.text.is_empty() || failed.load(Ordering::Relaxed) {
+                return Ok(());
+            }
+            if sender.send(batch).is_err() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn scan_batches<const N: usize>(
+    receiver: &Mutex<Receiver<Batch>>,
+    scanners: &[&Scanner; N],
+    failed: &AtomicBool,
+) -> Result<[u64; N], Diagnostic> {
+    let mut sums = [0; N];
+    let mut error = None;
+    loop {
+        // The lock is only held while waiting, not while scanning
+        let received = receiver.lock().unwrap().recv();
+        let Ok(batch) = received else {
+            break;
+        };
+        // Once a line is bad, batches still in flight are only drained, so
+        // the reader never blocks on a full channel
+        if error.is_some() {
+            continue;
+        }
+        if let Err(diagnostic) = scan_batch(&batch, scanners, &mut sums) {
+            failed.store(true, Ordering::Relaxed);
+            error = Some(diagnostic);
+        }
+    }
+    error.map_or(Ok(sums), Err)
+}
+
+fn scan_batch(batch: &Batch, scanners: &[&Scanner], sums: &mut [u64]) -> Result<(), Diagnostic> {
+    for (index, line) in batch.text.lines().enumerate() {
+        for (sum, scanner) in sums.iter_mut().zip(scanners) {
+            let Some(value) = scanner.calibration_value(line) else {
+                let diagnostic = Diagnostic::new(line, line, "expected a digit in the line");
+                return Err(diagnostic.after_lines(batch.first_line + index));
+            };
+            *sum += u64::from(value);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n\
+                           4nineeightseven2\nzoneight234\n7pqrstsixteen\n";
+
+    #[test]
+    fn matches_scanning_line_by_line() {
+        let document = EXAMPLE.repeat(500);
+        let expected: u64 = document
+            .lines()
+            .map(|line| u64::from(Scanner::spelled_digits().calibration_value(line).unwrap()))
+            .sum();
+
+        for threads in [1, 3, 8] {
+            let engine = Engine::default().threads(threads).batch_bytes(100);
+            let sums = engine.sums(document.as_bytes(), [Scanner::spelled_digits()]);
+            assert_eq!(sums.unwrap(), [expected]);
+        }
+        assert_eq!(
+            Engine::default()
+                .sums(EXAMPLE.as_bytes(), [Scanner::spelled_digits()])
+                .unwrap(),
+            [281]
+        );
+    }
+
+    #[test]
+    fn sums_for_several_scanners_in_one_pass() {
+        let document = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\nxtwone3four";
+        let sums = Engine::default().threads(2).batch_bytes(1).sums(
+            document.as_bytes(),
+            [Scanner::digits(), Scanner::spelled_digits()],
+        );
+        assert_eq!(sums.unwrap(), [142 + 33, 142 + 24]);
+    }
+
+    #[test]
+    fn reports_the_earliest_bad_line() {
+        let mut document = "12\n".repeat(1000);
+        document.push_str("one\n");
+        document.push_str(&"34\nnope\n".repeat(1000));
+
+        let err = Engine::default()
+            .threads(4)
+            .batch_bytes(10)
+            .sums(document.as_bytes(), [Scanner::digits()])
+            .unwrap_err();
+        let CalibrationError::Malformed(diagnostic) = err else {
+            panic!("expected a diagnostic, got {:?}", err);
+        };
+        assert_eq!(diagnostic.line, 1001);
+        assert_eq!(diagnostic.snippet, "one");
+    }
+}
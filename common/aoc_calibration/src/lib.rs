@@ -0,0 +1,265 @@
+mod engine;
+mod vocabulary;
+
+use std::collections::VecDeque;
+use std::sync::LazyLock;
+
+pub use engine::{CalibrationError, Engine};
+pub use vocabulary::{Vocabulary, VocabularyError};
+
+static DIGITS: LazyLock<Scanner> = LazyLock::new(|| Vocabulary::new::<&str>([]).scanner());
+static SPELLED_DIGITS: LazyLock<Scanner> = LazyLock::new(|| Vocabulary::english().scanner());
+
+/// Where one of a [`Scanner`]'s patterns was found, as byte offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+/// Finds every pattern in a line in a single pass, including ones that
+/// overlap, like the `eight` and `two` in `eightwo`. This is an Aho-Corasick
+/// automaton with the failure links folded into a full transition table.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    // 256 transitions per state, indexed by byte
+    transitions: Vec<u32>,
+    // The patterns that end in each state, longest first
+    outputs: Vec<Vec<usize>>,
+    // Length and value of each pattern
+    patterns: Vec<(usize, u32)>,
+}
+
+impl Scanner {
+    /// Builds a scanner for `(pattern, value)` pairs. Empty patterns are
+    /// ignored, and if a pattern is given twice the first value wins.
+    pub fn new<'p>(patterns: impl IntoIterator<Item = (&'p str, u32)>) -> Scanner {
+        Scanner::build(patterns, false)
+    }
+
+    /// Like [`Scanner::new`], but ASCII letters match whatever their case.
+    pub fn ignoring_case<'p>(patterns: impl IntoIterator<Item = (&'p str, u32)>) -> Scanner {
+        Scanner::build(patterns, true)
+    }
+
+    fn build<'p>(patterns: impl IntoIterator<Item = (&'p str, u32)>, ignore_case: bool) -> Scanner {
+        const MISSING: u32 = u32::MAX;
+
+        let mut scanner = Scanner {
+            transitions: vec![MISSING; 256],
+            outputs: vec![Vec::new()],
+            patterns: Vec::new(),
+        };
+
+        for (pattern, value) in patterns {
+            if pattern.is_empty() {
+                continue;
+            }
+            let mut state = 0;
+            for mut byte in pattern.bytes() {
+                if ignore_case {
+                    byte = byte.to_ascii_lowercase();
+                }
+                let index = state * 256 + byte as usize;
+                if scanner.transitions[index] == MISSING {
+                    scanner.transitions[index] = scanner.outputs.len() as u32;
+                    scanner.transitions.extend([MISSING; 256]);
+                    scanner.outputs.push(Vec::new());
+                }
+                state = scanner.transitions[index] as usize;
+            }
+            if scanner.outputs[state].is_empty() {
+                scanner.outputs[state].push(scanner.patterns.len());
+                scanner.patterns.push((pattern.len(), value));
+            }
+        }
+
+        // Breadth first, so a state's failure link is finished before it is
+        // needed. Missing transitions take the failure link's instead.
+        let mut failure = vec![0; scanner.outputs.len()];
+        let mut queue = VecDeque::new();
+        for byte in 0..256 {
+            match scanner.transitions[byte] {
+                MISSING => scanner.transitions[byte] = 0,
+                next => queue.push_back(next as usize),
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            let inherited = scanner.outputs[failure[state]].clone();
+            scanner.outputs[state].extend(inherited);
+            for byte in 0..256 {
+                let fallback = scanner.transitions[failure[state] * 256 + byte];
+                let index = state * 256 + byte;
+                match scanner.transitions[index] {
+                    MISSING => scanner.transitions[index] = fallback,
+                    next => {
+                        failure[next as usize] = fallback as usize;
+                        queue.push_back(next as usize);
+                    }
+                }
+            }
+        }
+
+        // Upper case letters go wherever their lower case ones do
+        if ignore_case {
+            for state in 0..scanner.outputs.len() {
+                for upper in b'A'..=b'Z' {
+                    let lower =
+                        scanner.transitions[state * 256 + upper.to_ascii_lowercase() as usize];
+                    scanner.transitions[state * 256 + upper as usize] = lower;
+                }
+            }
+        }
+
+        scanner
+    }
+
+    /// Just the digits `0` to `9`.
+    pub fn digits() -> &'static Scanner {
+        &DIGITS
+    }
+
+    /// The digits `0` to `9` and the words `one` to `nine`.
+    pub fn spelled_digits() -> &'static Scanner {
+        &SPELLED_DIGITS
+    }
+
+    /// Every match in `line`, in the order they end.
+    pub fn find_iter<'s>(&'s self, line: &'s str) -> impl Iterator<Item = Match> + 's {
+        let mut state = 0;
+        line.bytes().enumerate().flat_map(move |(i, byte)| {
+            state = self.transitions[state * 256 + byte as usize] as usize;
+            self.outputs[state].iter().map(move |&pattern| {
+                let (len, value) = self.patterns[pattern];
+                Match {
+                    start: i + 1 - len,
+                    end: i + 1,
+                    value,
+                }
+            })
+        })
+    }
+
+    /// The values of the first and last matches in `line`, going by where
+    /// they start. Where two start together the longer one counts.
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        let mut first: Option<Match> = None;
+        let mut last: Option<Match> = None;
+        for found in self.find_iter(line) {
+            if first.is_none_or(|first| (found.start, first.end) < (first.start, found.end)) {
+                first = Some(found);
+            }
+            if last.is_none_or(|last| (found.start, found.end) > (last.start, last.end)) {
+                last = Some(found);
+            }
+        }
+        Some((first?.value, last?.value))
+    }
+
+    /// The first digit of the first match followed by the last digit of the
+    /// last one, so a line ending in `twenty` ends in 0.
+    pub fn calibration_value(&self, line: &str) -> Option<u32> {
+        let (mut first, last) = self.first_and_last(line)?;
+        while first >= 10 {
+            first /= 10;
+        }
+        Some(first * 10 + last % 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const DIGITS: [(&str, u32); 19] = [
+        ("0", 0),
+        ("1", 1),
+        ("2", 2),
+        ("3", 3),
+        ("4", 4),
+        ("5", 5),
+        ("6", 6),
+        ("7", 7),
+        ("8", 8),
+        ("9", 9),
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+    ];
+
+    // Checks every position against every pattern
+    fn naive_first_and_last(line: &str) -> Option<(u32, u32)> {
+        let found: Vec<(usize, usize, u32)> = (0..line.len())
+            .flat_map(|start| {
+                DIGITS
+                    .iter()
+                    .filter(move |(pattern, _)| line[start..].starts_with(pattern))
+                    .map(move |&(pattern, value)| (start, pattern.len(), value))
+            })
+            .collect();
+        let first = found
+            .iter()
+            .min_by_key(|&&(start, len, _)| (start, usize::MAX - len))?;
+        let last = found.iter().max_by_key(|&&(start, len, _)| (start, len))?;
+        Some((first.2, last.2))
+    }
+
+    #[test]
+    fn finds_overlapping_words() {
+        let scanner = Scanner::spelled_digits();
+        assert_eq!(scanner.first_and_last("eighthree"), Some((8, 3)));
+        assert_eq!(scanner.first_and_last("xtwone3four"), Some((2, 4)));
+        assert_eq!(scanner.first_and_last("zoneight"), Some((1, 8)));
+        assert_eq!(scanner.first_and_last("7pqrstsixteen"), Some((7, 6)));
+        assert_eq!(scanner.first_and_last("treb7uchet"), Some((7, 7)));
+        assert_eq!(scanner.first_and_last("abc"), None);
+    }
+
+    #[test]
+    fn reports_where_matches_are() {
+        let found: Vec<_> = Scanner::spelled_digits().find_iter("twone").collect();
+        assert_eq!(
+            found,
+            vec![
+                Match {
+                    start: 0,
+                    end: 3,
+                    value: 2
+                },
+                Match {
+                    start: 2,
+                    end: 5,
+                    value: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn prefers_the_longer_of_two_matches_at_the_same_place() {
+        let scanner = Scanner::new([("seven", 7), ("seventeen", 17), ("teen", 10)]);
+        assert_eq!(scanner.first_and_last("seventeen"), Some((17, 10)));
+        assert_eq!(scanner.first_and_last("sevenx"), Some((7, 7)));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_a_naive_search(
+            line in "(one|two|three|four|five|six|seven|eight|nine|[a-z]|[0-9]){0,12}"
+        ) {
+            prop_assert_eq!(
+                Scanner::spelled_digits().first_and_last(&line),
+                naive_first_and_last(&line)
+            );
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use aoc_core::{diagnostic, read_input, Diagnostic, InputError};
+use thiserror::Error;
+
+use crate::Scanner;
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+#[derive(Error, Debug)]
+pub enum VocabularyError {
+    #[error("Cannot load vocabulary: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+/// The number words a calibration line may spell its digits with, on top of
+/// the digits themselves. Words can stand for numbers of more than one digit,
+/// like `seventeen` or `vingt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    ignore_case: bool,
+}
+
+impl Vocabulary {
+    pub fn new<W: Into<String>>(words: impl IntoIterator<Item = (W, u32)>) -> Vocabulary {
+        Vocabulary {
+            words: words
+                .into_iter()
+                .map(|(word, value)| (word.into(), value))
+                .collect(),
+            ignore_case: false,
+        }
+    }
+
+    /// `one` to `nine`, as the puzzle has them.
+    pub fn english() -> Vocabulary {
+        Vocabulary::new(ENGLISH)
+    }
+
+    /// Reads a vocabulary with one `word = value` per line, such as
+    /// `deux = 2`. Blank lines and lines starting with `#` are skipped.
+    pub fn parse(input: &str) -> Result<Vocabulary, Diagnostic> {
+        let mut words = Vec::new();
+        let mut seen = HashMap::new();
+        for line in input.lines() {
+            let entry = line.trim();
+            if entry.is_empty() || entry.starts_with('#') {
+                continue;
+            }
+
+            let Some((word, value)) = entry.split_once('=') else {
+                return Err(Diagnostic::new(input, entry, "expected `word = value`"));
+            };
+            let (word, value) = (word.trim(), value.trim());
+            if word.is_empty() {
+                return Err(Diagnostic::new(input, entry, "expected a word before `=`"));
+            }
+            let value: u32 = diagnostic::parse(input, value)?;
+            if let Some(previous) = seen.insert(word, value) {
+                let message = format!("`{}` is already given as {}", word, previous);
+                return Err(Diagnostic::new(input, word, message));
+            }
+            words.push((word.to_owned(), value));
+        }
+        Ok(Vocabulary {
+            words,
+            ignore_case: false,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Vocabulary, VocabularyError> {
+        let input = read_input(path)?;
+        Vocabulary::parse(&input).map_err(|diagnostic| diagnostic.in_file(path).into())
+    }
+
+    /// Matches the words whatever the case of their ASCII letters, so `Nine`
+    /// and `NINE` count as well as `nine`.
+    pub fn ignore_case(mut self) -> Vocabulary {
+        self.ignore_case = true;
+        self
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    /// A scanner for the digits `0` to `9` and these words.
+    pub fn scanner(&self) -> Scanner {
+        let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"]
+            .into_iter()
+            .zip(0..);
+        let patterns = digits.chain(self.words());
+        if self.ignore_case {
+            Scanner::ignoring_case(patterns)
+        } else {
+            Scanner::new(patterns)
+        }
+    }
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Vocabulary::english()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_vocabulary_files() {
+        let vocabulary =
+            Vocabulary::parse("# Spanish\nuno = 1\n\n  dos=2\ndiecisiete = 17\n").unwrap();
+        assert_eq!(
+            vocabulary.words().collect::<Vec<_>>(),
+            vec![("uno", 1), ("dos", 2), ("diecisiete", 17)]
+        );
+
+        let err = Vocabulary::parse("uno = 1\ndos 2\n").unwrap_err();
+        assert_eq!(err.message, "expected `word = value`");
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = Vocabulary::parse("uno = 1\nuno = 2\n").unwrap_err();
+        assert_eq!(err.message, "`uno` is already given as 1");
+        let err = Vocabulary::parse("uno = one\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+    }
+
+    #[test]
+    fn reads_other_languages_and_bigger_numbers() {
+        let french = Vocabulary::parse("un = 1\ndeux = 2\ntrois = 3\ndix-sept = 17\nvingt = 20")
+            .unwrap()
+            .scanner();
+        assert_eq!(french.calibration_value("xdeuxtrois"), Some(23));
+        assert_eq!(french.calibration_value("dix-sept4"), Some(14));
+        assert_eq!(french.calibration_value("8vingt"), Some(80));
+        assert_eq!(french.calibration_value("two"), None);
+    }
+
+    #[test]
+    fn ignores_case_when_asked() {
+        let line = "xNINEabcOne";
+        assert_eq!(
+            Vocabulary::english().scanner().calibration_value(line),
+            None
+        );
+        let scanner = Vocabulary::english().ignore_case().scanner();
+        assert_eq!(scanner.calibration_value(line), Some(91));
+    }
+}
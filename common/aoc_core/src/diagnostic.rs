@@ -0,0 +1,232 @@
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Where and why a puzzle input failed to parse, shown like a compiler error:
+///
+/// ```text
+/// expected red, green or blue, found `purple`
+///  --> input.txt:3:11
+///   |
+/// 3 | Game 3: 8 purple, 6 blue
+///   |           ^^^^^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub file: Option<PathBuf>,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, counted in characters.
+    pub column: usize,
+    /// How many characters to underline, at least one.
+    pub len: usize,
+    /// The whole line the error is on.
+    pub snippet: String,
+}
+
+impl Diagnostic {
+    /// Points at `span` within `source`. `span` should be sliced out of
+    /// `source`; failing that the first copy of it in `source` is used, and
+    /// failing that the start of `source`.
+    pub fn new(source: &str, span: &str, message: impl Into<String>) -> Self {
+        let start = offset_in(source, span)
+            .or_else(|| source.find(span))
+            .unwrap_or(0);
+        let line_start = source[..start].rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |newline| start + newline);
+        let snippet = source[line_start..line_end].trim_end_matches('\r');
+
+        Diagnostic {
+            message: message.into(),
+            file: None,
+            line: source[..start].matches('\n').count() + 1,
+            column: source[line_start..start].chars().count() + 1,
+            len: span.lines().next().unwrap_or("").chars().count().max(1),
+            snippet: snippet.to_owned(),
+        }
+    }
+
+    /// Points just past the end of `source`, for inputs that stop too soon.
+    pub fn at_end(source: &str, message: impl Into<String>) -> Self {
+        let source = source.trim_end();
+        Diagnostic::new(source, &source[source.len()..], message)
+    }
+
+    /// Moves the diagnostic down by `lines`, for parsers that were only given
+    /// part of the input starting at that line offset.
+    pub fn after_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Moves a diagnostic reported against `part` onto `source`, which `part`
+    /// was sliced out of, so parsers can report against just the piece they
+    /// were given.
+    pub fn within(self, source: &str, part: &str) -> Self {
+        let outer = Diagnostic::new(source, part, "");
+        let line = outer.line + self.line - 1;
+        let column = if self.line == 1 {
+            outer.column + self.column - 1
+        } else {
+            self.column
+        };
+        let snippet = source
+            .lines()
+            .nth(line - 1)
+            .map_or(self.snippet, str::to_owned);
+
+        Diagnostic {
+            line,
+            column,
+            snippet,
+            ..self
+        }
+    }
+
+    pub fn in_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+/// Parses `span` as a `T`, reporting a diagnostic at `span` within `source`
+/// if it isn't one.
+pub fn parse<T>(source: &str, span: &str) -> Result<T, Diagnostic>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    span.parse().map_err(|err| {
+        Diagnostic::new(source, span, format!("unable to parse `{}`: {}", span, err))
+    })
+}
+
+fn offset_in(source: &str, span: &str) -> Option<usize> {
+    let start = (span.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+    (start + span.len() <= source.len()).then_some(start)
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self
+            .file
+            .as_ref()
+            .map_or("<input>".to_owned(), |file| file.display().to_string());
+        let gutter = " ".repeat(self.line.to_string().len());
+        let indent: String = self
+            .snippet
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{} | {}{}", gutter, indent, "^".repeat(self.len))
+    }
+}
+
+impl Error for Diagnostic {}
+
+/// The first diagnostic in `err` or anything it was caused by.
+pub fn find_diagnostic<'a>(err: &'a (dyn Error + 'static)) -> Option<&'a Diagnostic> {
+    let mut err = Some(err);
+    while let Some(current) = err {
+        if let Some(diagnostic) = current.downcast_ref::<Diagnostic>() {
+            return Some(diagnostic);
+        }
+        err = current.source();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Game 1: 3 blue\nGame 2: 8 purple, 6 blue\n";
+
+    #[test]
+    fn points_at_span() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let diagnostic = Diagnostic::new(
+            INPUT,
+            &line[10..16],
+            "expected red, green or blue, found `purple`",
+        )
+        .in_file("input.txt");
+
+        assert_eq!(
+            (diagnostic.line, diagnostic.column, diagnostic.len),
+            (2, 11, 6)
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            "expected red, green or blue, found `purple`
+ --> input.txt:2:11
+  |
+2 | Game 2: 8 purple, 6 blue
+  |           ^^^^^^"
+        );
+    }
+
+    #[test]
+    fn shifts_diagnostics_from_a_single_line() {
+        let line = INPUT.lines().nth(1).unwrap().to_owned();
+        let diagnostic = parse::<u32>(&line, "purple").unwrap_err().after_lines(1);
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 11));
+        assert_eq!(
+            diagnostic.message,
+            "unable to parse `purple`: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn moves_diagnostics_onto_source() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let sets = &line[7..];
+        let diagnostic = Diagnostic::new(sets, &sets[3..9], "unknown colour").within(INPUT, sets);
+        assert_eq!(
+            diagnostic,
+            Diagnostic::new(INPUT, &line[10..16], "unknown colour")
+        );
+    }
+
+    #[test]
+    fn points_past_the_end() {
+        let diagnostic = Diagnostic::at_end(INPUT, "expected another game");
+        assert_eq!(
+            (diagnostic.line, diagnostic.column, diagnostic.len),
+            (2, 25, 1)
+        );
+    }
+
+    #[test]
+    fn finds_diagnostic_in_source_chain() {
+        #[derive(Debug)]
+        struct Wrapper(Diagnostic);
+
+        impl fmt::Display for Wrapper {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl Error for Wrapper {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let diagnostic = Diagnostic::at_end(INPUT, "expected another game");
+        let err = Wrapper(diagnostic.clone());
+        assert_eq!(find_diagnostic(&err), Some(&diagnostic));
+        assert_eq!(find_diagnostic(&std::fmt::Error), None);
+    }
+}
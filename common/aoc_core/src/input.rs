@@ -0,0 +1,90 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::trace;
+
+/// Passing this as the input path reads the puzzle input from stdin instead.
+pub const STDIN: &str = "-";
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("input file {} does not exist, pass the path to your puzzle input or - for stdin", .0.display())]
+    Missing(PathBuf),
+    #[error("unable to read {}: {source}", path.display())]
+    Unreadable { path: PathBuf, source: io::Error },
+    #[error("unable to read input from stdin: {0}")]
+    Stdin(io::Error),
+}
+
+/// The input path given as the first command line argument, or `default` when
+/// there isn't one. Verbosity flags like `-v` are skipped.
+pub fn input_path(default: impl Into<PathBuf>) -> PathBuf {
+    env::args_os()
+        .skip(1)
+        .find(|arg| arg.to_str().and_then(trace::verbosity_flag).is_none())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default.into())
+}
+
+pub fn read_input(path: &Path) -> Result<String, InputError> {
+    let mut input = String::new();
+    open_input(path)?
+        .read_to_string(&mut input)
+        .map_err(|err| read_error(path, err))?;
+    Ok(input)
+}
+
+pub fn open_input(path: &Path) -> Result<Box<dyn BufRead>, InputError> {
+    if path == Path::new(STDIN) {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    let file = File::open(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+        _ => read_error(path, err),
+    })?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn read_error(path: &Path, source: io::Error) -> InputError {
+    if path == Path::new(STDIN) {
+        InputError::Stdin(source)
+    } else {
+        InputError::Unreadable {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_file() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let input = read_input(&path).unwrap();
+        assert!(input.starts_with("[package]"));
+    }
+
+    #[test]
+    fn reports_missing_file() {
+        let err = read_input(Path::new("no/such/input.txt")).unwrap_err();
+        assert!(matches!(err, InputError::Missing(_)));
+        assert_eq!(
+            err.to_string(),
+            "input file no/such/input.txt does not exist, pass the path to your puzzle input or - for stdin"
+        );
+    }
+
+    #[test]
+    fn reports_unreadable_path() {
+        let err = read_input(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap_err();
+        assert!(matches!(err, InputError::Unreadable { .. }));
+    }
+}
@@ -0,0 +1,242 @@
+pub mod diagnostic;
+pub mod input;
+pub mod trace;
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub use diagnostic::{find_diagnostic, Diagnostic};
+pub use input::{input_path, open_input, read_input, InputError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, got {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident as $inner:ty: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::$variant(value as $inner)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Signed as i64: i32, i64);
+answer_from!(Unsigned as u64: u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Deliberately not a `std::error::Error` itself so that any day's error type
+/// can be turned into one with `?`.
+#[derive(Debug)]
+pub enum SolutionError {
+    NotImplemented(Part),
+    Failed(Box<dyn Error + Send + Sync>),
+}
+
+impl SolutionError {
+    /// Where in the input things went wrong, if the solution could tell.
+    pub fn diagnostic(&self) -> Option<&Diagnostic> {
+        match self {
+            SolutionError::Failed(err) => find_diagnostic(err.as_ref()),
+            SolutionError::NotImplemented(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for SolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolutionError::NotImplemented(part) => write!(f, "part {} is not implemented", part),
+            SolutionError::Failed(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<E> for SolutionError {
+    fn from(err: E) -> Self {
+        SolutionError::Failed(Box::new(err))
+    }
+}
+
+impl From<SolutionError> for Box<dyn Error> {
+    fn from(err: SolutionError) -> Self {
+        match err {
+            SolutionError::Failed(err) => err,
+            not_implemented => not_implemented.to_string().into(),
+        }
+    }
+}
+
+impl From<SolutionError> for Box<dyn Error + Send + Sync> {
+    fn from(err: SolutionError) -> Self {
+        match err {
+            SolutionError::Failed(err) => err,
+            not_implemented => not_implemented.to_string().into(),
+        }
+    }
+}
+
+/// A single day's puzzle. `parse` turns the raw puzzle input into whatever the
+/// parts work on, and each part consumes its own parsed copy.
+pub trait Solution {
+    type Input<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError>;
+
+    fn part_one(&self, _input: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Err(SolutionError::NotImplemented(Part::One))
+    }
+
+    fn part_two(&self, _input: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Err(SolutionError::NotImplemented(Part::Two))
+    }
+}
+
+/// An answer along with how long parsing and solving took. Dropping the
+/// parsed input counts towards `solve`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Object safe view of a [`Solution`] so solutions with different parsed
+/// inputs can live side by side.
+pub trait Solver: Sync {
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, SolutionError>;
+
+    fn solve_timed(&self, part: Part, input: &str) -> Result<Timed, SolutionError>;
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, SolutionError> {
+        let parsed = self.parse(input)?;
+        match part {
+            Part::One => self.part_one(parsed),
+            Part::Two => self.part_two(parsed),
+        }
+    }
+
+    fn solve_timed(&self, part: Part, input: &str) -> Result<Timed, SolutionError> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parsed_at = Instant::now();
+        let answer = match part {
+            Part::One => self.part_one(parsed),
+            Part::Two => self.part_two(parsed),
+        }?;
+
+        Ok(Timed {
+            answer,
+            parse: parsed_at - start,
+            solve: parsed_at.elapsed(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = Vec<i64>;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+            Ok(input
+                .lines()
+                .map(|line| line.parse::<i64>())
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(&self, input: Self::Input<'_>) -> Result<Answer, SolutionError> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+    }
+
+    #[test]
+    fn solves_implemented_part() {
+        let answer = Sum.solve(Part::One, "1\n-2\n4").unwrap();
+        assert_eq!(answer, Answer::Signed(3));
+        assert_eq!(answer.to_string(), "3");
+    }
+
+    #[test]
+    fn reports_unimplemented_part() {
+        let err = Sum.solve(Part::Two, "1").unwrap_err();
+        assert!(matches!(err, SolutionError::NotImplemented(Part::Two)));
+        assert_eq!(err.to_string(), "part 2 is not implemented");
+    }
+
+    #[test]
+    fn times_parse_and_solve() {
+        let timed = Sum.solve_timed(Part::One, "1\n-2\n4").unwrap();
+        assert_eq!(timed.answer, Answer::Signed(3));
+        assert!(Sum.solve_timed(Part::Two, "1").is_err());
+    }
+
+    #[test]
+    fn wraps_parse_errors() {
+        let err = Sum.solve(Part::One, "one").unwrap_err();
+        assert!(matches!(err, SolutionError::Failed(_)));
+        assert_eq!(err.to_string(), "invalid digit found in string");
+    }
+}
@@ -0,0 +1,66 @@
+use std::env;
+use std::io::{self, IsTerminal};
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+/// Sends diagnostics to stderr, leaving stdout to the answer. Only warnings
+/// and errors show by default; each level of `verbosity` shows more, from how
+/// long each span took up to every step of a solution. Negative `verbosity`
+/// silences warnings too.
+pub fn init(verbosity: i8) {
+    let level = match verbosity {
+        ..=-1 => LevelFilter::ERROR,
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    // Only fails if something else installed a subscriber first, which is
+    // fine to keep.
+    let _ = tracing_subscriber::fmt()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_target(false)
+        .try_init();
+}
+
+/// How much `-v`, `-vv` and so on, or `-q`, ask to see, if `arg` is one of
+/// them.
+pub fn verbosity_flag(arg: &str) -> Option<i8> {
+    match arg {
+        "-q" | "--quiet" => Some(-1),
+        "--verbose" => Some(1),
+        _ => {
+            let vs = arg.strip_prefix('-')?;
+            (!vs.is_empty() && vs.chars().all(|c| c == 'v')).then(|| vs.len().min(3) as i8)
+        }
+    }
+}
+
+/// Initialises tracing from the verbosity flags on the command line.
+pub fn init_from_args() {
+    let verbosity = env::args()
+        .skip(1)
+        .filter_map(|arg| verbosity_flag(&arg))
+        .sum();
+    init(verbosity);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_verbosity_flags() {
+        assert_eq!(verbosity_flag("-v"), Some(1));
+        assert_eq!(verbosity_flag("-vvv"), Some(3));
+        assert_eq!(verbosity_flag("--verbose"), Some(1));
+        assert_eq!(verbosity_flag("-q"), Some(-1));
+        assert_eq!(verbosity_flag("-"), None);
+        assert_eq!(verbosity_flag("input.txt"), None);
+        assert_eq!(verbosity_flag("-x"), None);
+    }
+}
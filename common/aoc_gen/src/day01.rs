@@ -0,0 +1,29 @@
+use rand::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Calibration lines mixing letters, digits and spelled out digits. Every
+/// line has at least one real digit so part one always has an answer.
+pub fn generate(rng: &mut impl Rng, lines: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..lines {
+        let target = rng.gen_range(4..=40);
+        let mut line = String::new();
+        while line.len() < target {
+            match rng.gen_range(0..10) {
+                0 | 1 => line.push(char::from(b'1' + rng.gen_range(0..9))),
+                2 | 3 => line.push_str(WORDS[rng.gen_range(0..9)]),
+                _ => line.push(char::from(b'a' + rng.gen_range(0..26))),
+            }
+        }
+        if !line.bytes().any(|b| b.is_ascii_digit()) {
+            let at = rng.gen_range(0..=line.len());
+            line.insert(at, char::from(b'1' + rng.gen_range(0..9)));
+        }
+        input.push_str(&line);
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,24 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+/// Games of one to six draws, each showing some of the three colours.
+pub fn generate(rng: &mut impl Rng, games: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=games {
+        let draws: Vec<String> = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colours = COLOURS;
+                colours.shuffle(rng);
+                colours[..rng.gen_range(1..=3)]
+                    .iter()
+                    .map(|colour| format!("{} {}", rng.gen_range(1..=20), colour))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        input.push_str(&format!("Game {}: {}\n", id, draws.join("; ")));
+    }
+    input
+}
@@ -0,0 +1,45 @@
+use rand::Rng;
+
+const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+/// A square engine schematic. Numbers are kept apart by at least one `.` on
+/// the same row, and symbols never sit directly beside a number on their row.
+pub fn generate(rng: &mut impl Rng, side: usize) -> String {
+    let mut grid = vec![vec![b'.'; side]; side];
+    for row in grid.iter_mut() {
+        let mut col = 0;
+        while col < side {
+            let len = rng.gen_range(1..=3);
+            if col + len <= side && rng.gen_bool(0.15) {
+                row[col] = b'1' + rng.gen_range(0..9);
+                for cell in &mut row[col + 1..col + len] {
+                    *cell = b'0' + rng.gen_range(0..10);
+                }
+                col += len + 1;
+            } else {
+                col += 1;
+            }
+        }
+    }
+
+    for row in grid.iter_mut() {
+        for col in 0..side {
+            let beside_number = (col > 0 && row[col - 1].is_ascii_digit())
+                || (col + 1 < side && row[col + 1].is_ascii_digit());
+            if row[col] == b'.' && !beside_number && rng.gen_bool(0.08) {
+                row[col] = SYMBOLS[rng.gen_range(0..SYMBOLS.len())];
+            }
+        }
+    }
+
+    lines(grid)
+}
+
+pub(crate) fn lines(grid: Vec<Vec<u8>>) -> String {
+    let mut input = String::new();
+    for row in grid {
+        input.push_str(&String::from_utf8(row).expect("grids are ASCII"));
+        input.push('\n');
+    }
+    input
+}
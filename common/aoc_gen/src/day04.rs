@@ -0,0 +1,46 @@
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+const WINNING: usize = 10;
+const HAVE: usize = 25;
+
+/// Scratchcards laid out like the real input. Most cards match nothing, so
+/// the part two copy counts grow slowly instead of overflowing.
+pub fn generate(rng: &mut impl Rng, cards: usize) -> String {
+    let width = cards.to_string().len().max(3);
+    let mut input = String::new();
+    for id in 1..=cards {
+        let numbers: Vec<usize> = index::sample(rng, 99, WINNING + HAVE)
+            .into_iter()
+            .map(|n| n + 1)
+            .collect();
+        let (winning, others) = numbers.split_at(WINNING);
+
+        let matches = if rng.gen_bool(0.7) {
+            0
+        } else {
+            rng.gen_range(1..=4).min(cards - id)
+        };
+        let mut have: Vec<usize> = winning[..matches].to_vec();
+        have.extend(&others[..HAVE - matches]);
+        let mut winning = winning.to_vec();
+        winning.shuffle(rng);
+        have.shuffle(rng);
+
+        let format = |numbers: &[usize]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        input.push_str(&format!(
+            "Card {:>width$}: {} | {}\n",
+            id,
+            format(&winning),
+            format(&have),
+            width = width
+        ));
+    }
+    input
+}
@@ -0,0 +1,53 @@
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// An almanac whose seven maps each split the numbers below `100 * ranges`
+/// into `ranges` chunks and shuffle them around, so every map is a bijection
+/// on the numbers the seeds can reach.
+pub fn generate(rng: &mut impl Rng, ranges: usize) -> String {
+    let domain = 100 * ranges;
+
+    let seeds: Vec<String> = (0..5)
+        .flat_map(|_| {
+            let start = rng.gen_range(0..domain);
+            let len = rng.gen_range(1..=(domain - start).min(domain / 10).max(1));
+            [start.to_string(), len.to_string()]
+        })
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for name in MAPS {
+        let mut cuts: Vec<usize> = index::sample(rng, domain - 1, ranges - 1)
+            .into_iter()
+            .map(|cut| cut + 1)
+            .collect();
+        cuts.sort();
+        cuts.insert(0, 0);
+        cuts.push(domain);
+        let chunks: Vec<(usize, usize)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+
+        let mut order: Vec<usize> = (0..chunks.len()).collect();
+        order.shuffle(rng);
+        let mut destination = 0;
+        let mut lines = Vec::new();
+        for &chunk in &order {
+            let (source, len) = chunks[chunk];
+            lines.push(format!("{} {} {}", destination, source, len));
+            destination += len;
+        }
+        lines.shuffle(rng);
+
+        input.push_str(&format!("\n{} map:\n{}\n", name, lines.join("\n")));
+    }
+    input
+}
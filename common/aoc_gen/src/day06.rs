@@ -0,0 +1,22 @@
+use rand::Rng;
+
+/// Races with two digit times. Part two glues the numbers together, so more
+/// than four races would overflow the record distance.
+pub fn generate(rng: &mut impl Rng, races: usize) -> String {
+    let mut times = Vec::new();
+    let mut distances = Vec::new();
+    for _ in 0..races {
+        let time: u64 = rng.gen_range(10..=99);
+        let best = (time / 2) * (time - time / 2);
+        times.push(time);
+        distances.push(rng.gen_range(best / 4..best));
+    }
+
+    let row = |numbers: &[u64]| {
+        numbers
+            .iter()
+            .map(|n| format!("{:>5}", n))
+            .collect::<String>()
+    };
+    format!("Time:    {}\nDistance:{}\n", row(&times), row(&distances))
+}
@@ -0,0 +1,28 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Distinct hands with bids, so every hand has a well defined rank.
+pub fn generate(rng: &mut impl Rng, hands: usize) -> String {
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+    while seen.len() < hands {
+        // Pick from a few cards now and then so the stronger hand types show
+        // up as often as in the real input.
+        let pool = if rng.gen_bool(0.5) {
+            13
+        } else {
+            rng.gen_range(2..=5)
+        };
+        let offset = rng.gen_range(0..=13 - pool);
+        let hand: String = (0..5)
+            .map(|_| char::from(CARDS[offset + rng.gen_range(0..pool)]))
+            .collect();
+        if seen.insert(hand.clone()) {
+            input.push_str(&format!("{} {}\n", hand, rng.gen_range(1..=1000)));
+        }
+    }
+    input
+}
@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const CYCLES: [usize; 6] = [11, 13, 17, 19, 23, 29];
+
+/// A network with one path per ghost, starting at `AAA` for the first. Each
+/// path reaches its `Z` node after a whole number of passes through the
+/// instructions and then loops back, so ghosts line up at the least common
+/// multiple of their path lengths.
+pub fn generate(rng: &mut impl Rng, ghosts: usize) -> String {
+    let instructions: String = (0..rng.gen_range(5..=20))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+
+    let mut names: HashSet<String> = ["AAA".to_owned(), "ZZZ".to_owned()].into();
+    let mut cycles = CYCLES;
+    cycles.shuffle(rng);
+    let mut nodes = Vec::new();
+    for (ghost, cycle) in cycles.into_iter().take(ghosts).enumerate() {
+        let steps = instructions.len() * cycle;
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_owned(), "ZZZ".to_owned())
+        } else {
+            (
+                new_name(rng, &mut names, Some(b'A')),
+                new_name(rng, &mut names, Some(b'Z')),
+            )
+        };
+        let mut path = vec![start];
+        path.extend((1..steps).map(|_| new_name(rng, &mut names, None)));
+        path.push(end);
+
+        for step in path.windows(2) {
+            nodes.push(format!("{} = ({}, {})", step[0], step[1], step[1]));
+        }
+        nodes.push(format!("{} = ({}, {})", path[steps], path[1], path[1]));
+    }
+    nodes.shuffle(rng);
+
+    format!("{}\n\n{}\n", instructions, nodes.join("\n"))
+}
+
+/// A name not used yet, ending in `last` if given and otherwise in neither
+/// `A` nor `Z`.
+fn new_name(rng: &mut impl Rng, names: &mut HashSet<String>, last: Option<u8>) -> String {
+    loop {
+        let mut letters: Vec<u8> = (0..3).map(|_| b'A' + rng.gen_range(0..26)).collect();
+        match last {
+            Some(last) => letters[2] = last,
+            None if matches!(letters[2], b'A' | b'Z') => continue,
+            None => {}
+        }
+        let name = String::from_utf8(letters).expect("names are ASCII");
+        if names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
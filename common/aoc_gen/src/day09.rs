@@ -0,0 +1,28 @@
+use rand::Rng;
+
+const READINGS: usize = 21;
+
+/// Histories that are polynomials of degree at most five, built up from a
+/// constant row of differences.
+pub fn generate(rng: &mut impl Rng, histories: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..histories {
+        let degree = rng.gen_range(0..=5);
+        let mut sequence = vec![rng.gen_range(-5i64..=5); READINGS];
+        for _ in 0..degree {
+            let mut value = rng.gen_range(-20..=20);
+            sequence = sequence
+                .iter()
+                .map(|difference| {
+                    let current = value;
+                    value += difference;
+                    current
+                })
+                .collect();
+        }
+        let line: Vec<String> = sequence.iter().map(i64::to_string).collect();
+        input.push_str(&line.join(" "));
+        input.push('\n');
+    }
+    input
+}
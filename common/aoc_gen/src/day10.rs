@@ -0,0 +1,78 @@
+use rand::Rng;
+
+use crate::day03::lines;
+use crate::region;
+
+const PIPES: &[u8] = b"|-LJ7F";
+
+/// A field of pipes with one loop running around a random region. Outline
+/// corner `(row, col)` lands on tile `(2 * row, 2 * col)`, with one more tile
+/// between neighbouring corners, so the loop never touches itself and there
+/// are tiles inside it for part two. Every other tile is junk.
+pub fn generate(rng: &mut impl Rng, side: usize) -> String {
+    let cells = (side - 1) / 2;
+    let region = region::grow(rng, cells, cells, cells * cells / 2);
+    let corners = region::outline(&region);
+
+    let mut path = Vec::new();
+    for (i, &(row, col)) in corners.iter().enumerate() {
+        let (next_row, next_col) = corners[(i + 1) % corners.len()];
+        path.push((2 * row, 2 * col));
+        path.push((row + next_row, col + next_col));
+    }
+
+    let mut grid: Vec<Vec<u8>> = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| {
+                    if rng.gen_bool(0.7) {
+                        PIPES[rng.gen_range(0..PIPES.len())]
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    for (i, &tile) in path.iter().enumerate() {
+        let previous = path[(i + path.len() - 1) % path.len()];
+        let next = path[(i + 1) % path.len()];
+        grid[tile.0][tile.1] = pipe(tile, previous, next);
+    }
+
+    // Clear the junk around the start so only the loop connects to it.
+    let start = path[rng.gen_range(0..path.len())];
+    let (row, col) = start;
+    let neighbours = [
+        (row.wrapping_sub(1), col),
+        (row + 1, col),
+        (row, col.wrapping_sub(1)),
+        (row, col + 1),
+    ];
+    for (row, col) in neighbours {
+        if row < side && col < side && !path.contains(&(row, col)) {
+            grid[row][col] = b'.';
+        }
+    }
+    grid[start.0][start.1] = b'S';
+
+    lines(grid)
+}
+
+fn pipe(tile: (usize, usize), a: (usize, usize), b: (usize, usize)) -> u8 {
+    let side = |other: (usize, usize)| match (other.0.cmp(&tile.0), other.1.cmp(&tile.1)) {
+        (std::cmp::Ordering::Less, _) => 'N',
+        (std::cmp::Ordering::Greater, _) => 'S',
+        (_, std::cmp::Ordering::Greater) => 'E',
+        _ => 'W',
+    };
+    match (side(a), side(b)) {
+        ('N', 'S') | ('S', 'N') => b'|',
+        ('E', 'W') | ('W', 'E') => b'-',
+        ('N', 'E') | ('E', 'N') => b'L',
+        ('N', 'W') | ('W', 'N') => b'J',
+        ('S', 'W') | ('W', 'S') => b'7',
+        _ => b'F',
+    }
+}
@@ -0,0 +1,31 @@
+use rand::Rng;
+
+use crate::day03::lines;
+
+/// A sparse image of galaxies with some rows and columns left empty to
+/// expand.
+pub fn generate(rng: &mut impl Rng, side: usize) -> String {
+    let mut grid: Vec<Vec<u8>> = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| if rng.gen_bool(0.04) { b'#' } else { b'.' })
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..side / 10 + 1 {
+        let empty_row = rng.gen_range(0..side);
+        let empty_col = rng.gen_range(0..side);
+        grid[empty_row].fill(b'.');
+        for row in grid.iter_mut() {
+            row[empty_col] = b'.';
+        }
+    }
+
+    if grid.iter().flatten().filter(|cell| **cell == b'#').count() < 2 {
+        grid[0][0] = b'#';
+        grid[side - 1][side - 1] = b'#';
+    }
+
+    lines(grid)
+}
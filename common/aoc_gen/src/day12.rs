@@ -0,0 +1,35 @@
+use rand::Rng;
+
+/// Rows of springs with their damaged groups. Some springs are hidden behind
+/// `?`, at most twelve per row so brute force stays feasible.
+pub fn generate(rng: &mut impl Rng, rows: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..rows {
+        let mut springs: Vec<u8> = (0..rng.gen_range(5..=20))
+            .map(|_| if rng.gen_bool(0.5) { b'#' } else { b'.' })
+            .collect();
+        let len = springs.len();
+        springs[rng.gen_range(0..len)] = b'#';
+
+        let groups: Vec<String> = springs
+            .split(|spring| *spring == b'.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+
+        let mut unknown = 0;
+        for spring in springs.iter_mut() {
+            if unknown < 12 && rng.gen_bool(0.5) {
+                *spring = b'?';
+                unknown += 1;
+            }
+        }
+
+        input.push_str(&format!(
+            "{} {}\n",
+            String::from_utf8(springs).expect("springs are ASCII"),
+            groups.join(",")
+        ));
+    }
+    input
+}
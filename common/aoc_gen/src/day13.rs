@@ -0,0 +1,109 @@
+use rand::Rng;
+
+/// Patterns with exactly one perfect reflection and exactly one other line
+/// that reflects after fixing a single smudge.
+pub fn generate(rng: &mut impl Rng, patterns: usize) -> String {
+    let patterns: Vec<String> = (0..patterns)
+        .map(|_| {
+            let pattern = pattern(rng);
+            pattern
+                .into_iter()
+                .map(|row| String::from_utf8(row).expect("patterns are ASCII"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+    format!("{}\n", patterns.join("\n\n"))
+}
+
+fn pattern(rng: &mut impl Rng) -> Vec<Vec<u8>> {
+    loop {
+        let rows = rng.gen_range(5..=17);
+        let cols = rng.gen_range(5..=17);
+        let row_line = rng.gen_range(1..rows);
+        let col_line = rng.gen_range(1..cols);
+
+        // Mirroring a random pattern across both lines makes both perfect.
+        let base: Vec<Vec<bool>> = (0..rows)
+            .map(|_| (0..cols).map(|_| rng.gen_bool(0.5)).collect())
+            .collect();
+        let mut pattern: Vec<Vec<bool>> = (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|col| base[fold(row, row_line, rows)][fold(col, col_line, cols)])
+                    .collect()
+            })
+            .collect();
+
+        // Then flip one cell that only the line picked for part two compares,
+        // leaving it one smudge away from reflecting.
+        let smudge_rows = rng.gen_bool(0.5);
+        let (smudged, smudged_len, perfect, perfect_len) = if smudge_rows {
+            (row_line, rows, col_line, cols)
+        } else {
+            (col_line, cols, row_line, rows)
+        };
+        let unchecked: Vec<usize> = (0..perfect_len)
+            .filter(|&i| {
+                fold(i, perfect, perfect_len) == i && mirror(i, perfect, perfect_len).is_none()
+            })
+            .collect();
+        if unchecked.is_empty() {
+            continue;
+        }
+        let across = unchecked[rng.gen_range(0..unchecked.len())];
+        let reach = smudged.min(smudged_len - smudged);
+        let along = rng.gen_range(smudged - reach..smudged + reach);
+        let (row, col) = if smudge_rows {
+            (along, across)
+        } else {
+            (across, along)
+        };
+        pattern[row][col] = !pattern[row][col];
+
+        let row_smudges: Vec<usize> = (1..rows).map(|line| smudges(&pattern, line)).collect();
+        let transposed: Vec<Vec<bool>> = (0..cols)
+            .map(|col| pattern.iter().map(|row| row[col]).collect())
+            .collect();
+        let col_smudges: Vec<usize> = (1..cols).map(|line| smudges(&transposed, line)).collect();
+        let all = || row_smudges.iter().chain(&col_smudges);
+        if all().filter(|count| **count == 0).count() == 1
+            && all().filter(|count| **count == 1).count() == 1
+        {
+            return pattern
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|rock| if rock { b'#' } else { b'.' })
+                        .collect()
+                })
+                .collect();
+        }
+    }
+}
+
+/// The index `i` reflects onto across the line before index `line`, if it is
+/// close enough to the line to be reflected at all.
+fn mirror(i: usize, line: usize, len: usize) -> Option<usize> {
+    let reach = line.min(len - line);
+    (line - reach..line + reach)
+        .contains(&i)
+        .then(|| 2 * line - 1 - i)
+}
+
+/// Folds the reflected half onto the other one.
+fn fold(i: usize, line: usize, len: usize) -> usize {
+    match mirror(i, line, len) {
+        Some(reflected) if i >= line => reflected,
+        _ => i,
+    }
+}
+
+fn smudges(pattern: &[Vec<bool>], line: usize) -> usize {
+    (0..line.min(pattern.len() - line))
+        .map(|offset| {
+            let (above, below) = (&pattern[line - 1 - offset], &pattern[line + offset]);
+            above.iter().zip(below).filter(|(a, b)| a != b).count()
+        })
+        .sum()
+}
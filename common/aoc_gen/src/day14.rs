@@ -0,0 +1,19 @@
+use rand::Rng;
+
+use crate::day03::lines;
+
+/// A square platform of round and cube shaped rocks.
+pub fn generate(rng: &mut impl Rng, side: usize) -> String {
+    let grid = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| match rng.gen_range(0..100) {
+                    0..=19 => b'O',
+                    20..=34 => b'#',
+                    _ => b'.',
+                })
+                .collect()
+        })
+        .collect();
+    lines(grid)
+}
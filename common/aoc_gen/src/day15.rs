@@ -0,0 +1,25 @@
+use rand::Rng;
+
+/// An initialization sequence drawing on a small pool of labels, so lenses
+/// get replaced and removed as well as added.
+pub fn generate(rng: &mut impl Rng, steps: usize) -> String {
+    let labels: Vec<String> = (0..steps / 4 + 1)
+        .map(|_| {
+            (0..rng.gen_range(2..=6))
+                .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+                .collect()
+        })
+        .collect();
+
+    let steps: Vec<String> = (0..steps)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.7) {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            } else {
+                format!("{}-", label)
+            }
+        })
+        .collect();
+    format!("{}\n", steps.join(","))
+}
@@ -0,0 +1,25 @@
+use rand::Rng;
+
+use crate::day03::lines;
+
+const PARTS: &[u8] = b"/\\|-";
+
+/// A contraption a third wider than it is tall, so nothing can rely on it
+/// being square.
+pub fn generate(rng: &mut impl Rng, rows: usize) -> String {
+    let cols = rows + rows / 3;
+    let grid = (0..rows)
+        .map(|_| {
+            (0..cols)
+                .map(|_| {
+                    if rng.gen_bool(0.1) {
+                        PARTS[rng.gen_range(0..PARTS.len())]
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    lines(grid)
+}
@@ -0,0 +1,64 @@
+use rand::Rng;
+
+use crate::region;
+
+/// A dig plan tracing the outline of a random region. Both the plan and the
+/// one hidden in the colours follow the same turns, with the outline's grid
+/// lines stretched by different amounts, so both loops are simple.
+pub fn generate(rng: &mut impl Rng, cells: usize) -> String {
+    let region = region::grow(rng, cells, cells, cells * cells / 2);
+    let corners = region::outline(&region);
+
+    let mut spacing = |max: u64| -> Vec<u64> {
+        let gaps: Vec<u64> = (0..=cells).map(|_| rng.gen_range(1..=max)).collect();
+        gaps.iter()
+            .scan(0, |position, gap| {
+                let current = *position;
+                *position += gap;
+                Some(current)
+            })
+            .collect()
+    };
+    let (rows, cols) = (spacing(10), spacing(10));
+    let (hidden_rows, hidden_cols) = (spacing(50_000), spacing(50_000));
+
+    let direction = |i: usize| {
+        let (row, col) = corners[i % corners.len()];
+        let (next_row, next_col) = corners[(i + 1) % corners.len()];
+        match (next_row.cmp(&row), next_col.cmp(&col)) {
+            (_, std::cmp::Ordering::Greater) => ('R', 0),
+            (std::cmp::Ordering::Greater, _) => ('D', 1),
+            (_, std::cmp::Ordering::Less) => ('L', 2),
+            _ => ('U', 3),
+        }
+    };
+
+    // Start on a turn so no straight run wraps around the end of the list.
+    let start = (0..corners.len())
+        .find(|&i| direction(i + corners.len() - 1) != direction(i))
+        .expect("a loop has to turn");
+
+    let mut input = String::new();
+    let mut from = start;
+    while from < start + corners.len() {
+        let mut to = from + 1;
+        while to < start + corners.len() && direction(to) == direction(from) {
+            to += 1;
+        }
+
+        let (a, b) = (corners[from % corners.len()], corners[to % corners.len()]);
+        let distance = |rows: &[u64], cols: &[u64]| {
+            rows[a.0].abs_diff(rows[b.0]) + cols[a.1].abs_diff(cols[b.1])
+        };
+        let (letter, digit) = direction(from);
+        input.push_str(&format!(
+            "{} {} (#{:05x}{})\n",
+            letter,
+            distance(&rows, &cols),
+            distance(&hidden_rows, &hidden_cols),
+            digit
+        ));
+        from = to;
+    }
+    input
+}
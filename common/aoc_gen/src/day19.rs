@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Workflows forming a tree under `in`, so every part is eventually accepted
+/// or rejected, followed by parts to sort.
+pub fn generate(rng: &mut impl Rng, workflows: usize) -> String {
+    let mut used = HashSet::from(["in".to_owned()]);
+    let mut names = vec!["in".to_owned()];
+    while names.len() < workflows {
+        let name: String = (0..rng.gen_range(2..=3))
+            .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+            .collect();
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    let mut children = vec![Vec::new(); workflows];
+    for child in 1..workflows {
+        children[rng.gen_range(0..child)].push(names[child].clone());
+    }
+
+    let mut lines = Vec::new();
+    for (name, mut targets) in names.iter().zip(children) {
+        let rules = targets.len().max(rng.gen_range(1..=3)) + 1;
+        while targets.len() < rules {
+            targets.push(if rng.gen_bool(0.5) { "A" } else { "R" }.to_owned());
+        }
+        targets.shuffle(rng);
+
+        let default = targets.pop().expect("every workflow has a fallback");
+        let mut rules: Vec<String> = targets
+            .into_iter()
+            .map(|target| {
+                format!(
+                    "{}{}{}:{}",
+                    char::from(b"xmas"[rng.gen_range(0..4)]),
+                    if rng.gen_bool(0.5) { '<' } else { '>' },
+                    rng.gen_range(1..=4000),
+                    target
+                )
+            })
+            .collect();
+        rules.push(default);
+        lines.push(format!("{}{{{}}}", name, rules.join(",")));
+    }
+    lines.shuffle(rng);
+
+    let parts: Vec<String> = (0..workflows / 2 + 1)
+        .map(|_| {
+            let mut rating = || rng.gen_range(1..=4000);
+            format!(
+                "{{x={},m={},a={},s={}}}",
+                rating(),
+                rating(),
+                rating(),
+                rating()
+            )
+        })
+        .collect();
+
+    format!("{}\n\n{}\n", lines.join("\n"), parts.join("\n"))
+}
@@ -0,0 +1,30 @@
+use rand::Rng;
+
+use crate::day03::lines;
+
+/// A square garden with the start in the middle. Like the real input, the
+/// start's row and column and the border are free of rocks.
+pub fn generate(rng: &mut impl Rng, side: usize) -> String {
+    let middle = side / 2;
+    let mut grid: Vec<Vec<u8>> = (0..side)
+        .map(|row| {
+            (0..side)
+                .map(|col| {
+                    let clear = row == middle
+                        || col == middle
+                        || row == 0
+                        || col == 0
+                        || row == side - 1
+                        || col == side - 1;
+                    if !clear && rng.gen_bool(0.1) {
+                        b'#'
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    grid[middle][middle] = b'S';
+    lines(grid)
+}
@@ -0,0 +1,143 @@
+//! Seeded generators for puzzle inputs of any size. The same day, seed and
+//! size always produce the same input.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use thiserror::Error;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day18;
+mod day19;
+mod day21;
+mod region;
+
+type GenRng = ChaCha8Rng;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GenError {
+    #[error("there is no input generator for day {0}")]
+    NoGenerator(u8),
+    #[error("day {day} inputs need a size between {min} and {max}, got {size}")]
+    SizeOutOfRange {
+        day: u8,
+        size: usize,
+        min: usize,
+        max: usize,
+    },
+}
+
+pub struct Generator {
+    pub day: u8,
+    /// What `size` counts for this day.
+    pub size: &'static str,
+    /// Roughly the size of a real puzzle input.
+    pub default_size: usize,
+    pub min_size: usize,
+    pub max_size: usize,
+    generate: fn(&mut GenRng, usize) -> String,
+}
+
+macro_rules! generator {
+    ($day:literal, $module:ident, $size:literal, $default:literal, $min:literal..=$max:expr) => {
+        Generator {
+            day: $day,
+            size: $size,
+            default_size: $default,
+            min_size: $min,
+            max_size: $max,
+            generate: $module::generate,
+        }
+    };
+}
+
+pub static GENERATORS: &[Generator] = &[
+    generator!(1, day01, "lines", 1000, 1..=usize::MAX),
+    generator!(2, day02, "games", 100, 1..=usize::MAX),
+    generator!(3, day03, "rows and columns", 140, 3..=usize::MAX),
+    generator!(4, day04, "cards", 200, 1..=usize::MAX),
+    generator!(5, day05, "ranges per map", 40, 1..=usize::MAX),
+    generator!(6, day06, "races", 4, 1..=4),
+    generator!(7, day07, "hands", 1000, 1..=100_000),
+    generator!(8, day08, "ghosts", 6, 1..=6),
+    generator!(9, day09, "histories", 200, 1..=usize::MAX),
+    generator!(10, day10, "rows and columns", 140, 5..=usize::MAX),
+    generator!(11, day11, "rows and columns", 140, 2..=usize::MAX),
+    generator!(12, day12, "rows", 1000, 1..=usize::MAX),
+    generator!(13, day13, "patterns", 100, 1..=usize::MAX),
+    generator!(14, day14, "rows and columns", 100, 1..=usize::MAX),
+    generator!(15, day15, "steps", 4000, 1..=usize::MAX),
+    generator!(16, day16, "rows", 110, 1..=usize::MAX),
+    generator!(18, day18, "outline cells per side", 30, 2..=usize::MAX),
+    generator!(19, day19, "workflows", 550, 1..=usize::MAX),
+    generator!(21, day21, "rows and columns", 131, 3..=usize::MAX),
+];
+
+pub fn find(day: u8) -> Result<&'static Generator, GenError> {
+    GENERATORS
+        .iter()
+        .find(|generator| generator.day == day)
+        .ok_or(GenError::NoGenerator(day))
+}
+
+/// Generates an input for `day` of the given size, or of roughly a real
+/// input's size when `size` is `None`.
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> Result<String, GenError> {
+    let generator = find(day)?;
+    let size = size.unwrap_or(generator.default_size);
+    if !(generator.min_size..=generator.max_size).contains(&size) {
+        return Err(GenError::SizeOutOfRange {
+            day,
+            size,
+            min: generator.min_size,
+            max: generator.max_size,
+        });
+    }
+
+    let mut rng = GenRng::seed_from_u64(seed);
+    Ok((generator.generate)(&mut rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        for generator in GENERATORS {
+            let size = Some(generator.min_size.max(5).min(generator.max_size));
+            let input = generate(generator.day, 7, size).unwrap();
+            assert!(!input.trim().is_empty(), "day {}", generator.day);
+            assert_eq!(input, generate(generator.day, 7, size).unwrap());
+            assert_ne!(input, generate(generator.day, 8, size).unwrap());
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_days_and_sizes() {
+        assert_eq!(generate(17, 0, None), Err(GenError::NoGenerator(17)));
+        assert_eq!(
+            generate(6, 0, Some(5)),
+            Err(GenError::SizeOutOfRange {
+                day: 6,
+                size: 5,
+                min: 1,
+                max: 4
+            })
+        );
+    }
+}
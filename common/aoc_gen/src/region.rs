@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+
+/// Grows a random region of about `target` cells in a `rows` x `cols` grid.
+/// The region stays 4-connected with no holes, and no two cells touch only
+/// at a corner, so its outline is a single simple loop.
+pub fn grow(rng: &mut impl Rng, rows: usize, cols: usize, target: usize) -> Vec<Vec<bool>> {
+    let mut region = vec![vec![false; cols]; rows];
+    let mut cells = vec![(rng.gen_range(0..rows), rng.gen_range(0..cols))];
+    region[cells[0].0][cells[0].1] = true;
+
+    let mut attempts = target * 50;
+    while cells.len() < target && attempts > 0 {
+        attempts -= 1;
+        let (row, col) = cells[rng.gen_range(0..cells.len())];
+        let (d_row, d_col) = [(-1, 0), (0, 1), (1, 0), (0, -1)][rng.gen_range(0..4)];
+        let (Some(row), Some(col)) = (
+            row.checked_add_signed(d_row).filter(|&row| row < rows),
+            col.checked_add_signed(d_col).filter(|&col| col < cols),
+        ) else {
+            continue;
+        };
+        if !region[row][col] && keeps_outline_simple(&region, row, col) {
+            region[row][col] = true;
+            cells.push((row, col));
+        }
+    }
+
+    region
+}
+
+/// Whether adding the cell at `row`, `col` keeps the region free of holes and
+/// corner-only contacts.
+fn keeps_outline_simple(region: &[Vec<bool>], row: usize, col: usize) -> bool {
+    let inside = |d_row: isize, d_col: isize| {
+        let (Some(row), Some(col)) = (row.checked_add_signed(d_row), col.checked_add_signed(d_col))
+        else {
+            return false;
+        };
+        region
+            .get(row)
+            .and_then(|cells| cells.get(col))
+            .copied()
+            .unwrap_or(false)
+    };
+
+    // Neighbours clockwise from north, alternating edge and corner neighbours.
+    let ring: Vec<bool> = [
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+        (-1, -1),
+    ]
+    .into_iter()
+    .map(|(d_row, d_col)| inside(d_row, d_col))
+    .collect();
+
+    let mut edges = 0;
+    let mut joined = 0;
+    for side in (0..8).step_by(2) {
+        let (corner, next_side) = (ring[side + 1], ring[(side + 2) % 8]);
+        if corner && !ring[side] && !next_side {
+            return false;
+        }
+        if ring[side] {
+            edges += 1;
+            if corner && next_side {
+                joined += 1;
+            }
+        }
+    }
+
+    // Every neighbouring cell has to belong to one run around the new cell,
+    // otherwise it would close a loop around some empty cells.
+    edges > 0 && edges - joined == 1
+}
+
+/// The corners of the region's outline in order, walking clockwise with the
+/// region on the right. Corner `(row, col)` is the top left corner of that
+/// cell.
+pub fn outline(region: &[Vec<bool>]) -> Vec<(usize, usize)> {
+    let inside = |row: Option<usize>, col: Option<usize>| match (row, col) {
+        (Some(row), Some(col)) => region
+            .get(row)
+            .and_then(|cells| cells.get(col))
+            .copied()
+            .unwrap_or(false),
+        _ => false,
+    };
+
+    let mut next = HashMap::new();
+    for (row, cells) in region.iter().enumerate() {
+        for (col, _) in cells.iter().enumerate().filter(|(_, cell)| **cell) {
+            if !inside(row.checked_sub(1), Some(col)) {
+                next.insert((row, col), (row, col + 1));
+            }
+            if !inside(Some(row), Some(col + 1)) {
+                next.insert((row, col + 1), (row + 1, col + 1));
+            }
+            if !inside(Some(row + 1), Some(col)) {
+                next.insert((row + 1, col + 1), (row + 1, col));
+            }
+            if !inside(Some(row), col.checked_sub(1)) {
+                next.insert((row + 1, col), (row, col));
+            }
+        }
+    }
+
+    let Some(&start) = next.keys().min() else {
+        return Vec::new();
+    };
+    let mut corners = vec![start];
+    let mut corner = next[&start];
+    while corner != start {
+        corners.push(corner);
+        corner = next[&corner];
+    }
+    corners
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+
+    #[test]
+    fn outline_visits_each_corner_once() {
+        for seed in 0..20 {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            let region = grow(&mut rng, 8, 12, 40);
+            let corners = outline(&region);
+
+            let mut unique = corners.clone();
+            unique.sort();
+            unique.dedup();
+            assert_eq!(unique.len(), corners.len());
+
+            // A single loop walks every cell edge on the boundary.
+            let inside = |row: usize, col: usize| region[row][col];
+            let mut perimeter = 0;
+            for row in 0..8 {
+                for col in (0..12).filter(|&col| inside(row, col)) {
+                    perimeter += [
+                        row == 0 || !inside(row - 1, col),
+                        row == 7 || !inside(row + 1, col),
+                        col == 0 || !inside(row, col - 1),
+                        col == 11 || !inside(row, col + 1),
+                    ]
+                    .into_iter()
+                    .filter(|open| *open)
+                    .count();
+                }
+            }
+            assert_eq!(corners.len(), perimeter);
+        }
+    }
+
+    #[test]
+    fn outline_of_single_cell() {
+        assert_eq!(
+            outline(&[vec![false, true]]),
+            vec![(0, 1), (0, 2), (1, 2), (1, 1)]
+        );
+    }
+}
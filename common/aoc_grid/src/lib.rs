@@ -0,0 +1,472 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use aoc_core::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GridError {
+    #[error("Grid has no rows")]
+    Empty,
+    #[error("Row {row} has {found} cells but the grid is {expected} wide")]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub fn new(row: usize, col: usize) -> Point {
+        Point { row, col }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Row and column offset of one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+}
+
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row. Points are `(row, col)` with the
+/// origin in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parses one row per line. Every line must be the same length.
+    pub fn parse(input: &str) -> Result<Grid<char>, Diagnostic> {
+        Grid::parse_with(input, |c| c)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Grid<T>, Diagnostic> {
+        Grid::try_parse_with(input, |c| Ok(cell(c)))
+    }
+
+    /// Like `parse_with`, but `cell` can reject a character with a message
+    /// that is reported at that character.
+    pub fn try_parse_with(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Grid<T>, Diagnostic> {
+        let lines: Vec<&str> = input.lines().collect();
+        let rows = lines
+            .iter()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, c)| {
+                        cell(c).map_err(|message| {
+                            Diagnostic::new(input, &line[i..i + c.len_utf8()], message)
+                        })
+                    })
+                    .collect::<Result<Vec<T>, Diagnostic>>()
+            })
+            .collect::<Result<Vec<_>, Diagnostic>>()?;
+
+        Grid::from_rows(rows).map_err(|err| match err {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => Diagnostic::new(
+                input,
+                lines[row],
+                format!(
+                    "expected {} cells like the first row, found {}",
+                    expected, found
+                ),
+            ),
+            GridError::Empty => Diagnostic::at_end(input, "expected a grid"),
+        })
+    }
+
+    pub fn from_rows<R>(rows: impl IntoIterator<Item = R>) -> Result<Grid<T>, GridError>
+    where
+        R: IntoIterator<Item = T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for (row, cells_in_row) in rows.into_iter().enumerate() {
+            let start = cells.len();
+            cells.extend(cells_in_row);
+            let found = cells.len() - start;
+            if row == 0 {
+                width = found;
+            } else if found != width {
+                return Err(GridError::Ragged {
+                    row,
+                    expected: width,
+                    found,
+                });
+            }
+            height += 1;
+        }
+
+        if width == 0 {
+            return Err(GridError::Empty);
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.height && point.col < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[self.index_of(point)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            let index = self.index_of(point);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn swap(&mut self, a: Point, b: Point) {
+        let (a, b) = (self.index_of(a), self.index_of(b));
+        self.cells.swap(a, b);
+    }
+
+    /// The point one step from `point` in `direction`, if it is still inside
+    /// the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.offset(point, direction.offset())
+    }
+
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        OFFSETS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Point::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| Point::new(index / self.width, index % self.width))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} is outside the grid", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+
+    fn index_of(&self, point: Point) -> usize {
+        assert!(self.contains(point), "{} is outside the grid", point);
+        point.row * self.width + point.col
+    }
+
+    fn offset(&self, point: Point, (d_row, d_col): (isize, isize)) -> Option<Point> {
+        let row = point.row.checked_add_signed(d_row)?;
+        let col = point.col.checked_add_signed(d_col)?;
+        let point = Point::new(row, col);
+        self.contains(point).then_some(point)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Rows become columns, so a `width` x `height` grid becomes `height` x
+    /// `width`.
+    pub fn transposed(&self) -> Grid<T> {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self.cells[self.index_of(point)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let index = self.index_of(point);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = "abc\ndef";
+
+    #[test]
+    fn parses_rectangular_grid() {
+        let grid = Grid::parse(EXAMPLE).unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+    }
+
+    #[test]
+    fn rejects_ragged_and_empty_input() {
+        assert_eq!(
+            Grid::from_rows(["abc".chars(), "de".chars()]),
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Grid::<char>::from_rows(Vec::<Vec<char>>::new()),
+            Err(GridError::Empty)
+        );
+    }
+
+    #[test]
+    fn reports_where_text_grids_go_wrong() {
+        let ragged = Grid::parse("abc\nde").unwrap_err();
+        assert_eq!((ragged.line, ragged.column, ragged.len), (2, 1, 2));
+        assert_eq!(
+            ragged.message,
+            "expected 3 cells like the first row, found 2"
+        );
+
+        let unknown = Grid::try_parse_with("..\n.x", |c| match c {
+            '.' => Ok(()),
+            c => Err(format!("unknown tile `{}`", c)),
+        })
+        .unwrap_err();
+        assert_eq!((unknown.line, unknown.column), (2, 2));
+
+        let empty = Grid::parse("").unwrap_err();
+        assert_eq!(empty.message, "expected a grid");
+    }
+
+    #[test]
+    fn steps_stay_inside_the_grid() {
+        let grid = Grid::parse(EXAMPLE).unwrap();
+        let corner = Point::new(0, 2);
+        assert_eq!(grid.step(corner, Direction::Up), None);
+        assert_eq!(grid.step(corner, Direction::Right), None);
+        assert_eq!(grid.step(corner, Direction::Down), Some(Point::new(1, 2)));
+        assert_eq!(grid.step(corner, Direction::Left), Some(Point::new(0, 1)));
+    }
+
+    #[test]
+    fn neighbors_are_clipped_at_the_edges() {
+        let grid = Grid::parse(EXAMPLE).unwrap();
+        let neighbors: Vec<char> = grid.neighbors4(Point::new(0, 0)).map(|p| grid[p]).collect();
+        assert_eq!(neighbors, vec!['b', 'd']);
+
+        let neighbors: Vec<char> = grid.neighbors8(Point::new(1, 1)).map(|p| grid[p]).collect();
+        assert_eq!(neighbors, vec!['a', 'b', 'c', 'd', 'f']);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = Grid::parse(EXAMPLE).unwrap();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf");
+    }
+
+    #[test]
+    fn finds_and_swaps_cells() {
+        let mut grid = Grid::parse(EXAMPLE).unwrap();
+        let e = grid.position(|c| *c == 'e').unwrap();
+        assert_eq!(e, Point::new(1, 1));
+        grid.swap(e, Point::new(0, 0));
+        assert_eq!(grid.to_string(), "ebc\ndaf");
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+    }
+
+    fn any_grid() -> impl Strategy<Value = Grid<char>> {
+        (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec("[^\r\n]", width), height).prop_map(
+                |rows| {
+                    let rows = rows
+                        .into_iter()
+                        .map(|row| row.join("").chars().collect::<Vec<_>>());
+                    Grid::from_rows(rows).unwrap()
+                },
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn printed_grids_parse_back(grid in any_grid()) {
+            prop_assert_eq!(Grid::parse(&grid.to_string()).unwrap(), grid);
+        }
+
+        #[test]
+        fn parsing_text_never_panics(input in "[.#a-z \n\r]{0,40}") {
+            let _ = Grid::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,618 @@
+use std::fmt;
+use std::iter;
+use std::str::FromStr;
+
+use aoc_core::Diagnostic;
+use aoc_grid::Grid;
+
+/// What a parser read, and the rest of the input after it.
+pub type ParseResult<'a, T> = Result<(T, Span<'a>), ParseError<'a>>;
+
+/// Why a parser stopped. It only becomes a [`Diagnostic`], which has to work
+/// out the line and column, once it is reported, so trying something that
+/// isn't there stays cheap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<'a> {
+    at: Span<'a>,
+    message: String,
+}
+
+impl From<ParseError<'_>> for Diagnostic {
+    fn from(err: ParseError<'_>) -> Self {
+        err.at.error(err.message)
+    }
+}
+
+/// Part of a puzzle input that still knows the whole input, so whatever is
+/// wrong with it is reported at the right line and column without callers
+/// having to shift diagnostics around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(source: &'a str) -> Span<'a> {
+        Span {
+            source,
+            text: source,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> Diagnostic {
+        Diagnostic::new(self.source, self.text, message)
+    }
+
+    /// For parsers to give up at this span; see [`ParseError`].
+    pub fn fail(&self, message: impl Into<String>) -> ParseError<'a> {
+        ParseError {
+            at: *self,
+            message: message.into(),
+        }
+    }
+
+    /// Points just past the end of the span, for input that stops too soon.
+    pub fn error_at_end(&self, message: impl Into<String>) -> Diagnostic {
+        let text = self.text.trim_end();
+        self.slice(&text[text.len()..]).error(message)
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.slice(self.text.trim())
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text.lines().map(move |line| span.slice(line))
+    }
+
+    /// Runs of lines separated by one or more blank lines, like the maps in
+    /// an almanac.
+    pub fn sections(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        let mut rest = self.text;
+        iter::from_fn(move || {
+            while rest.lines().next()?.trim().is_empty() {
+                rest = rest.split_once('\n').map_or("", |(_, after)| after);
+            }
+
+            let mut end = 0;
+            while end < rest.len() {
+                let line_end = rest[end..].find('\n').map_or(rest.len(), |i| end + i + 1);
+                if rest[end..line_end].trim().is_empty() {
+                    break;
+                }
+                end = line_end;
+            }
+            let section = rest[..end].trim_end_matches(['\n', '\r']);
+            rest = &rest[end..];
+            Some(span.slice(section))
+        })
+    }
+
+    pub fn split(&self, separator: char) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text
+            .split(separator)
+            .map(move |piece| span.slice(piece))
+    }
+
+    pub fn split_once(&self, separator: char) -> Option<(Span<'a>, Span<'a>)> {
+        let (before, after) = self.text.split_once(separator)?;
+        Some((self.slice(before), self.slice(after)))
+    }
+
+    /// Parses each line with `parser`, which has to use up the whole line.
+    pub fn parse_lines<T>(&self, parser: impl Parser<'a, T>) -> Result<Vec<T>, Diagnostic> {
+        self.lines().map(|line| parser.parse_all(line)).collect()
+    }
+
+    fn slice(&self, text: &'a str) -> Span<'a> {
+        Span {
+            source: self.source,
+            text,
+        }
+    }
+
+    fn split_at(&self, mid: usize) -> (Span<'a>, Span<'a>) {
+        let (before, after) = self.text.split_at(mid);
+        (self.slice(before), self.slice(after))
+    }
+
+    /// The part of this span a parser used up, given what it left.
+    fn until(&self, rest: Span<'a>) -> Span<'a> {
+        self.slice(&self.text[..self.text.len() - rest.text.len()])
+    }
+
+    /// The word or symbol at the start of the span, to say what was found
+    /// instead of what was expected.
+    fn next_token(&self) -> Span<'a> {
+        let len = match self.text.chars().next() {
+            Some(c) if c.is_alphanumeric() => self
+                .text
+                .find(|c: char| !c.is_alphanumeric())
+                .unwrap_or(self.text.len()),
+            Some(c) if !c.is_whitespace() => c.len_utf8(),
+            _ => 0,
+        };
+        self.split_at(len).0
+    }
+
+    fn expected(&self, what: &str) -> ParseError<'a> {
+        let found = self.next_token();
+        if found.is_empty() {
+            found.fail(format!("expected {}", what))
+        } else {
+            found.fail(format!("expected {}, found `{}`", what, found))
+        }
+    }
+}
+
+impl fmt::Display for Span<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// Reads a `T` off the front of a span. Any function or closure from a
+/// [`Span`] to a [`ParseResult`] is a parser.
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Span<'a>) -> ParseResult<'a, T>;
+
+    /// Parses the whole of `input`, apart from whitespace at either end.
+    fn parse_all(&self, input: Span<'a>) -> Result<T, Diagnostic> {
+        let (value, rest) = self.parse(input).map_err(Diagnostic::from)?;
+        let rest = rest.trim();
+        if rest.is_empty() {
+            Ok(value)
+        } else {
+            Err(rest.error(format!("unexpected `{}`", rest)))
+        }
+    }
+
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: Span<'a>| {
+            let (value, rest) = self.parse(input)?;
+            Ok((f(value), rest))
+        }
+    }
+
+    /// Like `map`, but `f` can reject what was read with a message, which is
+    /// reported at the text it was read from.
+    fn try_map<U>(self, f: impl Fn(T) -> Result<U, String>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: Span<'a>| {
+            let (value, rest) = self.parse(input)?;
+            match f(value) {
+                Ok(value) => Ok((value, rest)),
+                Err(message) => Err(input.until(rest).trim().fail(message)),
+            }
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Span<'a>) -> ParseResult<'a, T>,
+{
+    fn parse(&self, input: Span<'a>) -> ParseResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Exactly `tag`, such as `Game` or `->`.
+pub fn tag<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: Span<'a>| {
+        if input.text.starts_with(tag) {
+            let (tag, rest) = input.split_at(tag.len());
+            Ok((tag.text, rest))
+        } else {
+            Err(input.expected(&format!("`{}`", tag.trim())))
+        }
+    }
+}
+
+/// Skips any spaces and tabs, but not line breaks.
+pub fn space<'a>() -> impl Parser<'a, ()> {
+    |input: Span<'a>| {
+        let len = input.text.len() - input.text.trim_start_matches([' ', '\t']).len();
+        Ok(((), input.split_at(len).1))
+    }
+}
+
+/// A run of letters, digits and underscores, such as a name or a label.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    |input: Span<'a>| {
+        let len = input
+            .text
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(input.text.len());
+        if len == 0 {
+            return Err(input.expected("a name"));
+        }
+        let (word, rest) = input.split_at(len);
+        Ok((word.text, rest))
+    }
+}
+
+/// A whole number without a sign, such as `42`.
+pub fn uint<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    |input: Span<'a>| number(input, 0)
+}
+
+/// A whole number that may have a sign, such as `-7` or `+3`.
+pub fn int<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    |input: Span<'a>| number(input, usize::from(input.text.starts_with(['-', '+'])))
+}
+
+fn number<'a, T>(input: Span<'a>, sign: usize) -> ParseResult<'a, T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let digits = input.text[sign..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.text.len() - sign);
+    if digits == 0 {
+        return Err(input.expected("a number"));
+    }
+
+    let (number, rest) = input.split_at(sign + digits);
+    match number.text.parse() {
+        Ok(value) => Ok((value, rest)),
+        Err(err) => Err(number.fail(format!("unable to parse `{}`: {}", number, err))),
+    }
+}
+
+/// Any number of whole numbers, which may be negative, separated by spaces,
+/// such as `0 3 -6 9`.
+pub fn numbers<'a, T>() -> impl Parser<'a, Vec<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    |input: Span<'a>| {
+        let mut numbers = Vec::new();
+        let (_, mut rest) = space().parse(input)?;
+        while rest
+            .text
+            .starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+')
+        {
+            let (number, after) = int().parse(rest)?;
+            numbers.push(number);
+            rest = space().parse(after)?.1;
+        }
+        Ok((numbers, rest))
+    }
+}
+
+/// Whichever of `choices` is named next, such as a colour or a direction.
+pub fn one_of<'a, T: Clone>(choices: &'static [(&'static str, T)]) -> impl Parser<'a, T> {
+    move |input: Span<'a>| {
+        let found = input.next_token();
+        match choices.iter().find(|(name, _)| *name == found.text) {
+            Some((name, value)) => Ok((value.clone(), input.split_at(name.len()).1)),
+            None => {
+                let names: Vec<_> = choices.iter().map(|(name, _)| *name).collect();
+                let expected = match names.split_last() {
+                    Some((last, [])) => last.to_string(),
+                    Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+                    None => "nothing".to_owned(),
+                };
+                Err(input.expected(&expected))
+            }
+        }
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: Span<'a>| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// `item` after `prefix`, keeping only the item.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    item: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    pair(prefix, item).map(|(_, item)| item)
+}
+
+/// `item` followed by `suffix`, keeping only the item.
+pub fn terminated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    pair(item, suffix).map(|(item, _)| item)
+}
+
+/// `item` between `open` and `close`, such as a list in brackets.
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    item: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    preceded(open, terminated(item, close))
+}
+
+/// `first` if it is there, otherwise `second`. If neither is, whichever got
+/// further is reported.
+pub fn either<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: Span<'a>| match first.parse(input) {
+        Ok(parsed) => Ok(parsed),
+        Err(first_err) => second.parse(input).map_err(|second_err| {
+            if first_err.at.text.as_ptr() > second_err.at.text.as_ptr() {
+                first_err
+            } else {
+                second_err
+            }
+        }),
+    }
+}
+
+/// `item` if it is there, reading nothing if it isn't.
+pub fn opt<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: Span<'a>| match item.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// `item` along with the span it was read from, for reporting something
+/// wrong with it that only shows up later.
+pub fn spanned<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, (T, Span<'a>)> {
+    move |input: Span<'a>| {
+        let (value, rest) = item.parse(input)?;
+        Ok(((value, input.until(rest)), rest))
+    }
+}
+
+/// Any number of `item`s, stopping at the first thing that isn't one.
+pub fn many<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut input: Span<'a>| {
+        let mut items = Vec::new();
+        while let Ok((value, rest)) = item.parse(input) {
+            if rest == input {
+                break;
+            }
+            items.push(value);
+            input = rest;
+        }
+        Ok((items, input))
+    }
+}
+
+/// One or more `item`s with `separator` between them. Once a separator has
+/// been read an item has to follow it.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: Span<'a>| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Ok((_, after)) = separator.parse(rest) {
+            let (next, after) = item.parse(after)?;
+            items.push(next);
+            rest = after;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// `key=value`.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<'a, K>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    pair(terminated(key, tag("=")), value)
+}
+
+/// Comma separated `key=value` pairs, such as `x=787,m=2655,a=1222`.
+pub fn record<'a, K, V>(
+    key: impl Parser<'a, K>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, Vec<(K, V)>> {
+    separated(key_value(key, value), pair(tag(","), space()))
+}
+
+/// Parses `input` as a grid with one row per line, reporting any character
+/// `cell` rejects where it is in the whole input.
+pub fn grid<T>(
+    input: Span<'_>,
+    cell: impl FnMut(char) -> Result<T, String>,
+) -> Result<Grid<T>, Diagnostic> {
+    Grid::try_parse_with(input.text, cell)
+        .map_err(|diagnostic| diagnostic.within(input.source, input.text))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_grid::Point;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Colour {
+        Red,
+        Green,
+        Blue,
+    }
+
+    const COLOURS: &[(&str, Colour)] = &[
+        ("red", Colour::Red),
+        ("green", Colour::Green),
+        ("blue", Colour::Blue),
+    ];
+
+    fn game<'a>() -> impl Parser<'a, (u32, Vec<Vec<(u32, Colour)>>)> {
+        let cubes = pair(terminated(uint(), space()), one_of(COLOURS));
+        let set = separated(preceded(space(), cubes), tag(","));
+        pair(
+            delimited(tag("Game "), uint(), tag(":")),
+            separated(set, tag(";")),
+        )
+    }
+
+    #[test]
+    fn parses_lines() {
+        let input = "Game 1: 3 blue, 4 red; 2 green\nGame 2: 1 red\n";
+        let games = Span::new(input).parse_lines(game()).unwrap();
+        assert_eq!(
+            games,
+            vec![
+                (
+                    1,
+                    vec![
+                        vec![(3, Colour::Blue), (4, Colour::Red)],
+                        vec![(2, Colour::Green)]
+                    ]
+                ),
+                (2, vec![vec![(1, Colour::Red)]]),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_errors_within_whole_input() {
+        let input = "Game 1: 3 blue\nGame 2: 8 purple, 6 blue\n";
+        let err = Span::new(input).parse_lines(game()).unwrap_err();
+        assert_eq!(err.message, "expected red, green or blue, found `purple`");
+        assert_eq!((err.line, err.column, err.len), (2, 11, 6));
+
+        let err = Span::new("Game x: 1 red").parse_lines(game()).unwrap_err();
+        assert_eq!(err.message, "expected a number, found `x`");
+
+        let err = Span::new("Game 1: 1 red 2")
+            .parse_lines(game())
+            .unwrap_err();
+        assert_eq!((err.message.as_str(), err.column), ("unexpected `2`", 15));
+
+        let err = uint::<u8>().parse_all(Span::new("300")).unwrap_err();
+        assert_eq!(
+            err.message,
+            "unable to parse `300`: number too large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn parses_signed_lists() {
+        let (list, rest) = numbers::<i64>().parse(Span::new("0 3 -6  +9 x")).unwrap();
+        assert_eq!(list, vec![0, 3, -6, 9]);
+        assert_eq!(rest.as_str(), "x");
+        assert_eq!(numbers::<i64>().parse_all(Span::new("")).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn parses_records() {
+        let part = delimited(tag("{"), record(word(), uint::<u32>()), tag("}"));
+        assert_eq!(
+            part.parse_all(Span::new("{x=787,m=2655}")).unwrap(),
+            vec![("x", 787), ("m", 2655)]
+        );
+
+        let labels = many(preceded(space(), word()));
+        assert_eq!(
+            labels.parse_all(Span::new("qp cm ")).unwrap(),
+            vec!["qp", "cm"]
+        );
+
+        let lens = pair(word(), opt(preceded(tag("="), uint::<u8>())));
+        assert_eq!(lens.parse_all(Span::new("rn=1")).unwrap(), ("rn", Some(1)));
+        assert_eq!(lens.parse_all(Span::new("cm")).unwrap(), ("cm", None));
+
+        let step = pair(
+            word(),
+            either(
+                tag("-").map(|_| None),
+                preceded(tag("="), uint::<u8>()).map(Some),
+            ),
+        );
+        assert_eq!(step.parse_all(Span::new("cm-")).unwrap(), ("cm", None));
+        let err = step.parse_all(Span::new("qp=x")).unwrap_err();
+        assert_eq!(
+            (err.message.as_str(), err.column),
+            ("expected a number, found `x`", 4)
+        );
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "seeds: 1\n\n\na:\n1 2\n3 4\n\nb:\n5 6\n";
+        let sections: Vec<_> = Span::new(input).sections().map(|s| s.as_str()).collect();
+        assert_eq!(sections, vec!["seeds: 1", "a:\n1 2\n3 4", "b:\n5 6"]);
+
+        let last = Span::new(input).sections().last().unwrap();
+        let err = last.lines().nth(1).unwrap().error("bad range");
+        assert_eq!((err.line, err.column), (9, 1));
+    }
+
+    #[test]
+    fn try_map_reports_what_was_read() {
+        let even = preceded(space(), uint::<u32>()).try_map(|n| {
+            if n % 2 == 0 {
+                Ok(n)
+            } else {
+                Err(format!("{} is odd", n))
+            }
+        });
+        let err = even.parse_all(Span::new("  17")).unwrap_err();
+        assert_eq!(
+            (err.message.as_str(), err.column, err.len),
+            ("17 is odd", 3, 2)
+        );
+    }
+
+    #[test]
+    fn spanned_keeps_where_an_item_was_read() {
+        let names = separated(preceded(space(), spanned(word())), tag(","));
+        let (found, _) = names.parse(Span::new("a, bc")).unwrap();
+        let (name, at) = found[1];
+        assert_eq!((name, at.error("seen").column), ("bc", 4));
+    }
+
+    #[test]
+    fn parses_grids_in_place() {
+        let input = "size: 2\n\n.#\n#.\n";
+        let cells = Span::new(input).sections().nth(1).unwrap();
+        let wall = |c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            c => Err(format!("unknown tile `{}`", c)),
+        };
+        assert!(grid(cells, wall).unwrap()[Point::new(0, 1)]);
+
+        let input = "size: 2\n\n.#\n#x\n";
+        let cells = Span::new(input).sections().nth(1).unwrap();
+        let err = grid(cells, wall).unwrap_err();
+        assert_eq!((err.line, err.column), (4, 2));
+    }
+}
@@ -0,0 +1,303 @@
+use std::ops::Range;
+
+use aoc_core::{diagnostic, Diagnostic};
+use aoc_grid::Grid;
+
+pub use aoc_grid::Point;
+
+// Ids are u32 to keep a cell to eight bytes, so schematics with millions of
+// cells stay small
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Number(u32),
+    Symbol(u32),
+}
+
+/// A run of digits on one row. Columns count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub columns: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub point: Point,
+}
+
+/// An engine schematic indexed by cell. What touches a number or a symbol is
+/// found by looking at the cells around it, rather than by checking every
+/// other number or symbol. Numbers and symbols are referred to by their
+/// index in [`Schematic::numbers`] and [`Schematic::symbols`].
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    cells: Grid<Cell>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+}
+
+impl Schematic {
+    /// Indexes numbers and symbols that were found some other way. Rows can
+    /// be different lengths, but no two should share a cell.
+    pub fn new(numbers: Vec<Number>, symbols: Vec<Symbol>) -> Schematic {
+        let width = numbers
+            .iter()
+            .map(|number| number.columns.end)
+            .chain(symbols.iter().map(|symbol| symbol.point.col + 1))
+            .max()
+            .unwrap_or(0);
+        let height = numbers
+            .iter()
+            .map(|number| number.row + 1)
+            .chain(symbols.iter().map(|symbol| symbol.point.row + 1))
+            .max()
+            .unwrap_or(0);
+
+        let mut cells = Grid::new(width, height, Cell::Empty);
+        for (id, number) in numbers.iter().enumerate() {
+            let id = u32::try_from(id).expect("fewer than 2^32 numbers");
+            for col in number.columns.clone() {
+                cells[Point::new(number.row, col)] = Cell::Number(id);
+            }
+        }
+        for (id, symbol) in symbols.iter().enumerate() {
+            let id = u32::try_from(id).expect("fewer than 2^32 symbols");
+            cells[symbol.point] = Cell::Symbol(id);
+        }
+
+        Schematic {
+            cells,
+            numbers,
+            symbols,
+        }
+    }
+
+    /// Reads a schematic where digits make up numbers, `.` is empty and
+    /// anything else is a symbol. Rows can be different lengths.
+    pub fn parse(input: &str) -> Result<Schematic, Diagnostic> {
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+        for (row, line) in input.lines().enumerate() {
+            // Where the digits being read started, as a byte offset and a column
+            let mut digits: Option<(usize, usize)> = None;
+            // A `.` past the end finishes a number at the end of the line
+            let cells = line.char_indices().chain([(line.len(), '.')]);
+            for (col, (i, c)) in cells.enumerate() {
+                if c.is_ascii_digit() {
+                    digits.get_or_insert((i, col));
+                    continue;
+                }
+                if let Some((start, start_col)) = digits.take() {
+                    numbers.push(Number {
+                        value: diagnostic::parse(input, &line[start..i])?,
+                        row,
+                        columns: start_col..col,
+                    });
+                }
+                if c != '.' {
+                    symbols.push(Symbol {
+                        symbol: c,
+                        point: Point::new(row, col),
+                    });
+                }
+            }
+        }
+        Ok(Schematic::new(numbers, symbols))
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    pub fn number_at(&self, point: Point) -> Option<usize> {
+        match self.cells.get(point) {
+            Some(&Cell::Number(id)) => Some(id as usize),
+            _ => None,
+        }
+    }
+
+    pub fn symbol_at(&self, point: Point) -> Option<usize> {
+        match self.cells.get(point) {
+            Some(&Cell::Symbol(id)) => Some(id as usize),
+            _ => None,
+        }
+    }
+
+    /// The symbols touching a number, diagonals included, row by row.
+    pub fn symbols_next_to(&self, number: usize) -> impl Iterator<Item = usize> + '_ {
+        let number = &self.numbers[number];
+        let columns = number.columns.start.saturating_sub(1)..=number.columns.end;
+        (number.row.saturating_sub(1)..=number.row + 1)
+            .flat_map(move |row| columns.clone().map(move |col| Point::new(row, col)))
+            .filter_map(|point| self.symbol_at(point))
+    }
+
+    /// The numbers touching a symbol, diagonals included, row by row and
+    /// each only once.
+    pub fn numbers_next_to(&self, symbol: usize) -> impl Iterator<Item = usize> + '_ {
+        let Point { row, col } = self.symbols[symbol].point;
+        // A number covers neighboring cells side by side in one row, so it
+        // only ever turns up several times in a row
+        let mut last = None;
+        (row.saturating_sub(1)..=row + 1)
+            .flat_map(move |row| {
+                (col.saturating_sub(1)..=col + 1).map(move |col| Point::new(row, col))
+            })
+            .filter_map(|point| self.number_at(point))
+            .filter(move |&number| last.replace(number) != Some(number))
+    }
+
+    /// The numbers touching at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        (0..self.numbers.len())
+            .filter(|&number| self.symbols_next_to(number).next().is_some())
+            .map(|number| &self.numbers[number])
+    }
+
+    /// For each `*` touching exactly two numbers, the product of the two.
+    pub fn gear_ratios(&self) -> impl Iterator<Item = u64> + '_ {
+        (0..self.symbols.len())
+            .filter(|&symbol| self.symbols[symbol].symbol == '*')
+            .filter_map(|symbol| {
+                let mut numbers = self.numbers_next_to(symbol);
+                let (Some(a), Some(b), None) = (numbers.next(), numbers.next(), numbers.next())
+                else {
+                    return None;
+                };
+                Some(u64::from(self.numbers[a].value) * u64::from(self.numbers[b].value))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n\
+                           .....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..\n";
+
+    // Checks every number against every symbol
+    fn naive_answers(input: &str) -> (u64, u64) {
+        let schematic = Schematic::parse(input).unwrap();
+        let touches = |number: &Number, point: Point| {
+            point.row + 1 >= number.row
+                && point.row <= number.row + 1
+                && point.col + 1 >= number.columns.start
+                && point.col <= number.columns.end
+        };
+
+        let part_numbers = schematic
+            .numbers()
+            .iter()
+            .filter(|number| {
+                schematic
+                    .symbols()
+                    .iter()
+                    .any(|symbol| touches(number, symbol.point))
+            })
+            .map(|number| u64::from(number.value))
+            .sum();
+        let gear_ratios = schematic
+            .symbols()
+            .iter()
+            .filter(|symbol| symbol.symbol == '*')
+            .filter_map(|symbol| {
+                let touching: Vec<u64> = schematic
+                    .numbers()
+                    .iter()
+                    .filter(|number| touches(number, symbol.point))
+                    .map(|number| u64::from(number.value))
+                    .collect();
+                (touching.len() == 2).then(|| touching[0] * touching[1])
+            })
+            .sum();
+        (part_numbers, gear_ratios)
+    }
+
+    fn answers(schematic: &Schematic) -> (u64, u64) {
+        let part_numbers = schematic
+            .part_numbers()
+            .map(|number| u64::from(number.value))
+            .sum();
+        (part_numbers, schematic.gear_ratios().sum())
+    }
+
+    #[test]
+    fn answers_both_parts_from_one_index() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+        assert_eq!(answers(&schematic), (4361, 467835));
+    }
+
+    #[test]
+    fn reads_numbers_and_symbols() {
+        let schematic = Schematic::parse("é12.\n.#\n\n..7").unwrap();
+        assert_eq!(
+            schematic.numbers(),
+            [
+                Number {
+                    value: 12,
+                    row: 0,
+                    columns: 1..3
+                },
+                Number {
+                    value: 7,
+                    row: 3,
+                    columns: 2..3
+                }
+            ]
+        );
+        let symbols: Vec<_> = schematic
+            .symbols()
+            .iter()
+            .map(|symbol| (symbol.symbol, symbol.point))
+            .collect();
+        assert_eq!(
+            symbols,
+            vec![('é', Point::new(0, 0)), ('#', Point::new(1, 1))]
+        );
+
+        let err = Schematic::parse("..1\n.99999999999*").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn looks_up_neighbors_both_ways() {
+        let schematic = Schematic::parse("467.\n...*\n.35.\n*...").unwrap();
+        assert_eq!(schematic.number_at(Point::new(0, 1)), Some(0));
+        assert_eq!(schematic.symbol_at(Point::new(1, 3)), Some(0));
+        assert_eq!(schematic.symbol_at(Point::new(9, 9)), None);
+
+        assert_eq!(schematic.symbols_next_to(0).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(schematic.symbols_next_to(1).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(schematic.numbers_next_to(0).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(schematic.numbers_next_to(1).collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn indexes_millions_of_cells() {
+        // Each `12*34` is a gear, and blank rows keep them from touching
+        let row = "12*34.....".repeat(200);
+        let blank = ".".repeat(row.len());
+        let input = format!("{}\n{}\n", row, blank).repeat(1000);
+
+        let schematic = Schematic::parse(&input).unwrap();
+        assert_eq!(answers(&schematic), (200_000 * 46, 200_000 * 408));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_checking_every_pair(input in "([.0-9*#]{0,12}\n){0,8}") {
+            let schematic = Schematic::parse(&input).unwrap();
+            prop_assert_eq!(answers(&schematic), naive_answers(&input));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day01_part1::Day1,
+            &lethalgem_day01_part2::Day1,
+            &mattdamachine_day01_part1::Day1,
+            &mohammad_day01::Day1,
+        ],
+        input,
+    );
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day02_part1::Day2,
+            &lethalgem_day02_part2::Day2,
+            &mattdamachine_day02_part1::Day2,
+            &mohammad_day02::Day2,
+        ],
+        input,
+    );
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day03_part1::Day3,
+            &lethalgem_day03_part2::Day3,
+            &mohammad_day03::Day3,
+        ],
+        input,
+    );
+});
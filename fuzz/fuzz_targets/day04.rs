@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day04_part1::Day4,
+            &lethalgem_day04_part2::Day4,
+            &mattdamachine_day04_part1::Day4,
+            &mohammad_day04::Day4,
+        ],
+        input,
+    );
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day05_part1::Day5,
+            &lethalgem_day05_part2::Day5,
+            &mattdamachine_day05_part1::Day5,
+            &mohammad_day05::Day5,
+        ],
+        input,
+    );
+});
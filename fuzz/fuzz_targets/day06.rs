@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day06_part1::Day6,
+            &lethalgem_day06_part2::Day6,
+            &mattdamachine_day06_part1::Day6,
+            &mohammad_day06::Day6,
+        ],
+        input,
+    );
+});
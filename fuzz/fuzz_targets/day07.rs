@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day07_part1::Day7,
+            &lethalgem_day07_part2::Day7,
+            &mattdamachine_day07_part1::Day7,
+            &mohammad_day07_part1::Day7,
+            &mohammad_day07_part2::Day7,
+        ],
+        input,
+    );
+});
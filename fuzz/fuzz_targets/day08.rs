@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &mattdamachine_day08_part1::Day8,
+            &mohammad_day08_part1::Day8,
+            &mohammad_day08_part2::Day8,
+        ],
+        input,
+    );
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day09_part1::Day9,
+            &lethalgem_day09_part2::Day9,
+            &mattdamachine_day09_part1::Day9,
+            &mohammad_day09::Day9,
+        ],
+        input,
+    );
+});
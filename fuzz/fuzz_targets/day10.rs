@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &mattdamachine_day10_part1::Day10,
+            &mohammad_day10::Day10,
+        ],
+        input,
+    );
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day11_part1::Day11,
+            &lethalgem_day11_part2::Day11,
+            &mattdamachine_day11_part1::Day11,
+            &mohammad_day11::Day11,
+        ],
+        input,
+    );
+});
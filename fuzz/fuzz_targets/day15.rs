@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day15_part1::Day15,
+            &lethalgem_day15_part2::Day15,
+            &mohammad_day15::Day15,
+        ],
+        input,
+    );
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    aoc_fuzz::solve_all(
+        &[
+            &lethalgem_day16_part1::Day16,
+            &lethalgem_day16_part2::Day16,
+            &mohammad_day16::Day16,
+        ],
+        input,
+    );
+});
@@ -0,0 +1,11 @@
+use aoc_core::{Part, Solver};
+
+/// Runs both parts of every solver on `input`. Errors are expected for most
+/// of what the fuzzer comes up with; only a panic counts as a crash.
+pub fn solve_all(solvers: &[&dyn Solver], input: &str) {
+    for solver in solvers {
+        for part in [Part::One, Part::Two] {
+            let _ = solver.solve(part, input);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use aoc_calibration::{CalibrationError, Engine, Scanner, Vocabulary, VocabularyError};
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day1Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("{0}")]
+    Calibration(#[from] CalibrationError),
+    #[error("{0}")]
+    Vocabulary(#[from] VocabularyError),
+    #[error("Missing value for {0}")]
+    MissingArgument(&'static str),
+}
+
+pub fn run(input_path: &Path, vocabulary: &Vocabulary) -> Result<(), Day1Error> {
+    let reader = info_span!("load").in_scope(|| open_input(input_path))?;
+    let answer = info_span!("solve").in_scope(|| sum_calibration_values(reader, vocabulary))?;
+    println!("{}", answer);
+    Ok(())
+}
+
+/// Streams the calibration document rather than loading it, so it can be
+/// any size.
+pub fn sum_calibration_values(
+    reader: impl BufRead,
+    vocabulary: &Vocabulary,
+) -> Result<u64, Day1Error> {
+    let [sum] = Engine::default().sums(reader, [&vocabulary.scanner()])?;
+    debug!("summed calibration values to {}", sum);
+    Ok(sum)
+}
+
+/// The puzzle as given, with English number words.
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Day1With(Vocabulary::english()).parse(input)
+    }
+
+    fn part_two(&self, numbers: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(numbers.iter().sum::<i32>().into())
+    }
+}
+
+/// Part 2 with the number words from some other vocabulary.
+pub struct Day1With(pub Vocabulary);
+
+impl Solution for Day1With {
+    type Input<'a> = Vec<i32>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_all_numbers(input.to_owned(), &self.0)?)
+    }
+
+    fn part_two(&self, numbers: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(numbers.iter().sum::<i32>().into())
+    }
+}
+
+#[cfg(test)]
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day1Error> {
+    let data = aoc_core::read_input(file_path.as_ref()).map_err(Day1Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn extract_all_numbers(input: String, vocabulary: &Vocabulary) -> Result<Vec<i32>, Day1Error> {
+    let scanner = vocabulary.scanner();
+    let mut numbers: Vec<i32> = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let extracted_number = extract_number_from_line(line.to_owned(), &scanner)
+            .map_err(|diagnostic| diagnostic.after_lines(index))?;
+        numbers.push(extracted_number);
+    }
+    Ok(numbers)
+}
+
+fn extract_number_from_line(line: String, scanner: &Scanner) -> Result<i32, Diagnostic> {
+    // Words can share letters, as in "eightwo", so both are found
+    let Some(calibration_value) = scanner.calibration_value(&line) else {
+        return Err(Diagnostic::new(
+            &line,
+            &line,
+            "no digit or number word found in line",
+        ));
+    };
+
+    Ok(calibration_value as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        extract_all_numbers, extract_number_from_line, load_input, sum_calibration_values, Day1With,
+    };
+    use aoc_calibration::{Scanner, Vocabulary};
+    use aoc_core::{Part, Solver};
+    use std::path::Path;
+
+    #[test]
+    fn manual_parsing_one() {
+        let hay = "1one";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 11)
+    }
+
+    #[test]
+    fn manual_parsing_two() {
+        let hay = "2two";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 22)
+    }
+
+    #[test]
+    fn manual_parsing_three() {
+        let hay = "3three";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 33)
+    }
+
+    #[test]
+    fn manual_parsing_four() {
+        let hay = "4four";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 44)
+    }
+
+    #[test]
+    fn manual_parsing_five() {
+        let hay = "5five";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 55)
+    }
+
+    #[test]
+    fn manual_parsing_six() {
+        let hay = "6six";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 66)
+    }
+
+    #[test]
+    fn manual_parsing_seven() {
+        let hay = "7seven";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 77)
+    }
+
+    #[test]
+    fn manual_parsing_eight() {
+        let hay = "8eight";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 88)
+    }
+
+    #[test]
+    fn manual_parsing_nine() {
+        let hay = "9nine";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 99)
+    }
+
+    #[test]
+    fn manual_parsing_all_numbers() {
+        let hay = "123456789onetwothreefourfivesixseveneightnine";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 19)
+    }
+
+    #[test]
+    fn manual_parsing_overlap() {
+        let hay = "eighthree";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 83)
+    }
+
+    #[test]
+    fn manual_parsing_shared_letters() {
+        let hay = "twoneightwone";
+        let result = extract_number_from_line(hay.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 21)
+    }
+
+    #[test]
+    fn extract_correct_number_from_line() {
+        let input = "one1two";
+        let result = extract_number_from_line(input.to_owned(), Scanner::spelled_digits()).unwrap();
+        assert_eq!(result, 12)
+    }
+
+    #[test]
+    fn other_vocabularies() {
+        let vocabulary = Vocabulary::parse("uno = 1\ndos = 2\ntres = 3\ndiecisiete = 17")
+            .unwrap()
+            .ignore_case();
+        let input = "Dosuno\nxdiecisiete9\ntreS4one".to_owned();
+        let result = extract_all_numbers(input, &vocabulary).unwrap();
+        assert_eq!(result, vec![21, 19, 34]);
+    }
+
+    #[test]
+    fn vocabulary_from_a_file() {
+        let vocabulary = Vocabulary::load(Path::new("src/example_vocabulary.txt"))
+            .unwrap()
+            .ignore_case();
+        let input_data = load_input("src/example_input_spanish.txt").unwrap();
+        let result = sum_calibration_values(input_data.as_bytes(), &vocabulary).unwrap();
+        assert_eq!(result, 21 + 19 + 34);
+        let answer = Day1With(vocabulary).solve(Part::Two, &input_data).unwrap();
+        assert_eq!(answer.to_string(), "74");
+    }
+
+    #[test]
+    fn stream_sum() {
+        let input_data = load_input("src/example_input_part_2.txt").unwrap();
+        let result = sum_calibration_values(input_data.as_bytes(), &Vocabulary::english());
+        assert_eq!(result.unwrap(), 281);
+    }
+
+    #[test]
+    fn load_file() {
+        let result = load_input("src/example_input_part_2.txt").unwrap();
+        assert_eq!(result, "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen");
+    }
+
+    #[test]
+    fn find_numbers() {
+        let input_data = load_input("src/example_input_part_2.txt").unwrap();
+        let mut result: Vec<i32> = Vec::new();
+        for line in input_data.lines() {
+            let extracted_number =
+                extract_number_from_line(line.to_owned(), Scanner::spelled_digits()).unwrap();
+            result.push(extracted_number);
+        }
+        assert_eq!(result, vec![29, 83, 13, 24, 42, 14, 76]);
+    }
+
+    #[test]
+    fn find_sum() {
+        let input_data = load_input("src/example_input_part_2.txt").unwrap();
+        let extracted_numbers = extract_all_numbers(input_data, &Vocabulary::english()).unwrap();
+        let result: i32 = extracted_numbers.iter().sum();
+        assert_eq!(result, 281);
+    }
+}
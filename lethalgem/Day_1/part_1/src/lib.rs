@@ -0,0 +1,128 @@
+use aoc_calibration::{CalibrationError, Engine, Scanner};
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Part1Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("{0}")]
+    Calibration(#[from] CalibrationError),
+}
+
+pub fn run(input_path: &Path) -> Result<(), Part1Error> {
+    let reader = info_span!("load").in_scope(|| open_input(input_path))?;
+    let answer = info_span!("solve").in_scope(|| sum_calibration_values(reader))?;
+    println!("{}", answer);
+    Ok(())
+}
+
+/// Streams the calibration document rather than loading it, so it can be
+/// any size.
+pub fn sum_calibration_values(reader: impl BufRead) -> Result<u64, Part1Error> {
+    let [sum] = Engine::default().sums(reader, [Scanner::digits()])?;
+    debug!("summed calibration values to {}", sum);
+    Ok(sum)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_all_numbers(input.to_owned())?)
+    }
+
+    fn part_one(&self, numbers: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(numbers.iter().sum::<i32>().into())
+    }
+}
+
+#[cfg(test)]
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Part1Error> {
+    let data = aoc_core::read_input(file_path.as_ref()).map_err(Part1Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn extract_all_numbers(input: String) -> Result<Vec<i32>, Part1Error> {
+    let mut numbers: Vec<i32> = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let extracted_number = extract_number_from_line(line.to_owned())
+            .map_err(|diagnostic| diagnostic.after_lines(index))?;
+        numbers.push(extracted_number);
+    }
+    Ok(numbers)
+}
+
+fn extract_number_from_line(line: String) -> Result<i32, Diagnostic> {
+    let mut nums: Vec<char> = Vec::new();
+    for c in line.chars() {
+        c.is_ascii_digit().then(|| nums.push(c));
+    }
+    let (Some(first_num), Some(last_num)) = (nums.first(), nums.last()) else {
+        return Err(Diagnostic::new(&line, &line, "no digit found in line"));
+    };
+    let composed_num = format!("{}{}", first_num, last_num)
+        .parse::<i32>()
+        .map_err(|err| Diagnostic::new(&line, &line, err.to_string()))?;
+    Ok(composed_num)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        extract_all_numbers, extract_number_from_line, load_input, sum_calibration_values,
+    };
+
+    #[test]
+    fn stream_sum() {
+        let input_data = load_input("src/example_input.txt").unwrap();
+        let result = sum_calibration_values(input_data.as_bytes());
+        assert_eq!(result.unwrap(), 142);
+    }
+
+    #[test]
+    fn load_file() {
+        let result = load_input("src/example_input.txt").unwrap();
+        assert_eq!(result, "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet");
+    }
+
+    #[test]
+    fn find_numbers() {
+        let input_data = load_input("src/example_input.txt").unwrap();
+        let mut result: Vec<i32> = Vec::new();
+        for line in input_data.lines() {
+            let extracted_number = extract_number_from_line(line.to_owned()).unwrap();
+            result.push(extracted_number);
+        }
+        assert_eq!(result, vec![12, 38, 15, 77]);
+    }
+
+    #[test]
+    fn find_sum() {
+        let input_data = load_input("src/example_input.txt").unwrap();
+        let extracted_numbers = extract_all_numbers(input_data).unwrap();
+        let result: i32 = extracted_numbers.iter().sum();
+        assert_eq!(result, 142);
+    }
+
+    #[test]
+    fn report_line_without_digits() {
+        let err = extract_all_numbers("1abc2\npqrstu\n".to_owned()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no digit found in line
+ --> <input>:2:1
+  |
+2 | pqrstu
+  | ^^^^^^"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use aoc_core::{read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_grid::Grid;
+use std::{collections::HashMap, path::Path};
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day11Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("Failed to find corresponding x in map")]
+    MissingXMapCoord,
+    #[error("Failed to find corresponding y in map")]
+    MissingYMapCoord,
+    #[error("Failed to find next galaxy for pairing")]
+    MissingNextGalaxy,
+}
+
+#[derive(Debug, Clone)]
+struct GalaxyPair {
+    #[allow(dead_code)]
+    start: Galaxy,
+    #[allow(dead_code)]
+    end: Galaxy,
+    shortest_distance: usize,
+}
+
+impl GalaxyPair {
+    fn new(start: Galaxy, end: Galaxy) -> GalaxyPair {
+        GalaxyPair {
+            start: start.clone(),
+            end: end.clone(),
+            shortest_distance: Self::calculate_shortest_distance(start.location, end.location),
+        }
+    }
+
+    fn calculate_shortest_distance(start_location: Location, end_location: Location) -> usize {
+        let x_steps: i64 = start_location.x as i64 - end_location.x as i64;
+        let y_steps: i64 = start_location.y as i64 - end_location.y as i64;
+        (x_steps.abs() + y_steps.abs()) as usize
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Galaxy {
+    id: usize,
+    location: Location,
+}
+
+#[derive(Debug, Clone)]
+struct Location {
+    x: usize,
+    y: usize,
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day11Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let galaxies = info_span!("parse").in_scope(|| find_galaxies(input_data))?;
+    let sum = info_span!("solve").in_scope(|| {
+        let expanded_galaxies = expand_universe(galaxies)?;
+        let pairs = create_pairs(expanded_galaxies)?;
+        Ok::<_, Day11Error>(sum_shortest_distances(pairs))
+    })?;
+    println!("{}", sum);
+    Ok(())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Galaxy>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(find_galaxies(input.to_owned())?)
+    }
+
+    fn part_two(&self, galaxies: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(sum_shortest_distances(create_pairs(expand_universe(galaxies)?)?).into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day11Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day11Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn sum_shortest_distances(pairs: Vec<GalaxyPair>) -> i64 {
+    pairs.iter().map(|pair| pair.shortest_distance as i64).sum()
+}
+
+fn create_pairs(galaxies: Vec<Galaxy>) -> Result<Vec<GalaxyPair>, Day11Error> {
+    let mut galaxy_pairs: Vec<GalaxyPair> = Vec::new();
+    for (i, galaxy) in galaxies.iter().enumerate() {
+        for j in i + 1..galaxies.len() {
+            galaxy_pairs.push(GalaxyPair::new(
+                galaxy.clone(),
+                galaxies
+                    .get(j)
+                    .ok_or(Day11Error::MissingNextGalaxy)?
+                    .clone(),
+            ))
+        }
+    }
+    Ok(galaxy_pairs)
+}
+
+fn find_galaxies(universe: String) -> Result<Vec<Galaxy>, Day11Error> {
+    let image = Grid::try_parse_with(&universe, |char| match char {
+        '#' | '.' => Ok(char),
+        _ => Err(format!("expected `#` or `.`, found `{}`", char)),
+    })?;
+
+    let mut galaxies: Vec<Galaxy> = Vec::new();
+    for (point, _) in image.iter().filter(|(_, &char)| char == '#') {
+        galaxies.push(Galaxy {
+            id: galaxies.len() + 1,
+            location: Location {
+                x: point.col,
+                y: point.row,
+            },
+        })
+    }
+
+    Ok(galaxies)
+}
+
+fn expand_universe(galaxies: Vec<Galaxy>) -> Result<Vec<Galaxy>, Day11Error> {
+    let mut rows_with_galaxies: Vec<usize> =
+        galaxies.iter().map(|galaxy| galaxy.location.y).collect();
+    rows_with_galaxies.sort();
+    rows_with_galaxies.dedup();
+
+    let mut cols_with_galaxies: Vec<usize> =
+        galaxies.iter().map(|galaxy| galaxy.location.x).collect();
+    cols_with_galaxies.sort();
+    cols_with_galaxies.dedup();
+
+    let mut expanded_rows_map: HashMap<usize, usize> = HashMap::new();
+    build_map(rows_with_galaxies, &mut expanded_rows_map);
+
+    let mut expanded_cols_map: HashMap<usize, usize> = HashMap::new();
+    build_map(cols_with_galaxies, &mut expanded_cols_map);
+
+    let mut expanded_universe_galaxies: Vec<Galaxy> = Vec::new();
+    for galaxy in galaxies {
+        expanded_universe_galaxies.push(Galaxy {
+            id: galaxy.id,
+            location: Location {
+                x: *expanded_cols_map
+                    .get(&galaxy.location.x)
+                    .ok_or(Day11Error::MissingXMapCoord)?,
+                y: *expanded_rows_map
+                    .get(&galaxy.location.y)
+                    .ok_or(Day11Error::MissingYMapCoord)?,
+            },
+        })
+    }
+
+    Ok(expanded_universe_galaxies)
+}
+
+fn build_map(coord_with_galaxies: Vec<usize>, expanded_rows_map: &mut HashMap<usize, usize>) {
+    let mut expansion_count: usize = 0;
+    (0_usize..=*coord_with_galaxies.last().unwrap_or(&0)).for_each(|i| {
+        if !coord_with_galaxies.contains(&i) {
+            expansion_count += 999999;
+        } else {
+            expanded_rows_map.insert(i, i + expansion_count);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{create_pairs, find_galaxies, load_input};
+
+    fn check(actual: &str, expect: expect_test::Expect) {
+        expect.assert_eq(actual);
+    }
+
+    #[test]
+    fn find_correct_galaxy_locations() {
+        let input = load_input("src/initial_universe_example.txt").unwrap();
+        let result = find_galaxies(input).unwrap();
+        check(&format!("{:?}", result), expect_test::expect!["[Galaxy { id: 1, location: Location { x: 3, y: 0 } }, Galaxy { id: 2, location: Location { x: 7, y: 1 } }, Galaxy { id: 3, location: Location { x: 0, y: 2 } }, Galaxy { id: 4, location: Location { x: 6, y: 4 } }, Galaxy { id: 5, location: Location { x: 1, y: 5 } }, Galaxy { id: 6, location: Location { x: 9, y: 6 } }, Galaxy { id: 7, location: Location { x: 7, y: 8 } }, Galaxy { id: 8, location: Location { x: 0, y: 9 } }, Galaxy { id: 9, location: Location { x: 4, y: 9 } }]"]);
+    }
+
+    #[test]
+    fn create_pairs_test() {
+        let input = load_input("src/expanded_universe_example.txt").unwrap();
+        let galaxies = find_galaxies(input).unwrap();
+        let pairs = create_pairs(galaxies).unwrap();
+        let result = pairs.len();
+        check(&format!("{:?}", result), expect_test::expect!["36"]);
+    }
+}
@@ -0,0 +1,128 @@
+use aoc_core::{read_input, Answer, InputError, Solution, SolutionError};
+use std::{num::ParseIntError, path::Path};
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day15Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("Failed to parse int")]
+    ParseIntFailed(#[from] ParseIntError),
+}
+
+#[derive(Debug)]
+pub struct Step {
+    hash_result: u32,
+}
+
+impl Step {
+    fn new(string: &str) -> Step {
+        Step {
+            hash_result: Self::calculate_hash(string),
+        }
+    }
+
+    fn calculate_hash(string: &str) -> u32 {
+        let mut current_hash_value = 0;
+        for c in string.chars() {
+            current_hash_value += c as u32;
+            current_hash_value *= 17;
+            current_hash_value %= 256;
+        }
+        current_hash_value
+    }
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day15Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let steps = info_span!("parse").in_scope(|| extract_steps(&input_data));
+    let sum = info_span!("solve").in_scope(|| sum_steps(steps));
+    println!("{}", sum);
+    Ok(())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Step>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_steps(input))
+    }
+
+    fn part_one(&self, steps: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(sum_steps(steps).into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day15Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day15Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn sum_steps(steps: Vec<Step>) -> u32 {
+    steps.into_iter().map(|step| step.hash_result).sum()
+}
+
+fn extract_steps(input: &str) -> Vec<Step> {
+    input.trim_end().split(',').map(Step::new).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::{extract_steps, load_input, sum_steps, Step};
+
+    fn check(actual: &str, expect: expect_test::Expect) {
+        expect.assert_eq(actual);
+    }
+
+    #[test]
+    fn test_hashing() {
+        let input = "H";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["200"]);
+
+        let input = "HA";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["153"]);
+
+        let input = "HAS";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["172"]);
+
+        let input = "HASH";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["52"]);
+
+        let input = "rn=1";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["30"]);
+    }
+
+    #[test]
+    fn test_extraction() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let result = extract_steps(input);
+        check(
+            &format!("{:?}", result),
+            expect!["[Step { hash_result: 30 }, Step { hash_result: 253 }, Step { hash_result: 97 }, Step { hash_result: 47 }, Step { hash_result: 14 }, Step { hash_result: 180 }, Step { hash_result: 9 }, Step { hash_result: 197 }, Step { hash_result: 48 }, Step { hash_result: 214 }, Step { hash_result: 231 }]"],
+        );
+    }
+
+    #[test]
+    fn test_sum() {
+        let input = load_input("src/example.txt").unwrap();
+        let steps = extract_steps(&input);
+        let result = sum_steps(steps);
+        check(&format!("{:?}", result), expect!["1320"]);
+
+        let input = format!("{}\n", input);
+        let steps = extract_steps(&input);
+        let result = sum_steps(steps);
+        check(&format!("{:?}", result), expect!["1320"]);
+    }
+}
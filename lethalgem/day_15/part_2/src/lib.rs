@@ -0,0 +1,286 @@
+use aoc_core::{read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{either, pair, preceded, tag, uint, word, Parser, Span};
+use std::{collections::HashMap, path::Path};
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day15Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("Focusing power of lens {0} is too large")]
+    FocusingPowerOverflow(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Step {
+    lens_box: u32,
+    label: String,
+    focal_length: Option<u32>,
+    #[allow(dead_code)]
+    hash_result: u32,
+}
+
+impl Step {
+    fn new(string: Span) -> Result<Step, Diagnostic> {
+        let operation = either(tag("-").map(|_| None), preceded(tag("="), uint()).map(Some));
+        let (label, focal_length) = pair(word(), operation).parse_all(string).map_err(|_| {
+            string.error("expected a label followed by `-`, or by `=` and a focal length")
+        })?;
+
+        Ok(Step {
+            lens_box: Self::calculate_hash(label),
+            label: label.to_owned(),
+            focal_length,
+            hash_result: Self::calculate_hash(string.as_str()),
+        })
+    }
+
+    fn calculate_hash(string: &str) -> u32 {
+        let mut current_hash_value = 0;
+        for c in string.chars() {
+            current_hash_value += c as u32;
+            current_hash_value *= 17;
+            current_hash_value %= 256;
+        }
+        current_hash_value
+    }
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day15Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let steps = info_span!("parse").in_scope(|| extract_steps(&input_data))?;
+    let focusing_power = info_span!("solve").in_scope(|| {
+        let boxes = sort_lenses(steps);
+        calc_focusing_power(boxes)
+    })?;
+    println!("{}", focusing_power);
+    Ok(())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Step>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_steps(input)?)
+    }
+
+    fn part_two(&self, steps: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(calc_focusing_power(sort_lenses(steps))?.into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day15Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day15Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn calc_focusing_power(boxes: HashMap<u32, Vec<Step>>) -> Result<u32, Day15Error> {
+    let mut total_focusing_power: u32 = 0;
+    for box_ in boxes.iter() {
+        for (i, lens) in box_.1.iter().enumerate() {
+            if let Some(focal_length) = lens.focal_length {
+                total_focusing_power = u32::try_from(i + 1)
+                    .ok()
+                    .and_then(|slot| (lens.lens_box + 1).checked_mul(slot))
+                    .and_then(|power| power.checked_mul(focal_length))
+                    .and_then(|power| total_focusing_power.checked_add(power))
+                    .ok_or_else(|| Day15Error::FocusingPowerOverflow(lens.label.clone()))?;
+            }
+        }
+    }
+    Ok(total_focusing_power)
+}
+
+fn sort_lenses(steps: Vec<Step>) -> HashMap<u32, Vec<Step>> {
+    let mut boxes: HashMap<u32, Vec<Step>> = HashMap::new();
+
+    for step in steps {
+        if step.focal_length.is_some() {
+            if let Some(lenses_in_box) = boxes.clone().get(&step.lens_box) {
+                replace_or_insert_lens(lenses_in_box, &step, &mut boxes);
+            } else {
+                boxes.insert(step.lens_box, vec![step]);
+            }
+        } else if let Some(lenses_in_box) = boxes.clone().get(&step.lens_box) {
+            remove_lens(lenses_in_box, step, &mut boxes);
+        }
+    }
+
+    boxes
+}
+
+fn replace_or_insert_lens(
+    lenses_in_box: &[Step],
+    step: &Step,
+    boxes: &mut HashMap<u32, Vec<Step>>,
+) {
+    let mut updated_lenses_in_box = lenses_in_box.to_vec();
+
+    if let Some(existing_lens_index) = updated_lenses_in_box
+        .iter()
+        .position(|lens| lens.label == step.label)
+    {
+        updated_lenses_in_box.remove(existing_lens_index);
+        updated_lenses_in_box.insert(existing_lens_index, step.clone())
+    } else {
+        updated_lenses_in_box.push(step.clone());
+    }
+
+    boxes.insert(step.lens_box, updated_lenses_in_box.to_vec());
+}
+
+fn remove_lens(lenses_in_box: &[Step], step: Step, boxes: &mut HashMap<u32, Vec<Step>>) {
+    let mut updated_lenses_in_box = lenses_in_box.to_vec();
+    if let Some(lens_index_to_remove) = updated_lenses_in_box
+        .iter()
+        .position(|lens| lens.label == step.label)
+    {
+        updated_lenses_in_box.remove(lens_index_to_remove);
+    }
+    boxes.insert(step.lens_box, updated_lenses_in_box.to_vec());
+}
+
+fn extract_steps(input: &str) -> Result<Vec<Step>, Day15Error> {
+    Ok(Span::new(input)
+        .trim()
+        .split(',')
+        .filter(|step| !step.is_empty())
+        .map(Step::new)
+        .collect::<Result<Vec<Step>, Diagnostic>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use expect_test::expect;
+
+    use crate::{calc_focusing_power, extract_steps, load_input, sort_lenses, Day15Error, Step};
+
+    fn check(actual: &str, expect: expect_test::Expect) {
+        expect.assert_eq(actual);
+    }
+
+    #[test]
+    fn test_hashing() {
+        let input = "H";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["200"]);
+
+        let input = "HA";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["153"]);
+
+        let input = "HAS";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["172"]);
+
+        let input = "HASH";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["52"]);
+
+        let input = "rn=1";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["30"]);
+
+        let input = "rn";
+        let result = Step::calculate_hash(input);
+        check(&format!("{}", result), expect!["0"]);
+    }
+
+    #[test]
+    fn test_extraction() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let result = extract_steps(input);
+        check(
+            &format!("{:?}", result),
+            expect![[
+                r#"Ok([Step { lens_box: 0, label: "rn", focal_length: Some(1), hash_result: 30 }, Step { lens_box: 0, label: "cm", focal_length: None, hash_result: 253 }, Step { lens_box: 1, label: "qp", focal_length: Some(3), hash_result: 97 }, Step { lens_box: 0, label: "cm", focal_length: Some(2), hash_result: 47 }, Step { lens_box: 1, label: "qp", focal_length: None, hash_result: 14 }, Step { lens_box: 3, label: "pc", focal_length: Some(4), hash_result: 180 }, Step { lens_box: 3, label: "ot", focal_length: Some(9), hash_result: 9 }, Step { lens_box: 3, label: "ab", focal_length: Some(5), hash_result: 197 }, Step { lens_box: 3, label: "pc", focal_length: None, hash_result: 48 }, Step { lens_box: 3, label: "pc", focal_length: Some(6), hash_result: 214 }, Step { lens_box: 3, label: "ot", focal_length: Some(7), hash_result: 231 }])"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn test_sorting() {
+        let input = "rn=1,cm-";
+        let steps = extract_steps(input).unwrap();
+        let boxes = sort_lenses(steps);
+        let mut result: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+        for box_ in boxes.iter() {
+            let mut labels: Vec<String> = Vec::new();
+            for step in box_.1 {
+                labels.push(step.clone().label)
+            }
+            result.insert(*box_.0, labels);
+        }
+        check(&format!("{:?}", result), expect![r#"{0: ["rn"]}"#]);
+
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,";
+        let steps = extract_steps(input).unwrap();
+        let boxes = sort_lenses(steps);
+        let mut result: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+        for box_ in boxes.iter() {
+            let mut labels: Vec<String> = Vec::new();
+            for step in box_.1 {
+                labels.push(step.clone().label)
+            }
+            result.insert(*box_.0, labels);
+        }
+        check(
+            &format!("{:?}", result),
+            expect![r#"{0: ["rn", "cm"], 1: []}"#],
+        );
+
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let steps = extract_steps(input).unwrap();
+        let boxes = sort_lenses(steps);
+        let mut result: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+        for box_ in boxes.iter() {
+            let mut labels: Vec<String> = Vec::new();
+            for step in box_.1 {
+                labels.push(step.clone().label)
+            }
+            result.insert(*box_.0, labels);
+        }
+        check(
+            &format!("{:?}", result),
+            expect![r#"{0: ["rn", "cm"], 1: [], 3: ["ot", "ab", "pc"]}"#],
+        );
+    }
+
+    #[test]
+    fn test_focusing_power() {
+        let input = load_input("src/example.txt").unwrap();
+        let steps = extract_steps(&input).unwrap();
+        let boxes = sort_lenses(steps);
+        let result = calc_focusing_power(boxes).unwrap();
+        check(&format!("{:?}", result), expect!["145"]);
+    }
+
+    #[test]
+    fn reject_focusing_power_too_large() {
+        let steps = extract_steps("aaaa=4294967295").unwrap();
+        let result = calc_focusing_power(sort_lenses(steps));
+        assert!(matches!(result, Err(Day15Error::FocusingPowerOverflow(label)) if label == "aaaa"));
+    }
+
+    #[test]
+    fn report_malformed_step() {
+        let err = extract_steps("rn=1,cm-,qp=x,cm=2").unwrap_err();
+        check(
+            &err.to_string(),
+            expect![[r#"
+                expected a label followed by `-`, or by `=` and a focal length
+                 --> <input>:1:10
+                  |
+                1 | rn=1,cm-,qp=x,cm=2
+                  |          ^^^^"#]],
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use aoc_core::{read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_grid::{Direction, Grid, Point};
+use std::path::Path;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day16Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+#[derive(Debug, Clone)]
+pub enum EncounterType {
+    LeftTiltMirror,     // \
+    RightTiltMirror,    // /
+    HorizontalSplitter, // -
+    VerticalSplitter,   // |
+}
+
+#[derive(Debug, Clone)]
+struct Beam {
+    id: i32,
+    current_location: Point,
+    direction: Direction,
+    has_stopped_bouncing: bool,
+}
+
+impl Beam {
+    fn new(id: i32, current_location: Point, direction: Direction) -> Beam {
+        Beam {
+            id,
+            current_location,
+            direction,
+            has_stopped_bouncing: false,
+        }
+    }
+
+    fn update_current_location(&mut self, location: Point) {
+        self.current_location = location;
+    }
+
+    fn update_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    fn stop_bouncing(&mut self) {
+        self.has_stopped_bouncing = true;
+    }
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day16Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let encounters = info_span!("parse").in_scope(|| locate_all_encounters(&input_data))?;
+    let distance_traveled = info_span!("solve").in_scope(|| track_beam(&encounters));
+    println!("{}", distance_traveled);
+    Ok(())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Grid<Option<EncounterType>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(locate_all_encounters(input)?)
+    }
+
+    fn part_one(&self, encounters: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(track_beam(&encounters).into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day16Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day16Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn track_beam(encounters: &Grid<Option<EncounterType>>) -> usize {
+    let mut path_traveled_by_all_beams: Vec<Point> = vec![Point::new(0, 0)];
+    let mut path_visited_before_cutoff = 10000000;
+    let mut beams = vec![Beam::new(0, Point::new(0, 0), Direction::Right)];
+
+    let mut beams_still_bouncing = true;
+    while beams_still_bouncing {
+        let mut all_moved_beams: Vec<Beam> = Vec::new();
+        for beam in beams {
+            let moved_beams = check_beam_location(encounters, beam.clone());
+            for moved_beam in moved_beams.clone() {
+                if !path_traveled_by_all_beams.contains(&moved_beam.current_location) {
+                    path_traveled_by_all_beams.push(moved_beam.current_location);
+                } else {
+                    path_visited_before_cutoff -= 1;
+                    if path_visited_before_cutoff <= 0 {
+                        beams_still_bouncing = false;
+                    }
+                }
+            }
+
+            let mut still_bouncing_beams: Vec<Beam> = moved_beams
+                .into_iter()
+                .filter(|beam| !beam.has_stopped_bouncing)
+                .collect();
+
+            all_moved_beams.append(&mut still_bouncing_beams);
+        }
+        beams = all_moved_beams;
+        if beams.is_empty() {
+            beams_still_bouncing = false;
+        }
+    }
+
+    path_traveled_by_all_beams.len()
+}
+
+fn check_beam_location(encounters: &Grid<Option<EncounterType>>, mut beam: Beam) -> Vec<Beam> {
+    let mut split_beam: Option<Beam> = None;
+
+    let current_location_encounter = &encounters[beam.current_location];
+    match current_location_encounter {
+        Some(EncounterType::RightTiltMirror) => match beam.direction {
+            Direction::Up => {
+                beam.update_direction(Direction::Right);
+                move_beam(&mut beam, encounters);
+            }
+            Direction::Right => {
+                beam.update_direction(Direction::Up);
+                move_beam(&mut beam, encounters);
+            }
+            Direction::Left => {
+                beam.update_direction(Direction::Down);
+                move_beam(&mut beam, encounters);
+            }
+            Direction::Down => {
+                beam.update_direction(Direction::Left);
+                move_beam(&mut beam, encounters);
+            }
+        },
+        Some(EncounterType::LeftTiltMirror) => match beam.direction {
+            Direction::Up => {
+                beam.update_direction(Direction::Left);
+                move_beam(&mut beam, encounters);
+            }
+            Direction::Right => {
+                beam.update_direction(Direction::Down);
+                move_beam(&mut beam, encounters);
+            }
+            Direction::Left => {
+                beam.update_direction(Direction::Up);
+                move_beam(&mut beam, encounters);
+            }
+            Direction::Down => {
+                beam.update_direction(Direction::Right);
+                move_beam(&mut beam, encounters);
+            }
+        },
+        Some(EncounterType::VerticalSplitter) => match beam.direction {
+            Direction::Up | Direction::Down => {
+                move_beam(&mut beam, encounters);
+            }
+            Direction::Right | Direction::Left => {
+                beam.update_direction(Direction::Up);
+                let mut pre_moved_split_beam =
+                    Beam::new(beam.id + 1, beam.current_location, Direction::Down);
+
+                move_beam(&mut beam, encounters);
+                move_beam(&mut pre_moved_split_beam, encounters);
+
+                split_beam = Some(pre_moved_split_beam);
+            }
+        },
+        Some(EncounterType::HorizontalSplitter) => match beam.direction {
+            Direction::Up | Direction::Down => {
+                beam.update_direction(Direction::Left);
+                let mut pre_moved_split_beam =
+                    Beam::new(beam.id + 1, beam.current_location, Direction::Right);
+
+                move_beam(&mut beam, encounters);
+                move_beam(&mut pre_moved_split_beam, encounters);
+
+                split_beam = Some(pre_moved_split_beam);
+            }
+            Direction::Right | Direction::Left => {
+                move_beam(&mut beam, encounters);
+            }
+        },
+        _ => move_beam(&mut beam, encounters),
+    }
+
+    if let Some(split_beam) = split_beam {
+        vec![beam, split_beam]
+    } else {
+        vec![beam]
+    }
+}
+
+fn move_beam(beam: &mut Beam, encounters: &Grid<Option<EncounterType>>) {
+    match encounters.step(beam.current_location, beam.direction) {
+        Some(new_location) => beam.update_current_location(new_location),
+        None => beam.stop_bouncing(),
+    }
+}
+
+fn locate_all_encounters(input: &str) -> Result<Grid<Option<EncounterType>>, Day16Error> {
+    let encounters = Grid::try_parse_with(input, |c| match c {
+        '\\' => Ok(Some(EncounterType::LeftTiltMirror)),
+        '/' => Ok(Some(EncounterType::RightTiltMirror)),
+        '|' => Ok(Some(EncounterType::VerticalSplitter)),
+        '-' => Ok(Some(EncounterType::HorizontalSplitter)),
+        '.' => Ok(None),
+        c => Err(format!("expected one of .\\/|-, found `{}`", c)),
+    })?;
+
+    Ok(encounters)
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use std::collections::BTreeMap;
+
+    use crate::{load_input, locate_all_encounters, track_beam, EncounterType};
+
+    fn check(actual: &str, expect: expect_test::Expect) {
+        expect.assert_eq(actual);
+    }
+
+    fn encounter_locations(input: &str) -> BTreeMap<(usize, usize), EncounterType> {
+        let encounters = locate_all_encounters(input).unwrap();
+        encounters
+            .iter()
+            .filter_map(|(point, encounter)| Some(((point.col, point.row), encounter.clone()?)))
+            .collect()
+    }
+
+    #[test]
+    fn test_encounter_locations() {
+        let input = r".|...\....";
+        let result = encounter_locations(input);
+        check(
+            &format!("{:?}", result),
+            expect!["{(1, 0): VerticalSplitter, (5, 0): LeftTiltMirror}"],
+        );
+
+        let input = r".//...\....";
+        let result = encounter_locations(input);
+        check(
+            &format!("{:?}", result),
+            expect!["{(1, 0): RightTiltMirror, (2, 0): RightTiltMirror, (6, 0): LeftTiltMirror}"],
+        );
+
+        let input = r"..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+        let result = encounter_locations(input);
+        check(&format!("{:?}", result), expect!["{(1, 1): HorizontalSplitter, (1, 2): VerticalSplitter, (2, 3): RightTiltMirror, (3, 1): HorizontalSplitter, (3, 3): RightTiltMirror, (4, 0): RightTiltMirror, (4, 1): RightTiltMirror, (5, 3): VerticalSplitter, (6, 0): LeftTiltMirror, (6, 2): HorizontalSplitter, (7, 0): LeftTiltMirror, (7, 1): VerticalSplitter, (7, 2): VerticalSplitter, (9, 2): LeftTiltMirror}"]);
+
+        let input = r".|...\....
+|.-.\.....";
+        let result = encounter_locations(input);
+        check(&format!("{:?}", result), expect!["{(0, 1): VerticalSplitter, (1, 0): VerticalSplitter, (2, 1): HorizontalSplitter, (4, 1): LeftTiltMirror, (5, 0): LeftTiltMirror}"]);
+
+        let input = load_input("src/example.txt").unwrap();
+        let result = encounter_locations(&input);
+        check(&format!("{:?}", result), expect!["{(0, 1): VerticalSplitter, (1, 0): VerticalSplitter, (1, 7): HorizontalSplitter, (1, 8): VerticalSplitter, (2, 1): HorizontalSplitter, (2, 9): RightTiltMirror, (3, 7): HorizontalSplitter, (3, 9): RightTiltMirror, (4, 1): LeftTiltMirror, (4, 6): RightTiltMirror, (4, 7): RightTiltMirror, (5, 0): LeftTiltMirror, (5, 2): VerticalSplitter, (5, 9): VerticalSplitter, (6, 2): HorizontalSplitter, (6, 6): LeftTiltMirror, (6, 8): HorizontalSplitter, (7, 6): LeftTiltMirror, (7, 7): VerticalSplitter, (7, 8): VerticalSplitter, (8, 3): VerticalSplitter, (9, 5): LeftTiltMirror, (9, 8): LeftTiltMirror}"]);
+    }
+
+    #[test]
+    fn test_grid_bounds() {
+        let input = r"..........";
+        let result = locate_all_encounters(input).unwrap();
+        check(
+            &format!("{:?}", (result.width() - 1, result.height() - 1)),
+            expect!["(9, 0)"],
+        );
+
+        let input = r"..........
+..........
+..........
+..........";
+        let result = locate_all_encounters(input).unwrap();
+        check(
+            &format!("{:?}", (result.width() - 1, result.height() - 1)),
+            expect!["(9, 3)"],
+        );
+
+        let input = load_input("src/example.txt").unwrap();
+        let result = locate_all_encounters(&input).unwrap();
+        check(
+            &format!("{:?}", (result.width() - 1, result.height() - 1)),
+            expect!["(9, 9)"],
+        );
+    }
+
+    #[test]
+    fn test_beam_tracking() {
+        let input = r"..........";
+        let encounters = locate_all_encounters(input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["10"]);
+
+        let input = r"..../.....";
+        let encounters = locate_all_encounters(input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["5"]);
+
+        let input = r".......\..";
+        let encounters = locate_all_encounters(input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["8"]);
+
+        let input = r".\.......\
+...../.\..
+.\\.////..
+..\./.\../";
+        let encounters = locate_all_encounters(input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["29"]);
+
+        let input = r".|........
+.|........
+.-..-..|..
+..........";
+        let encounters = locate_all_encounters(input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["14"]);
+
+        let input = r".|........
+...|...\..
+.\..-../..
+..........";
+        let encounters = locate_all_encounters(input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["17"]);
+
+        let input = r".\........
+..........
+.\..|.....
+..........
+..........";
+        let encounters = locate_all_encounters(input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["11"]);
+
+        let input = r"....\.....
+..........
+....-.....
+..........
+..........";
+        let encounters = locate_all_encounters(input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["16"]);
+
+        let input = r".\........
+....-|....
+.\..|.....
+..........";
+        let encounters = locate_all_encounters(input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["16"]);
+
+        let input = load_input("src/example.txt").unwrap();
+        let encounters = locate_all_encounters(&input).unwrap();
+        let result = track_beam(&encounters);
+        check(&format!("{:?}", result), expect!["46"]);
+    }
+}
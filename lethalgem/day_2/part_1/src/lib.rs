@@ -0,0 +1,355 @@
+use aoc_core::{read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{
+    delimited, pair, preceded, separated, space, spanned, tag, uint, word, Parser, Span,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+/// The bag from the puzzle, used unless another is given.
+pub const DEFAULT_BAG: &str = "12 red, 13 green, 14 blue";
+
+#[derive(Error, Debug)]
+pub enum Day2Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    id: i32,
+    sets: Vec<Set>,
+}
+
+impl Game {
+    fn new_with_values(id: i32, sets: Vec<Set>) -> Game {
+        Game { id, sets }
+    }
+
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn sets(&self) -> &[Set] {
+        &self.sets
+    }
+
+    /// Whether every set in the game could have been drawn from `bag`.
+    pub fn is_possible(&self, bag: &Set) -> bool {
+        self.sets.iter().all(|set| bag.holds(set))
+    }
+}
+
+/// How many cubes of each color were drawn in one set, or are in a bag.
+/// Colors can be any name; a color that isn't there has a count of 0.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Set {
+    counts: BTreeMap<String, i32>,
+}
+
+impl Set {
+    pub fn new() -> Set {
+        Set::default()
+    }
+
+    #[cfg(test)]
+    fn new_with_values(red_count: i32, green_count: i32, blue_count: i32) -> Set {
+        let mut set = Set::new();
+        set.set_count("red", red_count);
+        set.set_count("green", green_count);
+        set.set_count("blue", blue_count);
+        set
+    }
+
+    /// The bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+    pub fn default_bag() -> Set {
+        Set::parse(DEFAULT_BAG).expect("the default bag parses")
+    }
+
+    /// Reads a set written the way the game log writes them, such as
+    /// `12 red, 13 green, 14 blue`.
+    pub fn parse(input: &str) -> Result<Set, Diagnostic> {
+        set().parse_all(Span::new(input))
+    }
+
+    /// Reads a bag from a file holding one set, such as
+    /// `12 red, 13 green, 14 blue`.
+    pub fn load(file_path: impl AsRef<Path>) -> Result<Set, Day2Error> {
+        let input = read_input(file_path.as_ref())?;
+        Set::parse(input.trim()).map_err(|diagnostic| diagnostic.in_file(file_path).into())
+    }
+
+    pub fn count(&self, color: &str) -> i32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn set_count(&mut self, color: impl Into<String>, count: i32) {
+        let color = color.into();
+        if count == 0 {
+            self.counts.remove(&color);
+        } else {
+            self.counts.insert(color, count);
+        }
+    }
+
+    /// Each color with any cubes and how many, in alphabetical order.
+    pub fn counts(&self) -> impl Iterator<Item = (&str, i32)> {
+        self.counts
+            .iter()
+            .map(|(color, count)| (color.as_str(), *count))
+    }
+
+    /// Whether this bag has at least as many cubes of every color as `set`.
+    pub fn holds(&self, set: &Set) -> bool {
+        set.counts()
+            .all(|(color, count)| count <= self.count(color))
+    }
+}
+
+impl fmt::Display for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts: Vec<String> = self
+            .counts()
+            .map(|(color, count)| format!("{} {}", count, color))
+            .collect();
+        write!(f, "{}", counts.join(", "))
+    }
+}
+
+pub fn run(input_path: &Path, bag: &Set) -> Result<(), Day2Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let games = info_span!("parse").in_scope(|| extract_games(input_data))?;
+    let answer = info_span!("solve").in_scope(|| sum_possible_games(&games, bag));
+    println!("{}", answer);
+    Ok(())
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Game>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_games(input.to_owned())?)
+    }
+
+    fn part_one(&self, games: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(sum_possible_games(&games, &Set::default_bag()).into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day2Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day2Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+pub fn sum_possible_games(games: &[Game], bag: &Set) -> i32 {
+    games
+        .iter()
+        .map(|game| if game.is_possible(bag) { game.id } else { 0 })
+        .sum()
+}
+
+pub fn extract_games(input: String) -> Result<Vec<Game>, Day2Error> {
+    Ok(Span::new(&input).parse_lines(game())?)
+}
+
+#[cfg(test)]
+fn extract_game(input: &str) -> Result<Game, Diagnostic> {
+    game().parse_all(Span::new(input))
+}
+
+#[cfg(test)]
+fn extract_game_id(input: &str) -> Result<i32, Diagnostic> {
+    game_id().parse_all(Span::new(input))
+}
+
+#[cfg(test)]
+fn extract_sets(input: &str) -> Result<Vec<Set>, Diagnostic> {
+    sets().parse_all(Span::new(input))
+}
+
+#[cfg(test)]
+fn extract_set(input: &str) -> Result<Set, Diagnostic> {
+    set().parse_all(Span::new(input))
+}
+
+fn game<'a>() -> impl Parser<'a, Game> {
+    pair(game_id(), sets()).map(|(id, sets)| Game::new_with_values(id, sets))
+}
+
+fn game_id<'a>() -> impl Parser<'a, i32> {
+    delimited(pair(tag("Game"), space()), uint(), tag(":"))
+}
+
+fn sets<'a>() -> impl Parser<'a, Vec<Set>> {
+    separated(set(), tag(";"))
+}
+
+fn set<'a>() -> impl Parser<'a, Set> {
+    let color_count = pair(
+        preceded(space(), uint()),
+        preceded(space(), spanned(word())),
+    );
+    let color_counts = separated(color_count, tag(","));
+
+    move |input: Span<'a>| {
+        let (color_counts, rest) = color_counts.parse(input)?;
+        let mut set = Set::new();
+        // A count of 0 leaves a color out of the set, so repeats are tracked apart
+        let mut seen = BTreeSet::new();
+        for (count, (color, at)) in color_counts {
+            if !seen.insert(color) {
+                return Err(at.fail(format!("`{}` is given more than once", color)));
+            }
+            set.set_count(color, count);
+        }
+        Ok((set, rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        extract_game, extract_game_id, extract_games, extract_set, extract_sets, load_input,
+        sum_possible_games, Game, Set,
+    };
+
+    #[test]
+    fn set() {
+        let input = "1 green, 3 red, 6 blue";
+        let result = extract_set(input).unwrap();
+        assert_eq!(result, Set::new_with_values(3, 1, 6))
+    }
+
+    #[test]
+    fn set_with_a_repeated_color() {
+        let err = extract_set("3 red, 0 blue, 4 red").unwrap_err();
+        assert_eq!(err.message, "`red` is given more than once");
+        assert_eq!(err.column, 18);
+        let err = extract_set("0 blue, 2 blue").unwrap_err();
+        assert_eq!(err.column, 11);
+    }
+
+    #[test]
+    fn sets() {
+        let input = "1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+        let result = extract_sets(input).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                Set::new_with_values(0, 2, 1),
+                Set::new_with_values(1, 3, 4),
+                Set::new_with_values(0, 1, 1)
+            ]
+        )
+    }
+
+    #[test]
+    fn game_id() {
+        let input = "Game 2:";
+        let result = extract_game_id(input).unwrap();
+        assert_eq!(result, 2)
+    }
+
+    #[test]
+    fn game() {
+        let input = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let result = extract_game(input).unwrap();
+        assert_eq!(
+            result,
+            Game {
+                id: 3,
+                sets: vec![
+                    Set::new_with_values(20, 8, 6),
+                    Set::new_with_values(4, 13, 5),
+                    Set::new_with_values(1, 5, 0)
+                ],
+            }
+        )
+    }
+
+    #[test]
+    fn extract_all_games() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let result = extract_games(input.to_owned()).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                Game {
+                    id: 1,
+                    sets: vec![
+                        Set::new_with_values(4, 0, 3),
+                        Set::new_with_values(1, 2, 6),
+                        Set::new_with_values(0, 2, 0)
+                    ],
+                },
+                Game {
+                    id: 2,
+                    sets: vec![
+                        Set::new_with_values(0, 2, 1),
+                        Set::new_with_values(1, 3, 4),
+                        Set::new_with_values(0, 1, 1)
+                    ],
+                },
+                Game {
+                    id: 3,
+                    sets: vec![
+                        Set::new_with_values(20, 8, 6),
+                        Set::new_with_values(4, 13, 5),
+                        Set::new_with_values(1, 5, 0)
+                    ],
+                },
+                Game {
+                    id: 4,
+                    sets: vec![
+                        Set::new_with_values(3, 1, 6),
+                        Set::new_with_values(6, 3, 0),
+                        Set::new_with_values(14, 3, 15)
+                    ],
+                },
+                Game {
+                    id: 5,
+                    sets: vec![Set::new_with_values(6, 3, 1), Set::new_with_values(1, 2, 2),],
+                },
+            ]
+        )
+    }
+
+    #[test]
+    fn sum_games() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let games = extract_games(input.to_owned()).unwrap();
+        let result = sum_possible_games(&games, &Set::default_bag());
+        assert_eq!(result, 8)
+    }
+
+    #[test]
+    fn any_colors() {
+        let games = extract_games("Game 1: 3 blue\nGame 2: 8 purple, 6 blue\n".to_owned()).unwrap();
+        assert_eq!(games[1].sets()[0].count("purple"), 8);
+        assert_eq!(sum_possible_games(&games, &Set::default_bag()), 1);
+
+        let bag = Set::parse("8 purple, 6 blue").unwrap();
+        assert_eq!(bag.to_string(), "6 blue, 8 purple");
+        assert_eq!(sum_possible_games(&games, &bag), 3);
+    }
+
+    #[test]
+    fn report_bad_bag() {
+        let err = Set::parse("12 red, lots green").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a number, found `lots`
+ --> <input>:1:9
+  |
+1 | 12 red, lots green
+  |         ^^^^"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::BTreeSet;
+
+use crate::{Game, Set};
+
+/// The game that needs the most cubes of one color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bottleneck {
+    pub color: String,
+    pub game_id: i32,
+    pub count: i32,
+}
+
+/// The ids of the games that aren't possible with `bag`, but would be with
+/// `extra` more cubes of `color`.
+pub fn newly_possible(games: &[Game], bag: &Set, color: &str, extra: u32) -> Vec<i32> {
+    let extra = i32::try_from(extra).unwrap_or(i32::MAX);
+    let mut bigger_bag = bag.clone();
+    bigger_bag.set_count(color, bag.count(color).saturating_add(extra));
+
+    games
+        .iter()
+        .filter(|game| !game.is_possible(bag) && game.is_possible(&bigger_bag))
+        .map(|game| game.id)
+        .collect()
+}
+
+/// The bag with the fewest cubes in all under which at least `at_least`
+/// games are possible, or `None` if there aren't that many games.
+///
+/// Each color's count only needs to be none or one of the counts some game
+/// needs, so this tries those, smallest first, one color at a time, and gives up
+/// on a branch once it has too few games left or too many cubes.
+pub fn smallest_bag(games: &[Game], at_least: usize) -> Option<Set> {
+    if at_least > games.len() {
+        return None;
+    }
+
+    let colors: BTreeSet<&str> = games
+        .iter()
+        .flat_map(|game| game.minimum_set.counts().map(|(color, _)| color))
+        .collect();
+    let mut search = BagSearch {
+        colors: colors.into_iter().collect(),
+        at_least,
+        best: None,
+    };
+    let games: Vec<&Game> = games.iter().collect();
+    search.fill(0, &games, &mut Set::new(), 0);
+
+    search.best.map(|(bag, _)| bag)
+}
+
+struct BagSearch<'a> {
+    colors: Vec<&'a str>,
+    at_least: usize,
+    best: Option<(Set, i64)>,
+}
+
+impl BagSearch<'_> {
+    // Picks a count for each color from `color` on, keeping to the games
+    // that still fit in `bag`
+    fn fill(&mut self, color: usize, games: &[&Game], bag: &mut Set, cubes: i64) {
+        let Some(&name) = self.colors.get(color) else {
+            self.best = Some((bag.clone(), cubes));
+            return;
+        };
+
+        let counts: BTreeSet<i32> = games
+            .iter()
+            .map(|game| game.minimum_set.count(name))
+            .chain([0])
+            .collect();
+        for count in counts {
+            let cubes = cubes + i64::from(count);
+            if self.best.as_ref().is_some_and(|(_, best)| cubes >= *best) {
+                break;
+            }
+            let fitting: Vec<&Game> = games
+                .iter()
+                .copied()
+                .filter(|game| game.minimum_set.count(name) <= count)
+                .collect();
+            if fitting.len() < self.at_least {
+                continue;
+            }
+
+            bag.set_count(name, count);
+            self.fill(color + 1, &fitting, bag, cubes);
+        }
+        bag.set_count(name, 0);
+    }
+}
+
+/// For each color, the game needing the most cubes of it, which sets how
+/// many the bag needs for every game to be possible. Ties go to the earlier
+/// game.
+pub fn bottlenecks(games: &[Game]) -> Vec<Bottleneck> {
+    let mut bottlenecks: Vec<Bottleneck> = Vec::new();
+    for game in games {
+        for (color, count) in game.minimum_set.counts() {
+            match bottlenecks.iter_mut().find(|found| found.color == color) {
+                Some(found) if count > found.count => {
+                    found.game_id = game.id;
+                    found.count = count;
+                }
+                Some(_) => {}
+                None => bottlenecks.push(Bottleneck {
+                    color: color.to_owned(),
+                    game_id: game.id,
+                    count,
+                }),
+            }
+        }
+    }
+    bottlenecks.sort_by(|a, b| a.color.cmp(&b.color));
+    bottlenecks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{extract_games, load_input};
+
+    fn example() -> Vec<Game> {
+        extract_games(load_input("src/example_1.txt").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn more_cubes_of_one_color() {
+        let games = example();
+        let bag = Set::default_bag();
+        assert_eq!(newly_possible(&games, &bag, "red", 8), vec![3]);
+        assert_eq!(newly_possible(&games, &bag, "blue", 1), Vec::<i32>::new());
+
+        let bag = Set::parse("20 red, 13 green, 14 blue").unwrap();
+        assert_eq!(newly_possible(&games, &bag, "blue", 1), vec![4]);
+        assert_eq!(newly_possible(&games, &bag, "blue", u32::MAX), vec![4]);
+    }
+
+    #[test]
+    fn smallest_bags() {
+        let games = example();
+        assert_eq!(
+            smallest_bag(&games, 5),
+            Some(Set::parse("20 red, 13 green, 15 blue").unwrap())
+        );
+        assert_eq!(
+            smallest_bag(&games, 3),
+            Some(Set::parse("6 red, 3 green, 6 blue").unwrap())
+        );
+        assert_eq!(smallest_bag(&games, 0), Some(Set::new()));
+        assert_eq!(smallest_bag(&games, 6), None);
+    }
+
+    #[test]
+    fn bottleneck_per_color() {
+        let bottlenecks = bottlenecks(&example());
+        let found: Vec<_> = bottlenecks
+            .iter()
+            .map(|b| (b.color.as_str(), b.game_id, b.count))
+            .collect();
+        assert_eq!(
+            found,
+            vec![("blue", 4, 15), ("green", 3, 13), ("red", 3, 20)]
+        );
+    }
+}
@@ -0,0 +1,433 @@
+use aoc_core::{diagnostic, read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_schematic::{Number, Point, Schematic, Symbol};
+use std::path::Path;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day3Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Span {
+    start: usize,
+    end: usize,
+}
+
+impl Span {
+    fn new() -> Span {
+        Span { start: 0, end: 0 }
+    }
+
+    fn set_start(&mut self, start: usize) {
+        self.start = start
+    }
+
+    fn set_end(&mut self, end: usize) {
+        self.end = end
+    }
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day3Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let schematic = info_span!("parse").in_scope(|| {
+        let (schematic_numbers, schematic_symbols) = scan_schematic(input_data)?;
+        Ok::<_, Day3Error>(Schematic::new(schematic_numbers, schematic_symbols))
+    })?;
+    let answer =
+        info_span!("solve").in_scope(|| sum_part_numbers(determine_part_numbers(&schematic)));
+    println!("{}", answer);
+    Ok(())
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Schematic;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        let (schematic_numbers, schematic_symbols) = scan_schematic(input.to_owned())?;
+        Ok(Schematic::new(schematic_numbers, schematic_symbols))
+    }
+
+    fn part_one(&self, schematic: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(sum_part_numbers(determine_part_numbers(&schematic)).into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day3Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day3Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn sum_part_numbers(part_numbers: Vec<u32>) -> u64 {
+    part_numbers.iter().map(|&part| u64::from(part)).sum()
+}
+
+fn determine_part_numbers(schematic: &Schematic) -> Vec<u32> {
+    let mut part_numbers: Vec<u32> = Vec::new();
+
+    for (id, number) in schematic.numbers().iter().enumerate() {
+        if is_adjacent_to_symbol(schematic, id) {
+            part_numbers.push(number.value);
+        }
+    }
+
+    part_numbers
+}
+
+fn is_adjacent_to_symbol(schematic: &Schematic, schematic_number: usize) -> bool {
+    schematic.symbols_next_to(schematic_number).next().is_some()
+}
+
+fn scan_schematic(full_schematic: String) -> Result<(Vec<Number>, Vec<Symbol>), Day3Error> {
+    let mut schematic_numbers: Vec<Number> = Vec::new();
+    let mut schematic_symbols: Vec<Symbol> = Vec::new();
+
+    for (line_number, line) in full_schematic.lines().enumerate() {
+        scan_for_numbers_in_line(line.to_owned(), line_number)
+            .map(|mut found_numbers| schematic_numbers.append(&mut found_numbers))?;
+        schematic_symbols.append(&mut scan_for_symbols_in_line(line.to_owned(), line_number));
+    }
+
+    Ok((schematic_numbers, schematic_symbols))
+}
+
+fn scan_for_symbols_in_line(schematic_line: String, line_number: usize) -> Vec<Symbol> {
+    let mut schematic_symbols: Vec<Symbol> = Vec::new();
+
+    let chars_in_line = schematic_line.chars();
+    for (i, char) in chars_in_line.clone().enumerate() {
+        if !char.is_ascii_digit() && char != '.' {
+            schematic_symbols.push(Symbol {
+                symbol: char,
+                point: Point::new(line_number, i),
+            })
+        }
+    }
+
+    schematic_symbols
+}
+
+fn scan_for_numbers_in_line(
+    schematic_line: String,
+    line_number: usize,
+) -> Result<Vec<Number>, Day3Error> {
+    let mut schematic_numbers: Vec<Number> = Vec::new();
+
+    let mut constructed_number = String::new();
+    let mut span = Span::new();
+
+    let line_length = schematic_line.chars().count();
+    for (i, (byte, char)) in schematic_line.char_indices().enumerate() {
+        if char.is_ascii_digit() {
+            if constructed_number.is_empty() {
+                span.set_start(i);
+            }
+            constructed_number.push(char);
+            span.set_end(i);
+
+            if i == line_length - 1 && !constructed_number.is_empty() {
+                construct_schematic_number(
+                    &mut schematic_numbers,
+                    &mut constructed_number,
+                    &schematic_line,
+                    schematic_line.len(),
+                    line_number,
+                    &span,
+                )?;
+            }
+        } else if !constructed_number.is_empty() {
+            construct_schematic_number(
+                &mut schematic_numbers,
+                &mut constructed_number,
+                &schematic_line,
+                byte,
+                line_number,
+                &span,
+            )?;
+        }
+    }
+
+    Ok(schematic_numbers)
+}
+
+fn construct_schematic_number(
+    schematic_numbers: &mut Vec<Number>,
+    constructed_number: &mut String,
+    schematic_line: &str,
+    digits_end: usize,
+    line_number: usize,
+    span: &Span,
+) -> Result<(), Day3Error> {
+    let digits = &schematic_line[digits_end - constructed_number.len()..digits_end];
+    schematic_numbers.push(Number {
+        value: diagnostic::parse(schematic_line, digits)
+            .map_err(|diagnostic| diagnostic.after_lines(line_number))?,
+        row: line_number,
+        columns: span.start..span.end + 1,
+    });
+    *constructed_number = String::new();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        determine_part_numbers, is_adjacent_to_symbol, load_input, scan_for_numbers_in_line,
+        scan_for_symbols_in_line, scan_schematic, sum_part_numbers,
+    };
+    use aoc_schematic::{Number, Point, Schematic, Symbol};
+
+    #[test]
+    fn find_number_in_line() {
+        let input = "467.......";
+        let result = scan_for_numbers_in_line(input.to_owned(), 1).unwrap();
+        assert_eq!(
+            result,
+            vec![Number {
+                value: 467,
+                row: 1,
+                columns: 0..3
+            }]
+        )
+    }
+
+    #[test]
+    fn find_numbers_in_line() {
+        let input = "467.*114.1";
+        let result = scan_for_numbers_in_line(input.to_owned(), 1).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                Number {
+                    value: 467,
+                    row: 1,
+                    columns: 0..3
+                },
+                Number {
+                    value: 114,
+                    row: 1,
+                    columns: 5..8
+                },
+                Number {
+                    value: 1,
+                    row: 1,
+                    columns: 9..10
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn find_symbol_in_line() {
+        let input = "467...*...";
+        let result = scan_for_symbols_in_line(input.to_owned(), 1);
+        assert_eq!(
+            result,
+            vec![Symbol {
+                symbol: '*',
+                point: Point::new(1, 6)
+            }]
+        )
+    }
+
+    #[test]
+    fn find_symbols_in_line() {
+        let input = "467.3..*.@";
+        let result = scan_for_symbols_in_line(input.to_owned(), 1);
+        assert_eq!(
+            result,
+            vec![
+                Symbol {
+                    symbol: '*',
+                    point: Point::new(1, 7)
+                },
+                Symbol {
+                    symbol: '@',
+                    point: Point::new(1, 9)
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn find_numbers_and_symbols_in_line() {
+        let input = "467...*.@1";
+        let result = scan_schematic(input.to_owned()).unwrap();
+        assert_eq!(
+            result,
+            (
+                vec![
+                    Number {
+                        value: 467,
+                        row: 0,
+                        columns: 0..3
+                    },
+                    Number {
+                        value: 1,
+                        row: 0,
+                        columns: 9..10
+                    }
+                ],
+                vec![
+                    Symbol {
+                        symbol: '*',
+                        point: Point::new(0, 6)
+                    },
+                    Symbol {
+                        symbol: '@',
+                        point: Point::new(0, 8)
+                    }
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn find_numbers_and_symbols_in_lines() {
+        let input = "467..114..\n...*......";
+        let result = scan_schematic(input.to_owned()).unwrap();
+        assert_eq!(
+            result,
+            (
+                vec![
+                    Number {
+                        value: 467,
+                        row: 0,
+                        columns: 0..3
+                    },
+                    Number {
+                        value: 114,
+                        row: 0,
+                        columns: 5..8
+                    }
+                ],
+                vec![Symbol {
+                    symbol: '*',
+                    point: Point::new(1, 3)
+                },]
+            )
+        )
+    }
+
+    #[test]
+    fn find_part_number() {
+        let mut results: Vec<bool> = Vec::new();
+
+        /*
+         * * * * *
+         * 4 6 7 *
+         * * * * *
+         */
+
+        let input_schematic_number = Number {
+            value: 467,
+            row: 1,
+            columns: 1..4,
+        };
+        let input_schematic_symbols = vec![
+            Symbol {
+                symbol: '*',
+                point: Point::new(0, 0),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(0, 1),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(0, 2),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(0, 3),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(0, 4),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(1, 0),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(1, 4),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(2, 0),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(2, 1),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(2, 2),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(2, 3),
+            },
+            Symbol {
+                symbol: '*',
+                point: Point::new(2, 4),
+            },
+        ];
+
+        for symbol in input_schematic_symbols {
+            let schematic = Schematic::new(vec![input_schematic_number.clone()], vec![symbol]);
+            results.push(is_adjacent_to_symbol(&schematic, 0))
+        }
+        assert_eq!(
+            results,
+            vec![true, true, true, true, true, true, true, true, true, true, true, true,]
+        )
+    }
+
+    #[test]
+    fn throw_out_schematic_number() {
+        let mut results: Vec<bool> = Vec::new();
+
+        /*
+        . . . . .
+        . 4 6 7 .
+        . . . . .
+        */
+
+        let input_schematic_number = Number {
+            value: 467,
+            row: 1,
+            columns: 1..4,
+        };
+        let schematic = Schematic::new(vec![input_schematic_number], vec![]);
+        results.push(is_adjacent_to_symbol(&schematic, 0));
+        assert_eq!(results, vec![false])
+    }
+
+    #[test]
+    fn find_part_numbers_in_line() {
+        let input = "467..114*..\n...*......";
+        let (schematic_numbers, schematic_symbols) = scan_schematic(input.to_owned()).unwrap();
+        let schematic = Schematic::new(schematic_numbers, schematic_symbols);
+        let result = determine_part_numbers(&schematic);
+        assert_eq!(result, vec![467, 114])
+    }
+
+    #[test]
+    fn sum_part_numbers_in_full_schematic() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let (schematic_numbers, schematic_symbols) = scan_schematic(input.to_owned()).unwrap();
+        let schematic = Schematic::new(schematic_numbers, schematic_symbols);
+        let part_numbers = determine_part_numbers(&schematic);
+        let result = sum_part_numbers(part_numbers);
+        assert_eq!(result, 4361)
+    }
+}
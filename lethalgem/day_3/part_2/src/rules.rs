@@ -0,0 +1,279 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use aoc_core::{diagnostic, read_input, Diagnostic};
+use aoc_schematic::{Point, Schematic};
+
+use crate::Day3Error;
+
+/// How the numbers next to a symbol are combined into its result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    /// The product of the numbers, only when there are exactly this many.
+    ProductOf(usize),
+    Sum,
+    Max,
+}
+
+impl Aggregation {
+    // None when the rule doesn't apply to these neighbors
+    fn apply(self, symbol: char, neighbors: &[u64]) -> Result<Option<u64>, Day3Error> {
+        match self {
+            Aggregation::ProductOf(count) if neighbors.len() == count => neighbors
+                .iter()
+                .try_fold(1u64, |product, &number| product.checked_mul(number))
+                .map(Some)
+                .ok_or(Day3Error::RuleOverflow(symbol)),
+            Aggregation::ProductOf(_) => Ok(None),
+            Aggregation::Sum if neighbors.is_empty() => Ok(None),
+            Aggregation::Sum => Ok(Some(neighbors.iter().sum())),
+            Aggregation::Max => Ok(neighbors.iter().max().copied()),
+        }
+    }
+}
+
+impl fmt::Display for Aggregation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Aggregation::ProductOf(count) => write!(f, "product {}", count),
+            Aggregation::Sum => write!(f, "sum"),
+            Aggregation::Max => write!(f, "max"),
+        }
+    }
+}
+
+/// Which symbols mean something, and what. Symbols without a rule are
+/// left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleTable {
+    rules: BTreeMap<char, Aggregation>,
+}
+
+impl RuleTable {
+    pub fn new() -> RuleTable {
+        RuleTable::default()
+    }
+
+    /// The puzzle's rule: a `*` next to exactly two numbers is a gear.
+    pub fn gears() -> RuleTable {
+        RuleTable::new().with('*', Aggregation::ProductOf(2))
+    }
+
+    pub fn with(mut self, symbol: char, aggregation: Aggregation) -> RuleTable {
+        self.rules.insert(symbol, aggregation);
+        self
+    }
+
+    /// Reads one `symbol = aggregation` per line, where the aggregation is
+    /// `product <count>`, `sum` or `max`, such as `* = product 2`. Blank
+    /// lines are skipped.
+    pub fn parse(input: &str) -> Result<RuleTable, Diagnostic> {
+        let mut rule_table = RuleTable::new();
+        for line in input.lines() {
+            let entry = line.trim();
+            if entry.is_empty() {
+                continue;
+            }
+
+            let Some((symbol, aggregation)) = entry.split_once('=') else {
+                return Err(Diagnostic::new(
+                    input,
+                    entry,
+                    "expected `symbol = aggregation`",
+                ));
+            };
+            let (symbol, aggregation) = (symbol.trim(), aggregation.trim());
+            let mut chars = symbol.chars();
+            let (Some(symbol_char), None) = (chars.next(), chars.next()) else {
+                return Err(Diagnostic::new(
+                    input,
+                    entry,
+                    "expected one symbol before `=`",
+                ));
+            };
+            if symbol_char.is_ascii_digit() || symbol_char == '.' {
+                let message = format!("`{}` is not a symbol", symbol_char);
+                return Err(Diagnostic::new(input, symbol, message));
+            }
+            if let Some(previous) = rule_table.rules.get(&symbol_char) {
+                let message = format!("`{}` already has the rule `{}`", symbol_char, previous);
+                return Err(Diagnostic::new(input, symbol, message));
+            }
+
+            let aggregation = match aggregation.split_once(' ') {
+                None if aggregation == "sum" => Aggregation::Sum,
+                None if aggregation == "max" => Aggregation::Max,
+                Some(("product", count)) => {
+                    let count = count.trim();
+                    match diagnostic::parse(input, count)? {
+                        0 => return Err(Diagnostic::new(input, count, "expected at least 1")),
+                        count => Aggregation::ProductOf(count),
+                    }
+                }
+                _ => {
+                    let message = "expected `product <count>`, `sum` or `max`";
+                    return Err(Diagnostic::new(input, aggregation, message));
+                }
+            };
+            rule_table.rules.insert(symbol_char, aggregation);
+        }
+        Ok(rule_table)
+    }
+
+    pub fn load(file_path: impl AsRef<Path>) -> Result<RuleTable, Day3Error> {
+        let file_path = file_path.as_ref();
+        let input = read_input(file_path)?;
+        Ok(RuleTable::parse(&input).map_err(|diagnostic| diagnostic.in_file(file_path))?)
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = (char, Aggregation)> + '_ {
+        self.rules
+            .iter()
+            .map(|(&symbol, &aggregation)| (symbol, aggregation))
+    }
+
+    /// Applies every rule to every symbol it covers.
+    pub fn evaluate(&self, schematic: &Schematic) -> Result<Report, Day3Error> {
+        let mut report = Report {
+            results: Vec::new(),
+            totals: self.rules.keys().map(|&symbol| (symbol, 0)).collect(),
+            total: 0,
+        };
+
+        for (id, symbol) in schematic.symbols().iter().enumerate() {
+            let Some(aggregation) = self.rules.get(&symbol.symbol) else {
+                continue;
+            };
+            let neighbors: Vec<u64> = schematic
+                .numbers_next_to(id)
+                .map(|number| u64::from(schematic.numbers()[number].value))
+                .collect();
+            let Some(value) = aggregation.apply(symbol.symbol, &neighbors)? else {
+                continue;
+            };
+
+            let overflow = || Day3Error::RuleOverflow(symbol.symbol);
+            let symbol_total = report.totals.entry(symbol.symbol).or_default();
+            *symbol_total = symbol_total.checked_add(value).ok_or_else(overflow)?;
+            report.total = report.total.checked_add(value).ok_or_else(overflow)?;
+            report.results.push(SymbolResult {
+                symbol: symbol.symbol,
+                point: symbol.point,
+                value,
+            });
+        }
+
+        Ok(report)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolResult {
+    pub symbol: char,
+    pub point: Point,
+    pub value: u64,
+}
+
+/// What each rule gave for each symbol it applied to, in schematic order,
+/// with the total for each rule and for all of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub results: Vec<SymbolResult>,
+    pub totals: BTreeMap<char, u64>,
+    pub total: u64,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in &self.results {
+            writeln!(
+                f,
+                "{} at line {}, column {}: {}",
+                result.symbol,
+                result.point.row + 1,
+                result.point.col + 1,
+                result.value
+            )?;
+        }
+        for (symbol, total) in &self.totals {
+            writeln!(f, "total for {}: {}", symbol, total)?;
+        }
+        write!(f, "total: {}", self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{load_input, read_schematic};
+
+    fn example() -> Schematic {
+        read_schematic(load_input("src/example_1.txt").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn gears() {
+        let report = RuleTable::gears().evaluate(&example()).unwrap();
+        let values: Vec<u64> = report.results.iter().map(|result| result.value).collect();
+        assert_eq!(values, vec![16345, 451490]);
+        assert_eq!(report.totals, BTreeMap::from([('*', 467835)]));
+        assert_eq!(report.total, 467835)
+    }
+
+    #[test]
+    fn other_aggregations() {
+        let rule_table = RuleTable::new()
+            .with('*', Aggregation::Max)
+            .with('$', Aggregation::Sum)
+            .with('&', Aggregation::Sum)
+            .with('#', Aggregation::ProductOf(1));
+        let report = rule_table.evaluate(&example()).unwrap();
+        assert_eq!(
+            report.totals,
+            BTreeMap::from([('#', 633), ('$', 664), ('&', 0), ('*', 467 + 617 + 755)])
+        );
+        assert_eq!(report.total, 633 + 664 + 467 + 617 + 755);
+        assert_eq!(
+            report.results[0],
+            SymbolResult {
+                symbol: '*',
+                point: Point::new(1, 3),
+                value: 467
+            }
+        );
+    }
+
+    #[test]
+    fn parse_rules() {
+        let rule_table = RuleTable::parse("* = product 2\n\n  # = sum\n+=max\n").unwrap();
+        assert_eq!(
+            rule_table.rules().collect::<Vec<_>>(),
+            vec![
+                ('#', Aggregation::Sum),
+                ('*', Aggregation::ProductOf(2)),
+                ('+', Aggregation::Max)
+            ]
+        );
+
+        let err = RuleTable::parse("* = product 2\n# = mean\n").unwrap_err();
+        assert_eq!(err.message, "expected `product <count>`, `sum` or `max`");
+        assert_eq!((err.line, err.column), (2, 5));
+        let err = RuleTable::parse("* = sum\n* = max\n").unwrap_err();
+        assert_eq!(err.message, "`*` already has the rule `sum`");
+        let err = RuleTable::parse("7 = sum\n").unwrap_err();
+        assert_eq!(err.message, "`7` is not a symbol");
+        let err = RuleTable::parse("* = product 0\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 13));
+    }
+
+    #[test]
+    fn report_overflow() {
+        let schematic = read_schematic("4000000000*4000000000\n4000000000".to_owned()).unwrap();
+        let rule_table = RuleTable::new().with('*', Aggregation::ProductOf(3));
+        assert!(matches!(
+            rule_table.evaluate(&schematic),
+            Err(Day3Error::RuleOverflow('*'))
+        ));
+    }
+}
@@ -0,0 +1,208 @@
+use aoc_core::{read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{delimited, numbers, pair, preceded, space, tag, uint, Parser, Span};
+use std::path::Path;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day4Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("Points of card {0} are too large")]
+    PointsOverflow(usize),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Card {
+    winning_numbers: Vec<i32>,
+    scratched_numbers: Vec<i32>,
+    // None when the card wins too many points to count
+    points: Option<i32>,
+}
+
+impl Card {
+    fn new() -> Card {
+        Card {
+            winning_numbers: Vec::new(),
+            scratched_numbers: Vec::new(),
+            points: Some(0),
+        }
+    }
+
+    fn set_winning_numbers(&mut self, numbers: Vec<i32>) {
+        self.winning_numbers = numbers;
+        self.points = self.calculate_points()
+    }
+
+    fn set_scratched_numbers(&mut self, numbers: Vec<i32>) {
+        self.scratched_numbers = numbers;
+        self.points = self.calculate_points()
+    }
+
+    fn calculate_points(&mut self) -> Option<i32> {
+        if self.winning_numbers.is_empty() || self.scratched_numbers.is_empty() {
+            Some(0)
+        } else {
+            let matching_numbers: Vec<i32> = self
+                .scratched_numbers
+                .clone()
+                .into_iter()
+                .filter(|num| self.winning_numbers.contains(num))
+                .collect();
+
+            match matching_numbers.len() {
+                0 => Some(0),
+                len => 2_i32.checked_pow((len - 1).try_into().ok()?),
+            }
+        }
+    }
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day4Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let extracted_cards = info_span!("parse").in_scope(|| extract_cards(input_data))?;
+    let answer = info_span!("solve").in_scope(|| sum_cards(extracted_cards))?;
+    println!("{}", answer);
+    Ok(())
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<Card>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_cards(input.to_owned())?)
+    }
+
+    fn part_one(&self, cards: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(sum_cards(cards)?.into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day4Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day4Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn sum_cards(cards: Vec<Card>) -> Result<i32, Day4Error> {
+    let mut sum: i32 = 0;
+    for (i, card) in cards.iter().enumerate() {
+        sum = card
+            .points
+            .and_then(|points| sum.checked_add(points))
+            .ok_or(Day4Error::PointsOverflow(i + 1))?;
+    }
+    Ok(sum)
+}
+
+fn extract_cards(input: String) -> Result<Vec<Card>, Day4Error> {
+    Ok(Span::new(&input).parse_lines(card())?)
+}
+
+#[cfg(test)]
+fn extract_card(line: String) -> Result<Card, Diagnostic> {
+    card().parse_all(Span::new(&line))
+}
+
+fn card<'a>() -> impl Parser<'a, Card> {
+    let card_number = delimited(pair(tag("Card"), space()), uint::<i32>(), tag(":"));
+    let winning_numbers = preceded(card_number, numbers());
+    let scratched_numbers = preceded(tag("|"), numbers());
+
+    pair(winning_numbers, scratched_numbers).map(|(winning_numbers, scratched_numbers)| {
+        let mut card = Card::new();
+        card.set_winning_numbers(winning_numbers);
+        card.set_scratched_numbers(scratched_numbers);
+        card
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{extract_card, extract_cards, load_input, sum_cards, Card, Day4Error};
+
+    #[test]
+    fn calculate_proper_points() {
+        let mut card = Card::new();
+        card.set_winning_numbers(vec![41, 48, 83, 86, 17]);
+        card.set_scratched_numbers(vec![83, 86, 6, 31, 17, 9, 48, 53]);
+        assert_eq!(card.points, Some(8))
+    }
+
+    #[test]
+    fn calculate_proper_solo_points() {
+        let mut card = Card::new();
+        card.set_winning_numbers(vec![41, 92, 73, 84, 69]);
+        card.set_scratched_numbers(vec![59, 84, 76, 51, 58, 5, 54, 83]);
+        assert_eq!(card.points, Some(1))
+    }
+
+    #[test]
+    fn create_card() {
+        let input = "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83";
+        let result = extract_card(input.to_owned()).unwrap();
+        let mut test_card = Card::new();
+        test_card.set_winning_numbers(vec![41, 92, 73, 84, 69]);
+        test_card.set_scratched_numbers(vec![59, 84, 76, 51, 58, 5, 54, 83]);
+        assert_eq!(result, test_card)
+    }
+
+    #[test]
+    fn create_cards() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let result = extract_cards(input).unwrap();
+        let mut test_card = Card::new();
+        test_card.set_winning_numbers(vec![41, 48, 83, 86, 17]);
+        test_card.set_scratched_numbers(vec![83, 86, 6, 31, 17, 9, 48, 53]);
+        let mut test_card_2 = Card::new();
+        test_card_2.set_winning_numbers(vec![13, 32, 20, 16, 61]);
+        test_card_2.set_scratched_numbers(vec![61, 30, 68, 82, 17, 32, 24, 19]);
+        let mut test_card_3 = Card::new();
+        test_card_3.set_winning_numbers(vec![1, 21, 53, 59, 44]);
+        test_card_3.set_scratched_numbers(vec![69, 82, 63, 72, 16, 21, 14, 1]);
+        let mut test_card_4 = Card::new();
+        test_card_4.set_winning_numbers(vec![41, 92, 73, 84, 69]);
+        test_card_4.set_scratched_numbers(vec![59, 84, 76, 51, 58, 5, 54, 83]);
+        let mut test_card_5 = Card::new();
+        test_card_5.set_winning_numbers(vec![87, 83, 26, 28, 32]);
+        test_card_5.set_scratched_numbers(vec![88, 30, 70, 12, 93, 22, 82, 36]);
+        let mut test_card_6 = Card::new();
+        test_card_6.set_winning_numbers(vec![31, 18, 13, 56, 72]);
+        test_card_6.set_scratched_numbers(vec![74, 77, 10, 23, 35, 67, 36, 11]);
+        assert_eq!(
+            result,
+            vec![
+                test_card,
+                test_card_2,
+                test_card_3,
+                test_card_4,
+                test_card_5,
+                test_card_6
+            ]
+        )
+    }
+
+    #[test]
+    fn sum_card_set() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let cards = extract_cards(input).unwrap();
+        let result = sum_cards(cards).unwrap();
+        assert_eq!(result, 13)
+    }
+
+    #[test]
+    fn reject_points_too_large() {
+        let numbers: Vec<String> = (1..=32).map(|num| num.to_string()).collect();
+        let numbers = numbers.join(" ");
+        let input = format!("Card 1: 1 | 1\nCard 2: {numbers} | {numbers}");
+        let cards = extract_cards(input).unwrap();
+        assert!(matches!(
+            sum_cards(cards),
+            Err(Day4Error::PointsOverflow(2))
+        ))
+    }
+}
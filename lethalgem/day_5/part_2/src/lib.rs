@@ -0,0 +1,279 @@
+use aoc_core::{read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{numbers, preceded, separated, tag, terminated, word, Parser, Span};
+use cond_utils::Between;
+use std::{ops::RangeInclusive, path::Path};
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day5Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    seed_list: Vec<SeedRange>,
+    maps: Vec<Map>,
+}
+
+#[derive(Debug, Clone)]
+struct SeedRange {
+    range: RangeInclusive<u64>,
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    bounds: Vec<MapBounds>,
+}
+
+#[derive(Debug, Clone)]
+struct MapBounds {
+    source_range: RangeInclusive<i64>,
+    destination_range: RangeInclusive<i64>,
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day5Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let almanac = info_span!("parse").in_scope(|| create_almanac(input_data))?;
+    let lowest_location = info_span!("solve").in_scope(|| find_lowest_location(almanac));
+    println!("{}", lowest_location);
+    Ok(())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = Almanac;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(create_almanac(input.to_owned())?)
+    }
+
+    fn part_two(&self, almanac: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(find_lowest_location(almanac).into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day5Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day5Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn find_lowest_location(almanac: Almanac) -> u64 {
+    let mut lowest_location: u64 = 0;
+    let mut first_iteration = true;
+    for seed_range in almanac.clone().seed_list {
+        for seed in seed_range.range {
+            let location = traverse_almanac_for_location(seed, almanac.clone());
+            if first_iteration {
+                lowest_location = location;
+                first_iteration = false;
+            } else if location < lowest_location {
+                lowest_location = location;
+            }
+        }
+    }
+    lowest_location
+}
+
+fn traverse_almanac_for_location(seed: u64, almanac: Almanac) -> u64 {
+    let mut source = seed;
+
+    for map in almanac.maps {
+        for map_bounds in map.bounds {
+            if source.within(
+                *map_bounds.source_range.start() as u64,
+                *map_bounds.source_range.end() as u64,
+            ) {
+                let source_index_in_range = source - *map_bounds.source_range.start() as u64;
+                let destination =
+                    *map_bounds.destination_range.start() as u64 + source_index_in_range;
+                source = destination;
+                break;
+            }
+        }
+    }
+
+    source
+}
+
+fn create_almanac(input: String) -> Result<Almanac, Day5Error> {
+    let input = Span::new(&input);
+    let mut sections = input.sections();
+    let seed_line = sections
+        .next()
+        .ok_or_else(|| input.error_at_end("expected a line of seeds"))?;
+    let almanac = Almanac {
+        seed_list: parse_seeds_list(seed_line)?,
+        maps: create_map_list(sections)?,
+    };
+    Ok(almanac)
+}
+
+fn parse_seeds_list(seed_line: Span) -> Result<Vec<SeedRange>, Day5Error> {
+    let seed_bounds_list: Vec<u64> = preceded(tag("seeds:"), numbers()).parse_all(seed_line)?;
+
+    debug!(?seed_bounds_list);
+
+    if !seed_bounds_list.len().is_multiple_of(2) {
+        return Err(seed_line
+            .error_at_end("expected a range length after the last seed")
+            .into());
+    }
+
+    let mut seed_list: Vec<SeedRange> = Vec::new();
+    let mut seed_lower_bound: u64 = 0;
+    for (i, seed_bound) in seed_bounds_list.iter().enumerate() {
+        match i % 2 {
+            1 => {
+                let Some(seed_upper_bound) = seed_lower_bound.checked_add(*seed_bound) else {
+                    return Err(seed_line.error("seed range is too large").into());
+                };
+                if seed_upper_bound > seed_lower_bound {
+                    seed_list.push(SeedRange {
+                        range: RangeInclusive::new(seed_lower_bound, seed_upper_bound - 1),
+                    });
+                }
+            }
+            _ => {
+                seed_lower_bound = *seed_bound;
+            }
+        }
+    }
+    Ok(seed_list)
+}
+
+fn create_map_list<'a>(
+    map_sections: impl Iterator<Item = Span<'a>>,
+) -> Result<Vec<Map>, Diagnostic> {
+    let map_header = terminated(separated(word(), tag("-")), tag(" map:"));
+
+    let mut map_list: Vec<Map> = Vec::new();
+    for map_section in map_sections {
+        let (header, map_input) = map_section
+            .split_once('\n')
+            .ok_or_else(|| map_section.error_at_end("expected ranges after the map's name"))?;
+        map_header.parse_all(header)?;
+        map_list.push(Map {
+            bounds: create_map_bounds(map_input)?,
+        });
+    }
+
+    Ok(map_list)
+}
+
+fn create_map_bounds(map_input: Span) -> Result<Vec<MapBounds>, Diagnostic> {
+    map_input.parse_lines(map_bounds())
+}
+
+fn map_bounds<'a>() -> impl Parser<'a, MapBounds> {
+    numbers::<i64>().try_map(|numbers| {
+        let [destination_range_start, source_range_start, range_length] = numbers[..] else {
+            return Err("expected a destination start, source start and range length".to_owned());
+        };
+        if destination_range_start < 0 || source_range_start < 0 || range_length < 1 {
+            return Err("expected non-negative starts and a positive range length".to_owned());
+        }
+        let (Some(source_range_end), Some(destination_range_end)) = (
+            source_range_start.checked_add(range_length - 1),
+            destination_range_start.checked_add(range_length - 1),
+        ) else {
+            return Err("range is too large".to_owned());
+        };
+        Ok(MapBounds {
+            source_range: RangeInclusive::new(source_range_start, source_range_end),
+            destination_range: RangeInclusive::new(destination_range_start, destination_range_end),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_parse::Span;
+
+    use crate::{
+        create_almanac, create_map_bounds, create_map_list, find_lowest_location, load_input,
+        parse_seeds_list, traverse_almanac_for_location,
+    };
+
+    fn check(actual: &str, expect: expect_test::Expect) {
+        expect.assert_eq(actual);
+    }
+
+    #[test]
+    fn parse_correct_seeds_list() {
+        let input = "seeds: 79 14 55 13";
+        let result = parse_seeds_list(Span::new(input)).unwrap();
+        check(
+            &format!("{:?}", result),
+            expect_test::expect!["[SeedRange { range: 79..=92 }, SeedRange { range: 55..=67 }]"],
+        );
+    }
+
+    #[test]
+    fn create_correct_map_bounds() {
+        let input = "50 98 2\n52 50 48\n";
+        let result = create_map_bounds(Span::new(input)).unwrap();
+        check(&format!("{:?}", result), expect_test::expect![
+            "[MapBounds { source_range: 98..=99, destination_range: 50..=51 }, MapBounds { source_range: 50..=97, destination_range: 52..=99 }]"]);
+    }
+
+    #[test]
+    fn parse_correct_maps_list() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let result = create_map_list(Span::new(&input).sections().skip(1)).unwrap();
+        check(&format!("{:?}", result.len()), expect_test::expect!["7"]);
+    }
+
+    #[test]
+    fn create_correct_almanac() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let result = create_almanac(input.to_owned()).unwrap();
+        check(
+            &format!("{:?}", result.seed_list),
+            expect_test::expect!["[SeedRange { range: 79..=92 }, SeedRange { range: 55..=67 }]"],
+        );
+        check(
+            &format!("{:?}", result.maps.len()),
+            expect_test::expect!["7"],
+        );
+    }
+
+    #[test]
+    fn find_correct_locations() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let almanac = create_almanac(input.to_owned()).unwrap();
+        check(
+            &format!("{:?}", traverse_almanac_for_location(79, almanac.clone())),
+            expect_test::expect!["82"],
+        );
+        check(
+            &format!("{:?}", traverse_almanac_for_location(14, almanac.clone())),
+            expect_test::expect!["43"],
+        );
+        check(
+            &format!("{:?}", traverse_almanac_for_location(55, almanac.clone())),
+            expect_test::expect!["86"],
+        );
+        check(
+            &format!("{:?}", traverse_almanac_for_location(13, almanac.clone())),
+            expect_test::expect!["35"],
+        );
+        check(
+            &format!("{:?}", traverse_almanac_for_location(82, almanac.clone())),
+            expect_test::expect!["46"],
+        );
+    }
+
+    #[test]
+    fn find_correct_lowest_location() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let almanac = create_almanac(input.to_owned()).unwrap();
+        let result = find_lowest_location(almanac);
+        check(&format!("{:?}", result), expect_test::expect!["46"]);
+    }
+}
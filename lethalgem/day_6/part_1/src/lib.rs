@@ -0,0 +1,137 @@
+use aoc_core::{read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{numbers, preceded, tag, Parser, Span};
+use std::path::Path;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day6Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("Margin of error is too large to count")]
+    MarginOverflow,
+}
+
+#[derive(Debug, Clone)]
+pub struct Race {
+    #[allow(dead_code)]
+    total_time: i32,
+    #[allow(dead_code)]
+    distance_to_beat: i32,
+    ways_to_win: i32,
+}
+
+impl Race {
+    fn new(total_time: i32, distance_to_beat: i32) -> Race {
+        Race {
+            total_time,
+            distance_to_beat,
+            ways_to_win: Self::calculate_ways_to_win(total_time, distance_to_beat),
+        }
+    }
+
+    fn calculate_ways_to_win(total_time: i32, distance_to_beat: i32) -> i32 {
+        let mut ways_to_win = 0;
+
+        for button_held_time in 0..=total_time {
+            let speed_per_time_step = button_held_time;
+            let time_steps_left_to_move = total_time - button_held_time;
+            let distance_traveled =
+                i64::from(speed_per_time_step) * i64::from(time_steps_left_to_move);
+            if distance_traveled > i64::from(distance_to_beat) {
+                ways_to_win += 1;
+            }
+        }
+
+        ways_to_win
+    }
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day6Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let races = info_span!("parse").in_scope(|| extract_races(input_data))?;
+    let margin_of_error = info_span!("solve").in_scope(|| calculate_margin_of_error(races))?;
+    println!("{}", margin_of_error);
+    Ok(())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Vec<Race>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_races(input.to_owned())?)
+    }
+
+    fn part_one(&self, races: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(calculate_margin_of_error(races)?.into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day6Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day6Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn calculate_margin_of_error(races: Vec<Race>) -> Result<i32, Day6Error> {
+    races
+        .iter()
+        .try_fold(1i32, |margin, race| margin.checked_mul(race.ways_to_win))
+        .ok_or(Day6Error::MarginOverflow)
+}
+
+fn extract_races(input: String) -> Result<Vec<Race>, Day6Error> {
+    let mut races: Vec<Race> = Vec::new();
+    let input = Span::new(&input);
+    let mut lines = input.lines();
+    let race_line = lines
+        .next()
+        .ok_or_else(|| input.error_at_end("expected a `Time:` line"))?;
+    let race_times = preceded(tag("Time:"), numbers::<i32>()).parse_all(race_line)?;
+
+    let distance_line = lines
+        .next()
+        .ok_or_else(|| input.error_at_end("expected a `Distance:` line"))?;
+    let distances = preceded(tag("Distance:"), numbers::<i32>()).parse_all(distance_line)?;
+
+    debug!(?race_times, ?distances);
+
+    if race_times.len() == distances.len() {
+        for (i, time) in race_times.iter().enumerate() {
+            races.push(Race::new(*time, distances[i]))
+        }
+    } else {
+        return Err(distance_line
+            .error(format!(
+                "expected {} distances, one for each race time, found {}",
+                race_times.len(),
+                distances.len()
+            ))
+            .into());
+    }
+
+    Ok(races)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{extract_races, load_input};
+
+    fn check(actual: &str, expect: expect_test::Expect) {
+        expect.assert_eq(actual);
+    }
+
+    #[test]
+    fn parse_correct_races() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let result = extract_races(input.to_owned()).unwrap();
+        check(
+            &format!("{:?}", result),
+            expect_test::expect!["[Race { total_time: 7, distance_to_beat: 9, ways_to_win: 4 }, Race { total_time: 15, distance_to_beat: 40, ways_to_win: 8 }, Race { total_time: 30, distance_to_beat: 200, ways_to_win: 9 }]"],
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use aoc_core::{read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{many, preceded, space, tag, uint, Parser, Span};
+use std::path::Path;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day6Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+#[derive(Debug, Clone)]
+pub struct Race {
+    ways_to_win: u64,
+}
+
+impl Race {
+    fn new(total_time: u64, distance_to_beat: u64) -> Race {
+        Race {
+            ways_to_win: Self::calculate_ways_to_win(total_time, distance_to_beat),
+        }
+    }
+
+    fn calculate_ways_to_win(total_time: u64, distance_to_beat: u64) -> u64 {
+        let mut ways_to_win = 0;
+
+        for button_held_time in 0..=total_time {
+            let speed_per_time_step = button_held_time;
+            let time_steps_left_to_move = total_time - button_held_time;
+            let distance_traveled =
+                u128::from(speed_per_time_step) * u128::from(time_steps_left_to_move);
+            if distance_traveled > u128::from(distance_to_beat) {
+                ways_to_win += 1;
+            }
+        }
+
+        ways_to_win
+    }
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day6Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let race = info_span!("parse").in_scope(|| extract_race(input_data))?;
+    println!("{}", race.ways_to_win);
+    Ok(())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Race;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_race(input.to_owned())?)
+    }
+
+    fn part_two(&self, race: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(race.ways_to_win.into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day6Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day6Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn extract_race(input: String) -> Result<Race, Day6Error> {
+    let input = Span::new(&input);
+    let mut lines = input.lines();
+    let race_line = lines
+        .next()
+        .ok_or_else(|| input.error_at_end("expected a `Time:` line"))?;
+    let race_time = kerned_number("Time:").parse_all(race_line)?;
+
+    let distance_line = lines
+        .next()
+        .ok_or_else(|| input.error_at_end("expected a `Distance:` line"))?;
+    let race_distance = kerned_number("Distance:").parse_all(distance_line)?;
+
+    debug!(race_time, race_distance);
+
+    let race = Race::new(race_time, race_distance);
+    Ok(race)
+}
+
+/// The digits after `label` read as one number, ignoring the spaces between them.
+fn kerned_number<'a>(label: &'static str) -> impl Parser<'a, u64> {
+    preceded(tag(label), many(preceded(space(), uint::<String>()))).try_map(|digits| {
+        let number = digits.concat();
+        number
+            .parse::<u64>()
+            .map_err(|err| format!("unable to parse `{}`: {}", number, err))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{extract_race, load_input};
+
+    fn check(actual: &str, expect: expect_test::Expect) {
+        expect.assert_eq(actual);
+    }
+
+    #[test]
+    fn parse_correct_race() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let result = extract_race(input.to_owned()).unwrap();
+        check(
+            &format!("{:?}", result),
+            expect_test::expect!["Race { ways_to_win: 71503 }"],
+        );
+    }
+}
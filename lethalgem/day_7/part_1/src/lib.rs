@@ -0,0 +1,372 @@
+use aoc_core::{diagnostic, read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use std::{cmp::Reverse, collections::HashMap, path::Path};
+use strum_macros::EnumIter;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day7Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("Failed to parse card from character {0}")]
+    CouldNotConstructCard(char),
+    #[error("Failed to sort in time")]
+    FailedToSort,
+    #[error("Failed to find hand with index")]
+    FailedToFindHand,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hand {
+    cards: Vec<Card>,
+    type_: HandType,
+    bid: i32,
+}
+
+impl Hand {
+    fn new(cards: Vec<Card>, bid: i32) -> Hand {
+        Hand {
+            cards: cards.clone(),
+            type_: Self::find_type(cards),
+            bid,
+        }
+    }
+
+    fn find_type(cards: Vec<Card>) -> HandType {
+        let mut card_count: HashMap<Card, i32> = HashMap::new();
+        for card in cards {
+            match card_count.clone().get(&card) {
+                Some(count) => {
+                    card_count.insert(card.clone(), count + 1);
+                }
+                None => {
+                    card_count.insert(card.clone(), 1);
+                }
+            };
+        }
+
+        if Self::find_count_of_counts(5, &card_count) == 1 {
+            HandType::FiveOfAKind
+        } else if Self::find_count_of_counts(4, &card_count) == 1 {
+            HandType::FourOfAKind
+        } else if Self::find_count_of_counts(2, &card_count) == 1
+            && Self::find_count_of_counts(3, &card_count) == 1
+        {
+            HandType::FullHouse
+        } else if Self::find_count_of_counts(3, &card_count) == 1 {
+            HandType::ThreeOfAKind
+        } else if Self::find_count_of_counts(2, &card_count) == 2 {
+            HandType::TwoPair
+        } else if Self::find_count_of_counts(2, &card_count) == 1 {
+            HandType::OnePair
+        } else {
+            HandType::HighCard
+        }
+    }
+
+    fn find_count_of_counts(desired_count: i32, card_count: &HashMap<Card, i32>) -> i32 {
+        card_count
+            .iter()
+            .filter(|(_, count)| count == &&desired_count)
+            .count() as i32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    fn new(char: char) -> Result<Card, Day7Error> {
+        match char {
+            '2' => Ok(Card::Two),
+            '3' => Ok(Card::Three),
+            '4' => Ok(Card::Four),
+            '5' => Ok(Card::Five),
+            '6' => Ok(Card::Six),
+            '7' => Ok(Card::Seven),
+            '8' => Ok(Card::Eight),
+            '9' => Ok(Card::Nine),
+            'T' => Ok(Card::Ten),
+            'J' => Ok(Card::Jack),
+            'Q' => Ok(Card::Queen),
+            'K' => Ok(Card::King),
+            'A' => Ok(Card::Ace),
+            _ => Err(Day7Error::CouldNotConstructCard(char)),
+        }
+    }
+
+    fn strength(&self) -> i32 {
+        match *self {
+            Card::Two => 1,
+            Card::Three => 2,
+            Card::Four => 3,
+            Card::Five => 4,
+            Card::Six => 5,
+            Card::Seven => 6,
+            Card::Eight => 7,
+            Card::Nine => 8,
+            Card::Ten => 9,
+            Card::Jack => 10,
+            Card::Queen => 11,
+            Card::King => 12,
+            Card::Ace => 13,
+        }
+    }
+}
+
+#[derive(Debug, Clone, EnumIter, PartialEq)]
+enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl HandType {
+    fn strength(&self) -> i32 {
+        match *self {
+            HandType::FiveOfAKind => 7,  // AAAAA
+            HandType::FourOfAKind => 6,  // AA8AA
+            HandType::FullHouse => 5,    // 23332
+            HandType::ThreeOfAKind => 4, // TTT98
+            HandType::TwoPair => 3,      // 23432
+            HandType::OnePair => 2,      // A23A4
+            HandType::HighCard => 1,     // 23456
+        }
+    }
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day7Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let mut hands = info_span!("parse").in_scope(|| extract_hands(input_data))?;
+    let total_winnings = info_span!("solve").in_scope(|| {
+        rank_hands(&mut hands);
+        calc_total_winnings(hands)
+    });
+    println!("{}", total_winnings);
+    Ok(())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Vec<Hand>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_hands(input.to_owned())?)
+    }
+
+    fn part_one(&self, mut hands: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok({
+            rank_hands(&mut hands);
+            calc_total_winnings(hands)
+        }
+        .into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day7Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day7Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn extract_hands(input: String) -> Result<Vec<Hand>, Day7Error> {
+    let mut hands: Vec<Hand> = Vec::new();
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        let (cards_c, bid_c) = line
+            .split_once(' ')
+            .ok_or_else(|| Diagnostic::new(&input, line, "expected a hand and a bid"))?;
+        let mut cards: Vec<Card> = Vec::new();
+        for (i, card) in cards_c.char_indices() {
+            cards.push(Card::new(card).map_err(|_| {
+                Diagnostic::new(
+                    &input,
+                    &cards_c[i..i + card.len_utf8()],
+                    format!("expected one of 23456789TJQKA, found `{}`", card),
+                )
+            })?)
+        }
+        hands.push(Hand::new(cards, diagnostic::parse::<i32>(&input, bid_c)?))
+    }
+    Ok(hands)
+}
+
+fn rank_hands(hands: &mut [Hand]) {
+    hands.sort_by_key(|hand| {
+        Reverse((
+            hand.type_.strength(),
+            hand.cards
+                .iter()
+                .map(|card| card.strength())
+                .collect::<Vec<i32>>(),
+        ))
+    });
+}
+
+fn calc_total_winnings(hands: Vec<Hand>) -> i32 {
+    let mut total = 0;
+    for (i, hand) in hands.iter().enumerate() {
+        total += (hands.len() - i) as i32 * hand.bid;
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{calc_total_winnings, extract_hands, load_input, rank_hands, Card, Hand, HandType};
+
+    fn check(actual: &str, expect: expect_test::Expect) {
+        expect.assert_eq(actual);
+    }
+
+    #[test]
+    fn find_pairs() {
+        let mut input: HashMap<Card, i32> = HashMap::new();
+        input.insert(Card::new('J').unwrap(), 2);
+        input.insert(Card::new('2').unwrap(), 1);
+        let result = Hand::find_count_of_counts(2, &input);
+        check(&format!("{:?}", result), expect_test::expect!["1"]);
+
+        input.insert(Card::new('Q').unwrap(), 2);
+        let result = Hand::find_count_of_counts(2, &input);
+        check(&format!("{:?}", result), expect_test::expect!["2"]);
+    }
+
+    #[test]
+    fn find_correct_hand_types() {
+        fn construct_hand_type(input: &str) -> HandType {
+            let mut cards: Vec<Card> = Vec::new();
+            for c in input.chars() {
+                cards.push(Card::new(c).unwrap())
+            }
+            Hand::find_type(cards)
+        }
+
+        let result = construct_hand_type("AAAAA");
+        check(
+            &format!("{:?}", result),
+            expect_test::expect!["FiveOfAKind"],
+        );
+
+        let result = construct_hand_type("AA8AA");
+        check(
+            &format!("{:?}", result),
+            expect_test::expect!["FourOfAKind"],
+        );
+
+        let result = construct_hand_type("23332");
+        check(&format!("{:?}", result), expect_test::expect!["FullHouse"]);
+
+        let result = construct_hand_type("TTT98");
+        check(
+            &format!("{:?}", result),
+            expect_test::expect!["ThreeOfAKind"],
+        );
+
+        let result = construct_hand_type("23432");
+        check(&format!("{:?}", result), expect_test::expect!["TwoPair"]);
+
+        let result = construct_hand_type("A23A4");
+        check(&format!("{:?}", result), expect_test::expect!["OnePair"]);
+
+        let result = construct_hand_type("23456");
+        check(&format!("{:?}", result), expect_test::expect!["HighCard"]);
+    }
+
+    #[test]
+    fn extract_proper_hands() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let result = extract_hands(input).unwrap();
+        check(
+            &format!("{:?}", result),
+            expect_test::expect!["[Hand { cards: [Three, Two, Ten, Three, King], type_: OnePair, bid: 765 }, Hand { cards: [Ten, Five, Five, Jack, Five], type_: ThreeOfAKind, bid: 684 }, Hand { cards: [King, King, Six, Seven, Seven], type_: TwoPair, bid: 28 }, Hand { cards: [King, Ten, Jack, Jack, Ten], type_: TwoPair, bid: 220 }, Hand { cards: [Queen, Queen, Queen, Jack, Ace], type_: ThreeOfAKind, bid: 483 }]"],
+        );
+    }
+
+    #[test]
+    fn sort_test() {
+        let input = "2AAAA 435\nA2AAA 555";
+        let mut hands = extract_hands(input.to_owned()).unwrap();
+        rank_hands(&mut hands);
+        check(&format!("{:?}", hands), expect_test::expect!["[Hand { cards: [Ace, Two, Ace, Ace, Ace], type_: FourOfAKind, bid: 555 }, Hand { cards: [Two, Ace, Ace, Ace, Ace], type_: FourOfAKind, bid: 435 }]"]);
+
+        let input = "A2AAA 435\nAA2AA 555";
+        let mut hands = extract_hands(input.to_owned()).unwrap();
+        rank_hands(&mut hands);
+        check(&format!("{:?}", hands), expect_test::expect!["[Hand { cards: [Ace, Ace, Two, Ace, Ace], type_: FourOfAKind, bid: 555 }, Hand { cards: [Ace, Two, Ace, Ace, Ace], type_: FourOfAKind, bid: 435 }]"]);
+
+        let input = "AA2AA 435\nAAA2A 555";
+        let mut hands = extract_hands(input.to_owned()).unwrap();
+        rank_hands(&mut hands);
+        check(&format!("{:?}", hands), expect_test::expect!["[Hand { cards: [Ace, Ace, Ace, Two, Ace], type_: FourOfAKind, bid: 555 }, Hand { cards: [Ace, Ace, Two, Ace, Ace], type_: FourOfAKind, bid: 435 }]"]);
+
+        let input = "AAA2A 435\nAAAA2 555";
+        let mut hands = extract_hands(input.to_owned()).unwrap();
+        rank_hands(&mut hands);
+        check(&format!("{:?}", hands), expect_test::expect!["[Hand { cards: [Ace, Ace, Ace, Ace, Two], type_: FourOfAKind, bid: 555 }, Hand { cards: [Ace, Ace, Ace, Two, Ace], type_: FourOfAKind, bid: 435 }]"]);
+
+        let input = "KK677 28\nKTJJT 220";
+        let mut hands = extract_hands(input.to_owned()).unwrap();
+        rank_hands(&mut hands);
+        check(&format!("{:?}", hands), expect_test::expect!["[Hand { cards: [King, King, Six, Seven, Seven], type_: TwoPair, bid: 28 }, Hand { cards: [King, Ten, Jack, Jack, Ten], type_: TwoPair, bid: 220 }]"]);
+
+        let input = "T55J5 684\nQQQJA 483";
+        let mut hands = extract_hands(input.to_owned()).unwrap();
+        rank_hands(&mut hands);
+        check(&format!("{:?}", hands), expect_test::expect!["[Hand { cards: [Queen, Queen, Queen, Jack, Ace], type_: ThreeOfAKind, bid: 483 }, Hand { cards: [Ten, Five, Five, Jack, Five], type_: ThreeOfAKind, bid: 684 }]"]);
+    }
+
+    #[test]
+    fn sort_all_hands_test() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let mut hands = extract_hands(input.to_owned()).unwrap();
+        rank_hands(&mut hands);
+        check(&format!("{:?}", hands), expect_test::expect!["[Hand { cards: [Queen, Queen, Queen, Jack, Ace], type_: ThreeOfAKind, bid: 483 }, Hand { cards: [Ten, Five, Five, Jack, Five], type_: ThreeOfAKind, bid: 684 }, Hand { cards: [King, King, Six, Seven, Seven], type_: TwoPair, bid: 28 }, Hand { cards: [King, Ten, Jack, Jack, Ten], type_: TwoPair, bid: 220 }, Hand { cards: [Three, Two, Ten, Three, King], type_: OnePair, bid: 765 }]"]);
+    }
+
+    #[test]
+    fn calc_total_winnings_test() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let mut hands = extract_hands(input.to_owned()).unwrap();
+        rank_hands(&mut hands);
+        let result = calc_total_winnings(hands);
+        check(&format!("{:?}", result), expect_test::expect!["6440"]);
+    }
+
+    #[test]
+    fn report_unknown_card() {
+        let input = "32T3K 765\nT55X5 684\n";
+        let err = extract_hands(input.to_owned()).unwrap_err();
+        check(
+            &err.to_string(),
+            expect_test::expect![[r#"
+                expected one of 23456789TJQKA, found `X`
+                 --> <input>:2:4
+                  |
+                2 | T55X5 684
+                  |    ^"#]],
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use aoc_core::{diagnostic, read_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use std::path::Path;
+use thiserror::Error;
+use tracing::{debug, info_span};
+
+#[derive(Error, Debug)]
+pub enum Day9Error {
+    #[error("File not loaded: {0}")]
+    UnableToLoadFile(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("Failed to find last value in sequence {0:?}")]
+    NoLastValueInSequence(Vec<i32>),
+    #[error("Sequence {0:?} overflows while extrapolating")]
+    Overflow(Vec<i32>),
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day9Error> {
+    let input_data = info_span!("load").in_scope(|| load_input(input_path))?;
+    let sequences = info_span!("parse").in_scope(|| read_sequences(&input_data))?;
+    let sum = info_span!("solve").in_scope(|| extrapolate_all_values(sequences))?;
+    println!("{}", sum);
+    Ok(())
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(read_sequences(input)?)
+    }
+
+    fn part_one(&self, sequences: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(extrapolate_all_values(sequences)?.into())
+    }
+}
+
+fn load_input(file_path: impl AsRef<Path>) -> Result<String, Day9Error> {
+    let data = read_input(file_path.as_ref()).map_err(Day9Error::UnableToLoadFile)?;
+    debug!("loaded {} bytes", data.len());
+    Ok(data)
+}
+
+fn read_sequences(input: &str) -> Result<Vec<Vec<i32>>, Day9Error> {
+    let mut sequences = Vec::new();
+
+    for line in input.lines() {
+        let sequence: Vec<i32> = line
+            .split_whitespace()
+            .map(|num| diagnostic::parse::<i32>(input, num))
+            .collect::<Result<Vec<i32>, _>>()?;
+        sequences.push(sequence);
+    }
+
+    debug!("read {} sequences", sequences.len());
+    Ok(sequences)
+}
+
+fn extrapolate_all_values(sequences: Vec<Vec<i32>>) -> Result<i32, Day9Error> {
+    let mut sum: i32 = 0;
+
+    for sequence in sequences {
+        let reading = predict_next_reading(sequence.clone())?;
+        sum = sum
+            .checked_add(reading)
+            .ok_or(Day9Error::Overflow(sequence))?;
+    }
+
+    Ok(sum)
+}
+
+fn predict_next_reading(sequence: Vec<i32>) -> Result<i32, Day9Error> {
+    let mut found_zero_sequence = false;
+    let mut final_steps: Vec<i32> = vec![*sequence
+        .last()
+        .ok_or_else(|| Day9Error::NoLastValueInSequence(sequence.clone()))?];
+    let mut next_sequence: Vec<i32> = sequence;
+
+    while !found_zero_sequence {
+        let sequence_evaluation = find_next_sequence(&next_sequence)?;
+
+        final_steps.push(
+            *sequence_evaluation
+                .0
+                .last()
+                .ok_or_else(|| Day9Error::NoLastValueInSequence(sequence_evaluation.0.clone()))?,
+        );
+        found_zero_sequence = sequence_evaluation.1;
+
+        next_sequence = sequence_evaluation.0;
+    }
+
+    final_steps
+        .iter()
+        .try_fold(0i32, |sum, step| sum.checked_add(*step))
+        .ok_or(Day9Error::Overflow(final_steps.clone()))
+}
+
+// return sequence of steps, and if sequence was all 0's
+fn find_next_sequence(sequence: &[i32]) -> Result<(Vec<i32>, bool), Day9Error> {
+    let mut is_zero_sequence = true;
+    let mut next_sequence: Vec<i32> = Vec::new();
+    for (i, _) in sequence.iter().enumerate() {
+        if i > 0 {
+            let step = sequence[i]
+                .checked_sub(sequence[i - 1])
+                .ok_or_else(|| Day9Error::Overflow(sequence.to_vec()))?;
+            next_sequence.push(step);
+
+            if step != 0 {
+                is_zero_sequence = false;
+            }
+        }
+    }
+
+    Ok((next_sequence, is_zero_sequence))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        extrapolate_all_values, find_next_sequence, load_input, predict_next_reading,
+        read_sequences,
+    };
+
+    fn check(actual: &str, expect: expect_test::Expect) {
+        expect.assert_eq(actual);
+    }
+
+    #[test]
+    fn find_correct_steps() {
+        let input = [0, 3, 6, 9, 12, 15];
+        let result = find_next_sequence(&input).unwrap();
+        check(
+            &format!("{:?}", result),
+            expect_test::expect!["([3, 3, 3, 3, 3], false)"],
+        );
+
+        let input = [3, 3, 3, 3, 3];
+        let result = find_next_sequence(&input).unwrap();
+        check(
+            &format!("{:?}", result),
+            expect_test::expect!["([0, 0, 0, 0], true)"],
+        );
+    }
+
+    #[test]
+    fn find_correct_next_reading() {
+        let input = [0, 3, 6, 9, 12, 15];
+        let result = predict_next_reading(input.to_vec()).unwrap();
+        check(&format!("{:?}", result), expect_test::expect!["18"]);
+
+        let input = [1, 3, 6, 10, 15, 21];
+        let result = predict_next_reading(input.to_vec()).unwrap();
+        check(&format!("{:?}", result), expect_test::expect!["28"]);
+
+        let input = [10, 13, 16, 21, 30, 45];
+        let result = predict_next_reading(input.to_vec()).unwrap();
+        check(&format!("{:?}", result), expect_test::expect!["68"]);
+    }
+
+    #[test]
+    fn find_correct_sum() {
+        let input = load_input("src/example_1.txt").unwrap();
+        let result = extrapolate_all_values(read_sequences(&input).unwrap()).unwrap();
+        check(&format!("{:?}", result), expect_test::expect!["114"]);
+    }
+}
@@ -0,0 +1,69 @@
+// Steps:
+// Read the lines of the text input
+// Grab the first number and the last number that appear in each line.
+// Concat these two values together (be careful not to sum them!!)
+// Return the sum of the values obtained from each line in the text input
+
+use aoc_calibration::{CalibrationError, Engine, Scanner};
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Day1Error {
+    #[error("Cannot read contents from file")]
+    CannotReadFile(#[from] std::io::Error),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+impl From<CalibrationError> for Day1Error {
+    fn from(err: CalibrationError) -> Self {
+        match err {
+            CalibrationError::UnableToRead(err) => Day1Error::CannotReadFile(err),
+            CalibrationError::Malformed(diagnostic) => Day1Error::Malformed(diagnostic),
+        }
+    }
+}
+
+pub fn read_file_into_buffer(file_path: &Path) -> Result<Box<dyn BufRead>, InputError> {
+    open_input(file_path)
+}
+
+pub fn sum_lines(reader: impl BufRead) -> Result<u64, Day1Error> {
+    // Lines are summed on several threads as they are read
+    let [sum] = Engine::default().sums(reader, [Scanner::digits()])?;
+
+    Ok(sum)
+}
+
+/// The calibration value of each line, for a document already in memory
+pub fn calibration_values(input: &str) -> Result<Vec<u32>, Diagnostic> {
+    input
+        .lines()
+        .map(|line| {
+            Scanner::digits()
+                .calibration_value(line)
+                .ok_or_else(|| Diagnostic::new(input, line, "expected a digit in the line"))
+        })
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<u32>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(calibration_values(input)?)
+    }
+
+    fn part_one(&self, values: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(values
+            .iter()
+            .map(|&value| u64::from(value))
+            .sum::<u64>()
+            .into())
+    }
+}
@@ -0,0 +1,114 @@
+// Determine which games would have been possible if the bag had been loaded
+// with only 12 red cubes, 13 green cubes, and 14 blue cubes.
+// What is the sum of the IDs of those games?
+// Example input: Game 1: 9 red, 5 blue, 6 green; 6 red, 13 blue; 2 blue, 7 green, 5 red
+
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{delimited, pair, preceded, separated, space, tag, uint, word, Parser, Span};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Day2Error {
+    #[error("Cannot read contents from file")]
+    CannotReadFile(#[from] std::io::Error),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+pub fn read_file_into_buffer(file_path: &Path) -> Result<Box<dyn BufRead>, InputError> {
+    open_input(file_path)
+}
+
+pub fn find_possible_id_sums(
+    reader: impl BufRead,
+    color_limits: HashMap<&str, u32>,
+) -> Result<u32, Day2Error> {
+    let mut sum = 0;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+
+        let game =
+            parse_game(Span::new(&line)).map_err(|diagnostic| diagnostic.after_lines(index))?;
+
+        if is_game_possible(&game, &color_limits) {
+            sum += game.0;
+        }
+    }
+
+    Ok(sum)
+}
+
+pub fn sum_possible_ids(games: &[Game], color_limits: &HashMap<&str, u32>) -> u32 {
+    games
+        .iter()
+        .filter(|game| is_game_possible(game, color_limits))
+        .map(|(game_id, _)| game_id)
+        .sum()
+}
+
+fn is_game_possible(game: &Game, color_limits: &HashMap<&str, u32>) -> bool {
+    let (_, all_rounds) = game;
+
+    let mut is_game_possible = true;
+    for round in all_rounds {
+        for &(balls, color_grabbed) in round {
+            if is_color_limit_exceeded(color_limits, color_grabbed, balls) {
+                is_game_possible = false;
+            }
+        }
+    }
+
+    is_game_possible
+}
+
+fn is_color_limit_exceeded(color_limits: &HashMap<&str, u32>, color: &str, balls: u32) -> bool {
+    if let Some(&limit) = color_limits.get(color) {
+        balls > limit
+    } else {
+        // Color does not exist in bag
+        false
+    }
+}
+
+/// The balls grabbed in one round, as (count, color) pairs
+type Round<'a> = Vec<(u32, &'a str)>;
+
+/// A game's ID and its rounds
+pub type Game<'a> = (u32, Vec<Round<'a>>);
+
+pub fn parse_games(input: &str) -> Result<Vec<Game<'_>>, Diagnostic> {
+    Span::new(input).lines().map(parse_game).collect()
+}
+
+fn parse_game(line: Span) -> Result<Game, Diagnostic> {
+    // Each handful is a count and a color, e.g. " 9 red"
+    let handful = pair(preceded(space(), uint()), preceded(space(), word()));
+    let round = separated(handful, tag(","));
+    let game = pair(
+        delimited(tag("Game "), uint(), tag(":")),
+        separated(round, tag(";")),
+    );
+
+    game.parse_all(line)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Game<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse_games(input)?)
+    }
+
+    fn part_one(&self, games: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        let color_limits: HashMap<&str, u32> =
+            HashMap::from([("red", 12), ("green", 13), ("blue", 14)]);
+
+        Ok(sum_possible_ids(&games, &color_limits).into())
+    }
+}
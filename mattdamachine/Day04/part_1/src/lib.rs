@@ -0,0 +1,96 @@
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{delimited, numbers, pair, preceded, space, tag, uint, Parser, Span};
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Day4Error {
+    #[error("Cannot read contents from file")]
+    CannotReadFile(#[from] std::io::Error),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("Card {0} is worth too many points to add up")]
+    TooManyPoints(usize),
+}
+
+pub fn read_file_into_buffer(file_path: &Path) -> Result<Box<dyn BufRead>, InputError> {
+    open_input(file_path)
+}
+
+pub fn find_total_points(reader: impl BufRead) -> Result<i32, Day4Error> {
+    let mut sum: i32 = 0;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+
+        let card = extract_numbers(Span::new(&line))
+            .map_err(|diagnostic| diagnostic.after_lines(index))?;
+
+        sum = card_points(&card)
+            .and_then(|points| sum.checked_add(points))
+            .ok_or(Day4Error::TooManyPoints(index + 1))?;
+    }
+
+    Ok(sum)
+}
+
+pub fn total_points(cards: &[Card]) -> Result<i32, Day4Error> {
+    let mut sum: i32 = 0;
+
+    for (index, card) in cards.iter().enumerate() {
+        sum = card_points(card)
+            .and_then(|points| sum.checked_add(points))
+            .ok_or(Day4Error::TooManyPoints(index + 1))?;
+    }
+
+    Ok(sum)
+}
+
+// None if the points don't fit in an i32
+fn card_points(card: &Card) -> Option<i32> {
+    let (winning_numbers, my_numbers) = card;
+    let mut winning_num_count: u32 = 0;
+    let base: i32 = 2;
+
+    for num in my_numbers {
+        if winning_numbers.contains(num) {
+            winning_num_count += 1;
+        }
+    }
+
+    if winning_num_count > 0 {
+        base.checked_pow(winning_num_count - 1)
+    } else {
+        Some(0)
+    }
+}
+
+/// A card's winning numbers and the numbers I have
+pub type Card = (Vec<i32>, Vec<i32>);
+
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, Diagnostic> {
+    Span::new(input).lines().map(extract_numbers).collect()
+}
+
+fn extract_numbers(line: Span) -> Result<Card, Diagnostic> {
+    let card_id = delimited(pair(tag("Card"), space()), uint::<u32>(), tag(":"));
+    let winning_numbers = preceded(card_id, numbers());
+    let my_numbers = preceded(tag("|"), numbers());
+
+    pair(winning_numbers, my_numbers).parse_all(line)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<Card>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse_cards(input)?)
+    }
+
+    fn part_one(&self, cards: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(total_points(&cards)?.into())
+    }
+}
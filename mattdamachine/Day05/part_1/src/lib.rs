@@ -0,0 +1,187 @@
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{numbers, preceded, tag, Parser, Span};
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Almanac {
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    fn new() -> Self {
+        Almanac { maps: vec![] }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    conversion_lists: Vec<ConversionList>,
+}
+
+impl Map {
+    fn new() -> Self {
+        Map {
+            conversion_lists: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ConversionList {
+    destination_start: u64,
+    source_start: u64,
+    range: u64,
+}
+
+impl ConversionList {
+    fn new(destination_start: u64, source_start: u64, range: u64) -> Self {
+        ConversionList {
+            destination_start,
+            source_start,
+            range,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum Day5Error {
+    #[error("Cannot read contents from file")]
+    CannotReadFile(#[from] std::io::Error),
+    #[error("Cannot open input: {0}")]
+    CannotOpenInput(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+fn read_file_into_buffer(file_path: &Path) -> Result<(String, Vec<String>), Day5Error> {
+    let reader = open_input(file_path)?;
+
+    let (first_line, remaining_lines) = split_first_line(reader)?;
+
+    eprintln!("First line: {}", first_line);
+    eprintln!("remaining lines: {:?}", remaining_lines);
+
+    Ok((first_line, remaining_lines))
+}
+
+fn split_first_line(reader: impl BufRead) -> std::io::Result<(String, Vec<String>)> {
+    let mut lines_iter = reader.lines();
+    let first_line = lines_iter.next().transpose()?.unwrap_or_default();
+
+    let remaining_lines: Vec<String> = lines_iter.collect::<Result<_, _>>()?;
+
+    Ok((first_line, remaining_lines))
+}
+
+fn populate_almanac(lines: Vec<String>) -> Result<Almanac, Day5Error> {
+    let mut almanac = Almanac::new();
+    let mut current_map = Map::new();
+
+    for (index, line) in lines.into_iter().enumerate() {
+        if line.contains("map") {
+            if !current_map.conversion_lists.is_empty() {
+                almanac.maps.push(current_map);
+            }
+            // Start of a new map
+            current_map = Map::new();
+        } else if !line.is_empty() {
+            // populate the map with its corresponding numbers
+            // The seeds line comes before these
+            let conversion_list = conversion_list()
+                .parse_all(Span::new(&line))
+                .map_err(|diagnostic| diagnostic.after_lines(index + 1))?;
+            current_map.conversion_lists.push(conversion_list);
+        }
+    }
+
+    if !current_map.conversion_lists.is_empty() {
+        almanac.maps.push(current_map);
+    }
+
+    Ok(almanac)
+}
+
+fn conversion_list<'a>() -> impl Parser<'a, ConversionList> {
+    numbers::<u64>().try_map(|numbers| {
+        let [destination_start, source_start, range] = numbers[..] else {
+            return Err("expected a destination start, a source start and a range".to_owned());
+        };
+        if source_start.checked_add(range).is_none()
+            || destination_start.checked_add(range).is_none()
+        {
+            return Err("range runs past the largest u64".to_owned());
+        }
+        Ok(ConversionList::new(destination_start, source_start, range))
+    })
+}
+
+fn find_location_value(seed: u64, maps: Vec<Map>) -> u64 {
+    let mut current_value = seed;
+
+    for map in maps {
+        for list in map.conversion_lists {
+            if current_value >= list.source_start && current_value < list.source_start + list.range
+            {
+                current_value = current_value - list.source_start + list.destination_start;
+                break;
+            }
+        }
+    }
+
+    current_value
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day5Error> {
+    let (first_line, remaining_lines) = read_file_into_buffer(input_path)?;
+
+    let (seeds, almanac) = read_seeds_and_almanac(first_line, remaining_lines)?;
+    let smallest_value = find_smallest_location_value(seeds, &almanac);
+
+    println!("Smallest value is: {}", smallest_value);
+
+    Ok(())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = (Vec<u64>, Almanac);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        let (first_line, remaining_lines) = split_first_line(input.as_bytes())?;
+
+        Ok(read_seeds_and_almanac(first_line, remaining_lines)?)
+    }
+
+    fn part_one(&self, (seeds, almanac): Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(find_smallest_location_value(seeds, &almanac).into())
+    }
+}
+
+fn read_seeds_and_almanac(
+    first_line: String,
+    remaining_lines: Vec<String>,
+) -> Result<(Vec<u64>, Almanac), Day5Error> {
+    let seeds = preceded(tag("seeds:"), numbers::<u64>()).parse_all(Span::new(&first_line))?;
+    if seeds.is_empty() {
+        return Err(Diagnostic::at_end(&first_line, "expected at least one seed").into());
+    }
+
+    let almanac = populate_almanac(remaining_lines)?;
+
+    Ok((seeds, almanac))
+}
+
+fn find_smallest_location_value(seeds: Vec<u64>, almanac: &Almanac) -> u64 {
+    let mut location_values: Vec<u64> = seeds
+        .into_iter()
+        .map(|seed| find_location_value(seed, almanac.maps.clone()))
+        .collect();
+
+    location_values.sort();
+
+    // There is always at least one seed
+    location_values[0]
+}
@@ -0,0 +1,131 @@
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{numbers, pair, preceded, tag, word, Parser, Span};
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+
+pub struct Race {
+    time: i32,
+    distance: i32,
+}
+
+impl Race {
+    fn new(time: i32, distance: i32) -> Self {
+        Race { time, distance }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum Day6Error {
+    #[error("Cannot read contents from file")]
+    CannotReadFile(#[from] std::io::Error),
+    #[error("Cannot open input: {0}")]
+    CannotOpenInput(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("Time or distance values are 0")]
+    AbsenceOfTimes,
+    #[error("Number of time values does not match number of distance values")]
+    MismatchedLengths,
+    #[error("Product of the ways to win is too large")]
+    ProductOverflow,
+}
+
+fn read_file_into_buffer(file_path: &Path) -> Result<Box<dyn BufRead>, Day6Error> {
+    let reader = open_input(file_path)?;
+
+    Ok(reader)
+}
+
+fn extract_time_and_distance(reader: impl BufRead) -> Result<(Vec<i32>, Vec<i32>), Day6Error> {
+    let mut time_numbers: Vec<i32> = vec![];
+    let mut distance_numbers: Vec<i32> = vec![];
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+
+        if line.contains("Time") {
+            time_numbers =
+                extract_numbers(&line).map_err(|diagnostic| diagnostic.after_lines(index))?;
+        } else if line.contains("Distance") {
+            distance_numbers =
+                extract_numbers(&line).map_err(|diagnostic| diagnostic.after_lines(index))?;
+        }
+    }
+
+    // Lengths of the vectors must be the same and must not be zero
+    if time_numbers.is_empty() || distance_numbers.is_empty() {
+        Err(Day6Error::AbsenceOfTimes)
+    } else if time_numbers.len() != distance_numbers.len() {
+        Err(Day6Error::MismatchedLengths)
+    } else {
+        Ok((time_numbers, distance_numbers))
+    }
+}
+
+fn extract_numbers(line: &str) -> Result<Vec<i32>, Diagnostic> {
+    // The label is either `Time` or `Distance`
+    preceded(pair(word(), tag(":")), numbers()).parse_all(Span::new(line))
+}
+
+fn calculate_ways_to_win(race: &Race) -> i32 {
+    let mut ways = 0;
+
+    for i in 1..race.time {
+        let distance_traveled = i64::from(i) * i64::from(race.time - i);
+
+        if distance_traveled > i64::from(race.distance) {
+            ways += 1;
+        }
+    }
+
+    ways
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day6Error> {
+    let reader = read_file_into_buffer(input_path)?;
+
+    let races = read_races(reader)?;
+    let product = multiply_ways_to_win(&races)?;
+
+    println!("Product of all the possible ways to win is: {:?}", product);
+
+    Ok(())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Vec<Race>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(read_races(input.as_bytes())?)
+    }
+
+    fn part_one(&self, races: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(multiply_ways_to_win(&races)?.into())
+    }
+}
+
+fn read_races(reader: impl BufRead) -> Result<Vec<Race>, Day6Error> {
+    let (time_numbers, distance_numbers) = extract_time_and_distance(reader)?;
+
+    Ok(time_numbers
+        .iter()
+        .zip(distance_numbers.iter())
+        .map(|(&time, &distance)| Race::new(time, distance))
+        .collect())
+}
+
+fn multiply_ways_to_win(races: &[Race]) -> Result<i32, Day6Error> {
+    let mut results: Vec<i32> = vec![];
+
+    for race in races {
+        results.push(calculate_ways_to_win(race))
+    }
+
+    results
+        .iter()
+        .try_fold(1i32, |product, ways| product.checked_mul(*ways))
+        .ok_or(Day6Error::ProductOverflow)
+}
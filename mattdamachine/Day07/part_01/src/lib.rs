@@ -0,0 +1,290 @@
+// In Camel Cards, you get a list of hands, and your goal is to order them based on the strength of each hand.
+// A hand consists of five cards labeled one of A, K, Q, J, T, 9, 8, 7, 6, 5, 4, 3, or 2.
+// The relative strength of each card follows this order, where A is the highest and 2 is the lowest.
+
+// The seven types of hands from strongest to weakest:
+// 7. Five of a kind, where all five cards have the same label: AAAAA
+// 6. Four of a kind, where four cards have the same label and one card has a different label: AA8AA
+// 5. Full house, where three cards have the same label, and the remaining two cards share a different label: 23332
+// 4. Three of a kind, where three cards have the same label, and the remaining two cards are each different from any other card in the hand: TTT98
+// 3. Two pair, where two cards share one label, two other cards share a second label, and the remaining card has a third label: 23432
+// 2. One pair, where two cards share one label, and the other three cards have a different label from the pair and each other: A23A4
+// 1. High card, where all cards' labels are distinct: 23456
+
+// If two hands have the same type, then you compare one card from each hand
+// (using the strength at the top) until one is stronger than the other.
+
+// In this input, there are 1000 hands, put them in order of their strength and then multiply
+// that rank by their bid. Total up this amount for the answer.
+
+use aoc_core::{diagnostic, open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+pub struct Hand {
+    cards: String,
+    hand_type: u32, // Key for types will be above
+    bid: u32,
+}
+
+impl Hand {
+    fn new(cards: String, bid: u32) -> Self {
+        Hand {
+            cards,
+            hand_type: 0,
+            bid,
+        }
+    }
+}
+
+enum CardValue {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Eleven,
+    Twelve,
+    Thirteen,
+    Fourteen,
+}
+
+impl CardValue {
+    fn from(ch: char) -> Self {
+        match ch {
+            '2' => CardValue::Two,
+            '3' => CardValue::Three,
+            '4' => CardValue::Four,
+            '5' => CardValue::Five,
+            '6' => CardValue::Six,
+            '7' => CardValue::Seven,
+            '8' => CardValue::Eight,
+            '9' => CardValue::Nine,
+            'T' => CardValue::Ten,
+            'J' => CardValue::Eleven,
+            'Q' => CardValue::Twelve,
+            'K' => CardValue::Thirteen,
+            'A' => CardValue::Fourteen,
+            _ => unreachable!("Other kinds of cards do not exist"),
+        }
+    }
+    fn from_enum(card_value: CardValue) -> u32 {
+        match card_value {
+            CardValue::Two => 2,
+            CardValue::Three => 3,
+            CardValue::Four => 4,
+            CardValue::Five => 5,
+            CardValue::Six => 6,
+            CardValue::Seven => 7,
+            CardValue::Eight => 8,
+            CardValue::Nine => 9,
+            CardValue::Ten => 10,
+            CardValue::Eleven => 11,
+            CardValue::Twelve => 12,
+            CardValue::Thirteen => 13,
+            CardValue::Fourteen => 14,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum Day7Error {
+    #[error("Cannot read contents from file")]
+    CannotReadFile(#[from] std::io::Error),
+    #[error("Cannot open input: {0}")]
+    CannotOpenInput(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+fn read_file_into_buffer(file_path: &Path) -> Result<Box<dyn BufRead>, Day7Error> {
+    let reader = open_input(file_path)?;
+
+    Ok(reader)
+}
+
+fn extract_hands_from_file(reader: impl BufRead) -> Result<Vec<Hand>, Day7Error> {
+    let mut hands = vec![];
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let hand = extract_hand(&line).map_err(|diagnostic| diagnostic.after_lines(index))?;
+        hands.push(hand);
+    }
+
+    Ok(hands)
+}
+
+fn extract_hand(line: &str) -> Result<Hand, Diagnostic> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let [cards, bid] = parts[..] else {
+        return Err(Diagnostic::new(line, line, "expected a hand and a bid"));
+    };
+
+    // CardValue::from only knows these
+    if let Some((i, card)) = cards
+        .char_indices()
+        .find(|(_, card)| !"23456789TJQKA".contains(*card))
+    {
+        return Err(Diagnostic::new(
+            line,
+            &cards[i..i + card.len_utf8()],
+            format!("expected one of 23456789TJQKA, found `{}`", card),
+        ));
+    }
+
+    Ok(Hand::new(cards.to_string(), diagnostic::parse(line, bid)?))
+}
+
+fn find_hand_type(hand: &mut Hand) -> &mut Hand {
+    let mut hand_map: HashMap<String, u32> = HashMap::new();
+
+    // Populate hand map with the count that each card appears
+    for card in hand.cards.chars() {
+        if let std::collections::hash_map::Entry::Vacant(e) = hand_map.entry(card.to_string()) {
+            e.insert(1);
+        } else if let Some(value) = hand_map.get_mut(&card.to_string()) {
+            *value += 1;
+        }
+    }
+
+    // Find the type of the hand and modify the hand accordingly
+    // 23332 -> [2: 2, 3: 3].len() = 2
+    for (_, count) in hand_map.iter() {
+        let count = *count;
+        if count == 5 {
+            // Five of a kind
+            hand.hand_type = 7;
+            break;
+        } else if count == 4 {
+            // Four of a kind
+            hand.hand_type = 6;
+            break;
+        } else if count == 3 {
+            if hand_map.len() == 2 {
+                // Full house
+                hand.hand_type = 5;
+                break;
+            } else {
+                // Three of a kind
+                hand.hand_type = 4;
+                break;
+            }
+        } else if count == 2 {
+            if hand_map.len() == 3 {
+                // Two pair
+                hand.hand_type = 3;
+                break;
+            } else if hand_map.len() == 4 {
+                // One pair
+                hand.hand_type = 2;
+                break;
+            }
+        }
+    }
+
+    // No previous hands were found - default to high card
+    if hand.hand_type == 0 {
+        hand.hand_type = 1;
+    }
+
+    hand
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day7Error> {
+    let reader = read_file_into_buffer(input_path)?;
+
+    let hands = extract_hands_from_file(reader)?;
+    let sum = calculate_total_winnings(hands);
+
+    println!("Sum is {}", sum);
+
+    Ok(())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Vec<Hand>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(extract_hands_from_file(input.as_bytes())?)
+    }
+
+    fn part_one(&self, hands: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(calculate_total_winnings(hands).into())
+    }
+}
+
+fn calculate_total_winnings(mut hands: Vec<Hand>) -> usize {
+    for hand in &mut hands {
+        find_hand_type(hand);
+    }
+
+    let mut sum = 0;
+
+    hands.sort_by_key(|hand| {
+        (
+            hand.hand_type,
+            hand.cards
+                .chars()
+                .map(|card_char| {
+                    let card = CardValue::from(card_char);
+                    CardValue::from_enum(card)
+                })
+                .collect::<Vec<u32>>(),
+        )
+    });
+
+    for (i, hand) in hands.iter().enumerate() {
+        sum += (i + 1) * hand.bid as usize
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{extract_hands_from_file, find_hand_type, read_file_into_buffer, Day7Error};
+
+    #[test]
+    fn get_hand_type() {
+        let file_path = "examples.txt";
+        let reader = read_file_into_buffer(Path::new(file_path)).unwrap();
+        let mut hands = extract_hands_from_file(reader).unwrap();
+        for hand in &mut hands {
+            find_hand_type(hand);
+        }
+
+        let result_hand_types = hands
+            .iter()
+            .map(|hand| hand.hand_type)
+            .collect::<Vec<u32>>();
+
+        assert_eq!(result_hand_types, vec![7, 6, 5, 4, 3, 2, 1])
+    }
+
+    #[test]
+    fn report_unknown_card() {
+        let input = "32T3K 765\nT55X5 684\n";
+        let Err(Day7Error::Malformed(diagnostic)) = extract_hands_from_file(input.as_bytes())
+        else {
+            panic!("expected a diagnostic");
+        };
+
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 4));
+        assert_eq!(
+            diagnostic.message,
+            "expected one of 23456789TJQKA, found `X`"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_parse::{delimited, pair, tag, terminated, word, Parser, Span};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Day8Error {
+    #[error("Cannot read contents from file")]
+    CannotReadFile(#[from] std::io::Error),
+    #[error("Cannot open input: {0}")]
+    CannotOpenInput(#[from] InputError),
+    #[error("Key {0} does not exist in map")]
+    FailureToLookUpMapValue(String),
+    #[error("Unable to parse line from file")]
+    FailureToParseLine,
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+fn read_file_into_buffer(file_path: &Path) -> Result<Box<dyn BufRead>, Day8Error> {
+    let reader = open_input(file_path)?;
+
+    Ok(reader)
+}
+
+fn extract_lines_from_file(reader: impl BufRead) -> Result<(Vec<char>, Vec<String>), Day8Error> {
+    let mut lines_iter = reader.lines();
+
+    let first_line: Vec<char> = match lines_iter.next() {
+        Some(Ok(line)) => {
+            if line.is_empty() {
+                return Err(Diagnostic::at_end(&line, "expected L and R instructions").into());
+            }
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !matches!(c, 'L' | 'R')) {
+                let diagnostic = Diagnostic::new(
+                    &line,
+                    &line[i..i + c.len_utf8()],
+                    format!("expected L or R, found `{}`", c),
+                );
+                return Err(diagnostic.into());
+            }
+            line.chars().collect::<Vec<char>>()
+        }
+        _ => return Err(Day8Error::FailureToParseLine),
+    };
+
+    let remaining_lines: Result<Vec<String>, Day8Error> = lines_iter
+        .map(|line| line.map_err(|_| Day8Error::FailureToParseLine))
+        .collect();
+
+    let remaining_lines = remaining_lines?;
+
+    Ok((first_line, remaining_lines))
+}
+
+// Each node, mapped to its left and right neighbours
+pub type NetworkMap = HashMap<String, (String, String)>;
+
+fn populate_network_map_from_lines(network_lines: Vec<String>) -> Result<NetworkMap, Diagnostic> {
+    let mut network_map: NetworkMap = HashMap::new();
+
+    for (index, line) in network_lines.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        // The instructions line comes before these
+        let (node, (left, right)) = node()
+            .parse_all(Span::new(line))
+            .map_err(|diagnostic| diagnostic.after_lines(index + 1))?;
+
+        network_map.insert(node.to_string(), (left.to_string(), right.to_string()));
+    }
+
+    Ok(network_map)
+}
+
+fn node<'a>() -> impl Parser<'a, (&'a str, (&'a str, &'a str))> {
+    let directions = pair(terminated(word(), tag(", ")), word());
+
+    pair(
+        terminated(word(), tag(" = ")),
+        delimited(tag("("), directions, tag(")")),
+    )
+}
+
+fn calculate_steps_to_traverse_network(
+    instructions: &[char],
+    network_map: &NetworkMap,
+) -> Result<usize, Day8Error> {
+    let mut num_of_steps = 0;
+    let instructions_len = instructions.len();
+
+    let mut current_node = String::from("AAA");
+    let ending_node = String::from("ZZZ");
+
+    while current_node != ending_node {
+        match network_map.get(&current_node) {
+            None => return Err(Day8Error::FailureToLookUpMapValue(current_node)),
+            Some(node_values) => {
+                if instructions[num_of_steps % instructions_len] == 'L' {
+                    current_node = node_values.0.clone()
+                } else {
+                    // Instruction defaults to 'R'
+                    current_node = node_values.1.clone()
+                }
+
+                num_of_steps += 1
+            }
+        }
+    }
+
+    Ok(num_of_steps)
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day8Error> {
+    let reader = read_file_into_buffer(input_path)?;
+
+    let (instructions, network_map) = read_network(reader)?;
+    let num_of_steps = calculate_steps_to_traverse_network(&instructions, &network_map)?;
+
+    println!("Number of steps required = {}", num_of_steps);
+
+    Ok(())
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = (Vec<char>, NetworkMap);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(read_network(input.as_bytes())?)
+    }
+
+    fn part_one(
+        &self,
+        (instructions, network_map): Self::Input<'_>,
+    ) -> Result<Answer, SolutionError> {
+        Ok(calculate_steps_to_traverse_network(&instructions, &network_map)?.into())
+    }
+}
+
+fn read_network(reader: impl BufRead) -> Result<(Vec<char>, NetworkMap), Day8Error> {
+    let (first_line, remaining_lines) = extract_lines_from_file(reader)?;
+
+    let network_map = populate_network_map_from_lines(remaining_lines)?;
+
+    Ok((first_line, network_map))
+}
@@ -0,0 +1,145 @@
+use aoc_core::{diagnostic, open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use std::io::BufRead;
+use std::path::Path;
+use std::vec;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Day9Error {
+    #[error("Unable to read contents from file")]
+    FailedToReadFile(#[from] std::io::Error),
+    #[error("Cannot open input: {0}")]
+    CannotOpenInput(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+    #[error("History on line {0} never narrows down to all 0s")]
+    NeverSettles(usize),
+    #[error("History on line {0} overflows while extrapolating")]
+    Overflow(usize),
+}
+
+#[derive(Clone, Debug)]
+pub struct HistoryValues {
+    values: Vec<Vec<i32>>,
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day9Error> {
+    let reader = read_file_into_buffer(input_path)?;
+
+    let all_histories = populate_history_vectors(reader)?;
+    let sum = sum_extrapolated_values(all_histories)?;
+
+    println!("Sum = {}", sum);
+
+    Ok(())
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<HistoryValues>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(populate_history_vectors(input.as_bytes())?)
+    }
+
+    fn part_one(&self, all_histories: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(sum_extrapolated_values(all_histories)?.into())
+    }
+}
+
+fn sum_extrapolated_values(mut all_histories: Vec<HistoryValues>) -> Result<i32, Day9Error> {
+    let mut sum: i32 = 0;
+
+    for (index, history) in all_histories.iter_mut().enumerate() {
+        // Loop until a vector of all 0s is created
+        while !differences_are_zero(history.values.last().unwrap()) {
+            if history.values.last().unwrap().len() == 1 {
+                return Err(Day9Error::NeverSettles(index + 1));
+            }
+            let history_values_length = history.values.len();
+            let difference_vector =
+                create_differences_vector(history.values[history_values_length - 1].clone())
+                    .ok_or(Day9Error::Overflow(index + 1))?;
+            history.values.push(difference_vector);
+        }
+
+        // Moving from last to first,
+        // add the last number of the last vector with the last number of the vector before it until the first vector is reached
+        for i in 0..history.values.len() {
+            let history_length = history.values.len();
+            let current_values = history.values[history_length - (i + 1)].clone();
+
+            if i < (history_length - 1) {
+                let previous_values = &mut history.values[history_length - (i + 2)];
+
+                let new_num = current_values
+                    .last()
+                    .unwrap()
+                    .checked_add(*previous_values.last().unwrap())
+                    .ok_or(Day9Error::Overflow(index + 1))?;
+
+                previous_values.push(new_num);
+            } else {
+                // Arrived at the finalized first set of numbers
+                let final_num = current_values[current_values.len() - 1];
+
+                sum = sum
+                    .checked_add(final_num)
+                    .ok_or(Day9Error::Overflow(index + 1))?;
+            }
+        }
+    }
+
+    Ok(sum)
+}
+
+fn differences_are_zero(values: &[i32]) -> bool {
+    values.iter().all(|num| *num == 0)
+}
+
+fn create_differences_vector(values: Vec<i32>) -> Option<Vec<i32>> {
+    let mut differences: Vec<i32> = vec![];
+
+    for (i, _) in values.iter().enumerate() {
+        if i != (values.len() - 1) {
+            let difference = values[i + 1].checked_sub(values[i])?;
+            differences.push(difference);
+        }
+    }
+
+    Some(differences)
+}
+
+fn populate_history_vectors(reader: impl BufRead) -> Result<Vec<HistoryValues>, Day9Error> {
+    let mut all_histories: Vec<HistoryValues> = vec![];
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+
+        let values: Vec<i32> = line
+            .split_whitespace()
+            .map(|s| diagnostic::parse(&line, s))
+            .collect::<Result<_, _>>()
+            .map_err(|diagnostic| diagnostic.after_lines(index))?;
+
+        if values.is_empty() {
+            let diagnostic = Diagnostic::new(&line, &line, "expected a history of numbers");
+            return Err(diagnostic.after_lines(index).into());
+        }
+
+        let history = HistoryValues {
+            values: vec![values],
+        };
+
+        all_histories.push(history);
+    }
+
+    Ok(all_histories)
+}
+
+fn read_file_into_buffer(file_path: &Path) -> Result<Box<dyn BufRead>, Day9Error> {
+    let reader = open_input(file_path)?;
+
+    Ok(reader)
+}
@@ -0,0 +1,153 @@
+// The pipes are arranged in a two-dimensional grid of tiles:
+
+// | is a vertical pipe connecting north and south.
+// - is a horizontal pipe connecting east and west.
+// L is a 90-degree bend connecting north and east.
+// J is a 90-degree bend connecting north and west.
+// 7 is a 90-degree bend connecting south and west.
+// F is a 90-degree bend connecting south and east.
+// . is ground; there is no pipe in this tile.
+// S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+
+// Check all four sides (N, S, E, W) for a connecting pipe ->
+//      | = (i + 1, j) AND (i - 1, j)
+//      - = (i, j + 1) AND (i, j - 1)
+//      L = (i, j - 1) AND (i - 1, j)
+//      J = (i, j + 1) AND (i - 1, j)
+//      7 = (i + 1, j) AND (i, j + 1)
+//      F = (i + 1, j) AND (i, j - 1)
+
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_grid::{Direction, Grid, Point};
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Day10Error {
+    #[error("Unable to read contents from file")]
+    FailedToReadFile(#[from] std::io::Error),
+    #[error("Cannot open input: {0}")]
+    CannotOpenInput(#[from] InputError),
+    #[error("Failed to locate a starting index")]
+    NoStartingIndexFound,
+    #[error("The pipes stop at {0} without getting back to the start")]
+    LoopNotClosed(Point),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day10Error> {
+    let pipes = read_file_into_buffer(input_path)?;
+
+    // (17, 83)
+    let starting_index = find_starting_index(&pipes)?;
+
+    let total_steps = traverse_the_pipes(starting_index, &pipes)?;
+
+    println!("total steps = {}", total_steps);
+
+    Ok(())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Grid<char>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(read_lines(input.as_bytes())?)
+    }
+
+    fn part_one(&self, pipes: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        let starting_index = find_starting_index(&pipes)?;
+
+        // The traversal stops one pipe short of returning to the start, so the loop
+        // is one longer than the steps taken and the farthest pipe is half of that.
+        let total_steps = traverse_the_pipes(starting_index, &pipes)?;
+
+        Ok(total_steps.div_ceil(2).into())
+    }
+}
+
+fn find_starting_index(pipes: &Grid<char>) -> Result<Point, Day10Error> {
+    pipes
+        .position(|&pipe| pipe == 'S')
+        .ok_or(Day10Error::NoStartingIndexFound)
+}
+
+fn traverse_the_pipes(starting_index: Point, pipes: &Grid<char>) -> Result<usize, Day10Error> {
+    let mut index = starting_index;
+    let mut step_counter = 0;
+    let mut visited = HashSet::new();
+    visited.insert(index);
+
+    // Travel until the only way on is back to the starting pipe
+    loop {
+        let current_pipe = pipes[index];
+
+        // Check N, E, S and W in turn
+        let next_index = Direction::ALL.into_iter().find_map(|direction| {
+            let next_index = pipes.step(index, direction)?;
+            let open = pipe_can_go(current_pipe, direction)
+                && pipe_can_be_travelled(pipes[next_index], direction)
+                && !visited.contains(&next_index);
+            open.then_some(next_index)
+        });
+
+        match next_index {
+            Some(next_index) => {
+                index = next_index;
+                step_counter += 1;
+                visited.insert(index);
+            }
+            None if step_counter > 0 && leads_to(pipes, index, starting_index) => {
+                return Ok(step_counter)
+            }
+            None => return Err(Day10Error::LoopNotClosed(index)),
+        }
+    }
+}
+
+// Utility functions to make above function a little more concise
+fn pipe_can_go(pipe: char, direction: Direction) -> bool {
+    match direction {
+        Direction::Up => pipe == 'S' || pipe == '|' || pipe == 'L' || pipe == 'J',
+        Direction::Right => pipe == 'S' || pipe == '-' || pipe == 'L' || pipe == 'F',
+        Direction::Down => pipe == 'S' || pipe == '|' || pipe == '7' || pipe == 'F',
+        Direction::Left => pipe == 'S' || pipe == '-' || pipe == 'J' || pipe == '7',
+    }
+}
+
+// The starting pipe is never travelled onto, which is where the traversal stops
+fn pipe_can_be_travelled(next_pipe: char, direction: Direction) -> bool {
+    next_pipe != 'S' && pipe_can_go(next_pipe, direction.opposite())
+}
+
+fn leads_to(pipes: &Grid<char>, index: Point, target: Point) -> bool {
+    Direction::ALL.into_iter().any(|direction| {
+        pipe_can_go(pipes[index], direction) && pipes.step(index, direction) == Some(target)
+    })
+}
+
+fn read_file_into_buffer(file_path: &Path) -> Result<Grid<char>, Day10Error> {
+    let reader = open_input(file_path)?;
+
+    read_lines(reader)
+}
+
+fn read_lines(mut reader: impl BufRead) -> Result<Grid<char>, Day10Error> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    let pipes = Grid::try_parse_with(&input, |c| {
+        if "|-LJ7F.S".contains(c) {
+            Ok(c)
+        } else {
+            Err(format!("expected one of |-LJ7F.S, found `{}`", c))
+        }
+    })?;
+
+    Ok(pipes)
+}
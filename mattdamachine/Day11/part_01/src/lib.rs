@@ -0,0 +1,116 @@
+use aoc_core::{open_input, Answer, Diagnostic, InputError, Solution, SolutionError};
+use aoc_grid::{Grid, Point};
+use std::io::BufRead;
+use std::path::Path;
+use std::vec;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Day11Error {
+    #[error("Unable to read contents from file")]
+    FailedToReadFile(#[from] std::io::Error),
+    #[error("Cannot open input: {0}")]
+    CannotOpenInput(#[from] InputError),
+    #[error("{0}")]
+    Malformed(#[from] Diagnostic),
+}
+
+pub fn run(input_path: &Path) -> Result<(), Day11Error> {
+    let universe = read_file_into_buffer(input_path)?;
+
+    let sum = sum_galaxy_distances(&universe);
+
+    println!("Sum: {}", sum);
+
+    Ok(())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Grid<char>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(read_lines(input.as_bytes())?)
+    }
+
+    fn part_one(&self, universe: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(sum_galaxy_distances(&universe).into())
+    }
+}
+
+fn sum_galaxy_distances(universe: &Grid<char>) -> usize {
+    let expanded_universe = expand_universe(universe);
+
+    let galaxy_indices: Vec<Point> = locate_galaxy_indices(&expanded_universe);
+
+    calculate_distances(galaxy_indices)
+}
+
+fn calculate_distances(indices: Vec<Point>) -> usize {
+    let mut sum = 0;
+
+    for (i, location) in indices.iter().enumerate() {
+        let remaining_indices = indices.iter().skip(i + 1);
+
+        for next_location in remaining_indices {
+            let y_diff = next_location.col.abs_diff(location.col);
+            let x_diff = next_location.row.abs_diff(location.row);
+            sum += y_diff + x_diff;
+        }
+    }
+
+    sum
+}
+
+fn locate_galaxy_indices(universe: &Grid<char>) -> Vec<Point> {
+    // Keep track of where the galaxies are
+    universe
+        .iter()
+        .filter(|(_, &char)| char == '#')
+        .map(|(point, _)| point)
+        .collect()
+}
+
+fn expand_universe(universe: &Grid<char>) -> Grid<char> {
+    // Every empty row and column is doubled
+    let empty_columns: Vec<bool> = universe
+        .columns()
+        .map(|mut column| column.all(|&char| char == '.'))
+        .collect();
+
+    let mut rows = vec![];
+    for row in universe.rows() {
+        let mut expanded_row = vec![];
+        for (j, &char) in row.iter().enumerate() {
+            expanded_row.push(char);
+            if empty_columns[j] {
+                expanded_row.push('.');
+            }
+        }
+
+        if !row.contains(&'#') {
+            rows.push(expanded_row.clone());
+        }
+        rows.push(expanded_row);
+    }
+
+    Grid::from_rows(rows).expect("every row is expanded the same")
+}
+
+fn read_file_into_buffer(file_path: &Path) -> Result<Grid<char>, Day11Error> {
+    let reader = open_input(file_path)?;
+    read_lines(reader)
+}
+
+fn read_lines(mut reader: impl BufRead) -> Result<Grid<char>, Day11Error> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    let universe = Grid::try_parse_with(&input, |c| match c {
+        '#' | '.' => Ok(c),
+        _ => Err(format!("expected `#` or `.`, found `{}`", c)),
+    })?;
+
+    Ok(universe)
+}
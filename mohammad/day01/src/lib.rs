@@ -0,0 +1,66 @@
+use aoc_calibration::Vocabulary;
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::Span;
+
+fn calibration_value(line: &Span, digits: &[u32]) -> Result<u32, Diagnostic> {
+    match (digits.first(), digits.last()) {
+        (Some(first), Some(last)) => Ok(10 * first + last),
+        _ => Err(line.error("expected a digit in the line")),
+    }
+}
+
+// The lines of the calibration document, which still know where they are in it
+pub fn parse(input: &str) -> Vec<Span<'_>> {
+    Span::new(input).lines().collect()
+}
+
+pub fn part1(lines: &[Span]) -> Result<u32, Diagnostic> {
+    let mut total_sum = 0;
+    for line in lines {
+        let digits = line
+            .as_str()
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .collect::<Vec<_>>();
+        total_sum += calibration_value(line, &digits)?;
+    }
+
+    Ok(total_sum)
+}
+
+pub fn part2(lines: &[Span]) -> Result<u32, Diagnostic> {
+    part2_with(lines, &Vocabulary::english())
+}
+
+// Part 2 with number words from another vocabulary, e.g. one read from a file
+pub fn part2_with(lines: &[Span], vocabulary: &Vocabulary) -> Result<u32, Diagnostic> {
+    let scanner = vocabulary.scanner();
+    let mut total_sum = 0;
+    for line in lines {
+        // Spelled out numbers can overlap, like "twone"
+        let value = scanner
+            .calibration_value(line.as_str())
+            .ok_or_else(|| line.error("expected a digit in the line"))?;
+        total_sum += value;
+    }
+
+    Ok(total_sum)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<Span<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input))
+    }
+
+    fn part_one(&self, lines: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&lines)?.into())
+    }
+
+    fn part_two(&self, lines: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part2(&lines)?.into())
+    }
+}
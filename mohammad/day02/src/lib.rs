@@ -0,0 +1,104 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::{delimited, one_of, pair, preceded, separated, space, tag, uint, Parser, Span};
+use core::cmp::max;
+
+#[derive(Debug)]
+pub struct Game<'a> {
+    line: Span<'a>,
+    id: usize,
+    max_red: usize,
+    max_green: usize,
+    max_blue: usize,
+}
+
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Clone, Copy)]
+enum Colour {
+    Red,
+    Green,
+    Blue,
+}
+
+const COLOURS: &[(&str, Colour)] = &[
+    ("red", Colour::Red),
+    ("green", Colour::Green),
+    ("blue", Colour::Blue),
+];
+
+pub fn parse(input: &str) -> Result<Vec<Game<'_>>, Diagnostic> {
+    let mut games = Vec::new();
+
+    // "Game 1: 3 blue, 4 red; 1 red, 2 green"
+    let handful = pair(
+        preceded(space(), uint()),
+        preceded(space(), one_of(COLOURS)),
+    );
+    let rounds = separated(separated(handful, tag(",")), tag(";"));
+    let game_line = pair(delimited(tag("Game "), uint(), tag(":")), rounds);
+
+    for line in Span::new(input).lines() {
+        let (id, rounds) = game_line.parse_all(line)?;
+        let mut game = Game {
+            line,
+            id,
+            max_red: 0,
+            max_green: 0,
+            max_blue: 0,
+        };
+
+        for (amount, colour) in rounds.into_iter().flatten() {
+            match colour {
+                Colour::Red => game.max_red = max(game.max_red, amount),
+                Colour::Green => game.max_green = max(game.max_green, amount),
+                Colour::Blue => game.max_blue = max(game.max_blue, amount),
+            }
+        }
+        games.push(game);
+    }
+
+    Ok(games)
+}
+
+pub fn solution(games: &[Game], part: &Part) -> Result<usize, Diagnostic> {
+    let mut result: usize = 0;
+
+    for game in games {
+        let value = match part {
+            Part::One if game.max_red <= 12 && game.max_green <= 13 && game.max_blue <= 14 => {
+                Some(game.id)
+            }
+            Part::One => Some(0),
+            Part::Two => game
+                .max_red
+                .checked_mul(game.max_green)
+                .and_then(|power| power.checked_mul(game.max_blue)),
+        };
+        result = value
+            .and_then(|value| result.checked_add(value))
+            .ok_or_else(|| game.line.error("this game overflows the total"))?;
+    }
+
+    Ok(result)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Game<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, games: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&games, &Part::One)?.into())
+    }
+
+    fn part_two(&self, games: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&games, &Part::Two)?.into())
+    }
+}
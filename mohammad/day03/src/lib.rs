@@ -0,0 +1,59 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_schematic::Schematic;
+
+pub enum Part {
+    One,
+    Two,
+}
+
+pub fn parse(input: &str) -> Result<Schematic, Diagnostic> {
+    let mut cols = None;
+    for line in input.lines() {
+        let len = line.chars().count();
+        let cols = *cols.get_or_insert(len);
+        if len != cols {
+            let message = format!(
+                "expected {} characters like the first line, found {}",
+                cols, len
+            );
+            return Err(Diagnostic::new(input, line, message));
+        }
+    }
+
+    if cols.is_none() {
+        return Err(Diagnostic::at_end(input, "expected an engine schematic"));
+    }
+
+    Schematic::parse(input)
+}
+
+pub fn solution(schematic: &Schematic, part: &Part) -> Result<u64, SolutionError> {
+    match part {
+        Part::One => Ok(schematic
+            .part_numbers()
+            .map(|number| u64::from(number.value))
+            .sum()),
+        Part::Two => schematic
+            .gear_ratios()
+            .try_fold(0, u64::checked_add)
+            .ok_or_else(|| SolutionError::Failed("the gear ratios add up to too much".into())),
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Schematic;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, schematic: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&schematic, &Part::One)?.into())
+    }
+
+    fn part_two(&self, schematic: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&schematic, &Part::Two)?.into())
+    }
+}
@@ -0,0 +1,83 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::{delimited, numbers, pair, preceded, space, tag, uint, Parser, Span};
+use std::collections::{BTreeMap, HashSet};
+
+pub enum Part {
+    One,
+    Two,
+}
+
+pub struct Card<'a> {
+    line: Span<'a>,
+    matches: usize, // How many winning numbers the card has
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card<'_>>, Diagnostic> {
+    let card_id = delimited(pair(tag("Card"), space()), uint::<usize>(), tag(":"));
+    let card = pair(preceded(card_id, numbers()), preceded(tag("|"), numbers()));
+
+    let mut cards = Vec::new();
+    for line in Span::new(input).lines() {
+        let (winning, numbers): (Vec<usize>, Vec<usize>) = card.parse_all(line)?;
+        let winning: HashSet<_> = winning.into_iter().collect();
+        let numbers: HashSet<_> = numbers.into_iter().collect();
+
+        cards.push(Card {
+            line,
+            matches: winning.intersection(&numbers).count(),
+        });
+    }
+
+    Ok(cards)
+}
+
+pub fn solution(cards: &[Card], part: &Part) -> Result<usize, Diagnostic> {
+    match part {
+        Part::One => {
+            let mut result: usize = 0;
+            for card in cards {
+                if card.matches > 0 {
+                    result = u32::try_from(card.matches - 1)
+                        .ok()
+                        .and_then(|exponent| 2usize.checked_pow(exponent))
+                        .and_then(|points| result.checked_add(points))
+                        .ok_or_else(|| card.line.error("too many points to score"))?;
+                };
+            }
+            Ok(result)
+        }
+        Part::Two => {
+            let mut map: BTreeMap<usize, usize> = BTreeMap::new();
+            let mut result: usize = 0;
+            for (idx, card) in cards.iter().enumerate() {
+                let too_many = || card.line.error("too many cards to count");
+                let copies = map.get(&idx).copied().unwrap_or(0);
+                let multiplier = copies.checked_add(1).ok_or_else(too_many)?;
+                result = result.checked_add(multiplier).ok_or_else(too_many)?;
+                for i in (idx + 1)..=(idx + card.matches) {
+                    let value = map.entry(i).or_insert(0);
+                    *value = value.checked_add(multiplier).ok_or_else(too_many)?;
+                }
+            }
+            Ok(result)
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<Card<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, cards: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&cards, &Part::One)?.into())
+    }
+
+    fn part_two(&self, cards: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&cards, &Part::Two)?.into())
+    }
+}
@@ -0,0 +1,212 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::{numbers, preceded, tag, Parser, Span};
+use std::collections::BTreeMap;
+
+pub enum Part {
+    One,
+    Two,
+}
+
+// Gets a single element from a map of ranges
+fn get_from_map(map: &BTreeMap<(u64, u64), (u64, u64)>, attribute: u64) -> u64 {
+    let in_range = |val, range: (u64, u64)| val >= range.0 && val <= range.1;
+    for (key, value) in map {
+        // If within range, then do some offsetting
+        if in_range(attribute, *key) && in_range(attribute, *key) {
+            return value.0 + (attribute - key.0);
+        }
+    }
+    attribute
+}
+
+// Gets a vector of ranges corresponding to a input vector of ranges and a map of ranges
+fn get_ranges_from_map(
+    map: &BTreeMap<(u64, u64), (u64, u64)>,
+    attributes: &Vec<(u64, u64)>,
+) -> Vec<(u64, u64)> {
+    let mut result: Vec<(u64, u64)> = Vec::new();
+    for attribute in attributes {
+        result.extend(get_range_from_map(map, *attribute));
+    }
+
+    result
+}
+
+// Gets a vector of ranges corresponding to an input range and a map of ranges
+fn get_range_from_map(
+    map: &BTreeMap<(u64, u64), (u64, u64)>,
+    attribute: (u64, u64),
+) -> Vec<(u64, u64)> {
+    let mut result: Vec<(u64, u64)> = Vec::new();
+    let in_range = |val, range: (u64, u64)| val >= range.0 && val <= range.1;
+    let mut remaining: Vec<(u64, u64)> = Vec::new();
+    remaining.push(attribute);
+    let mut found;
+    while let Some(attribute) = remaining.pop() {
+        found = false;
+        for (key, value) in map {
+            if in_range(attribute.0, *key) && in_range(attribute.1, *key) {
+                result.push((
+                    value.0 + (attribute.0 - key.0),
+                    value.0 + (attribute.1 - key.0),
+                ));
+                found = true;
+                break;
+            } else if in_range(attribute.0, *key) && !in_range(attribute.1, *key) {
+                result.push((value.0 + (attribute.0 - key.0), value.1));
+                remaining.push((key.1 + 1, attribute.1));
+                found = true;
+                break;
+            } else if !in_range(attribute.0, *key) && in_range(attribute.1, *key) {
+                result.push((value.0, value.0 + attribute.1 - key.0));
+                remaining.push((attribute.0, key.0 - 1));
+                found = true;
+                break;
+            } else if !in_range(attribute.0, *key)
+                && !in_range(attribute.1, *key)
+                && attribute.0 <= key.0
+                && attribute.1 >= key.1
+            {
+                result.push((value.0, value.1));
+                remaining.push((attribute.0, key.0 - 1));
+                remaining.push((key.1 + 1, attribute.1));
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            result.push((attribute.0, attribute.1));
+        }
+    }
+
+    result
+}
+
+const MAP_HEADERS: [&str; 7] = [
+    "seed-to-soil map:",
+    "soil-to-fertilizer map:",
+    "fertilizer-to-water map:",
+    "water-to-light map:",
+    "light-to-temperature map:",
+    "temperature-to-humidity map:",
+    "humidity-to-location map:",
+];
+
+pub struct Almanac<'a> {
+    seeds_line: Span<'a>,
+    seeds: Vec<u64>,
+    maps: Vec<BTreeMap<(u64, u64), (u64, u64)>>,
+}
+
+pub fn parse(input: &str) -> Result<Almanac<'_>, Diagnostic> {
+    // Get the seeds
+    let mut lines = Span::new(input).lines();
+    let seeds_line = lines.next().unwrap_or(Span::new(input));
+    let seeds = preceded(tag("seeds:"), numbers()).parse_all(seeds_line)?;
+    let mut maps: Vec<BTreeMap<(u64, u64), (u64, u64)>> = Vec::new();
+
+    for line in lines {
+        // Get all the maps, encoded as ranges
+        match line.trim().as_str() {
+            "" => {}
+            header if header.ends_with(" map:") => {
+                let Some(expected) = MAP_HEADERS.get(maps.len()) else {
+                    return Err(line.error("expected no more maps"));
+                };
+                if header != *expected {
+                    return Err(line.error(format!("expected `{}`", expected)));
+                }
+                maps.push(BTreeMap::new());
+            }
+            _ if !maps.is_empty() => {
+                let v: Vec<u64> = numbers().parse_all(line)?;
+                if v.len() != 3 {
+                    return Err(
+                        line.error("expected a destination start, a source start and a range")
+                    );
+                }
+                if v[2] == 0 {
+                    continue;
+                }
+                let (Some(source_end), Some(destination_end)) =
+                    (v[1].checked_add(v[2] - 1), v[0].checked_add(v[2] - 1))
+                else {
+                    return Err(line.error("range is too large"));
+                };
+                let map = maps.last_mut().unwrap();
+                map.insert((v[1], source_end), (v[0], destination_end));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Almanac {
+        seeds_line,
+        seeds,
+        maps,
+    })
+}
+
+pub fn solution(almanac: &Almanac, part: &Part) -> Result<u64, Diagnostic> {
+    let Almanac {
+        seeds_line,
+        seeds,
+        maps,
+    } = almanac;
+
+    // Walk the maps
+    match part {
+        Part::One => {
+            let mut result = u64::MAX;
+            for seed in seeds {
+                let mut attribute = *seed;
+                for map in maps {
+                    attribute = get_from_map(map, attribute);
+                }
+                result = std::cmp::min(result, attribute);
+            }
+            Ok(result)
+        }
+
+        // Walk the maps
+        Part::Two => {
+            let mut result = u64::MAX;
+            for i in 0..seeds.len() / 2 {
+                let (start, length) = (seeds[2 * i], seeds[2 * i + 1]);
+                if length == 0 {
+                    continue;
+                }
+                let end = start
+                    .checked_add(length - 1)
+                    .ok_or_else(|| seeds_line.error("seed range is too large"))?;
+                let mut attributes = vec![(start, end)];
+                for map in maps {
+                    attributes = get_ranges_from_map(map, &attributes);
+                }
+                for attribute in attributes {
+                    result = std::cmp::min(result, attribute.0);
+                }
+            }
+            Ok(result)
+        }
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = Almanac<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, almanac: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&almanac, &Part::One)?.into())
+    }
+
+    fn part_two(&self, almanac: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&almanac, &Part::Two)?.into())
+    }
+}
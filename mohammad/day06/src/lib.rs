@@ -0,0 +1,97 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::{many, pair, preceded, space, tag, uint, Parser, Span};
+
+pub enum Part {
+    One,
+    Two,
+}
+
+pub struct Races {
+    // (time, distance) for each race
+    races: Vec<(u64, u64)>,
+    // Part 2 ignores the spaces between the digits, so it's all one race
+    kerned: (u64, u64),
+}
+
+fn to_u64(digits: &str) -> Result<u64, String> {
+    digits
+        .parse()
+        .map_err(|err| format!("unable to parse `{}`: {}", digits, err))
+}
+
+// The numbers after `label:` on the next line, read both ways
+fn values(
+    input: &str,
+    line: Option<Span>,
+    label: &'static str,
+) -> Result<(Vec<u64>, u64), Diagnostic> {
+    let Some(line) = line else {
+        return Err(Diagnostic::at_end(input, format!("expected `{}:`", label)));
+    };
+    let label = pair(tag(label), tag(":"));
+
+    preceded(label, many(preceded(space(), uint::<String>())))
+        .try_map(|groups| {
+            let values = groups
+                .iter()
+                .map(|digits| to_u64(digits))
+                .collect::<Result<_, _>>()?;
+            Ok((values, to_u64(&groups.concat())?))
+        })
+        .parse_all(line)
+}
+
+pub fn parse(input: &str) -> Result<Races, Diagnostic> {
+    let mut lines = Span::new(input).lines();
+
+    let (times, time) = values(input, lines.next(), "Time")?;
+    let (distances, distance) = values(input, lines.next(), "Distance")?;
+
+    Ok(Races {
+        races: times.into_iter().zip(distances).collect(),
+        kerned: (time, distance),
+    })
+}
+
+fn ways_to_win(time: u64, distance: u64) -> u64 {
+    let mut wins = 0;
+    for t in 0..time {
+        wins += u64::from(u128::from(t) * u128::from(time - t) > u128::from(distance));
+    }
+    wins
+}
+
+pub fn solution(races: &Races, part: &Part) -> Result<u64, SolutionError> {
+    match part {
+        Part::One => {
+            let mut result: u64 = 1;
+            for &(time, distance) in &races.races {
+                result = result
+                    .checked_mul(ways_to_win(time, distance))
+                    .ok_or_else(|| {
+                        SolutionError::Failed("the product of the ways to win overflows".into())
+                    })?;
+            }
+            Ok(result)
+        }
+        Part::Two => Ok(ways_to_win(races.kerned.0, races.kerned.1)),
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Races;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, races: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&races, &Part::One)?.into())
+    }
+
+    fn part_two(&self, races: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&races, &Part::Two)?.into())
+    }
+}
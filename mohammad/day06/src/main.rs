@@ -1,82 +1,25 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    path::PathBuf,
-};
+use std::process;
 
-enum Part {
-    One,
-    Two,
-}
-
-fn solution(part: &Part) {
-    let path: PathBuf = "src/input_large".into();
-    let handle = File::open(path.clone()).unwrap();
-    let mut lines = BufReader::new(handle).lines();
-
-    let mut result = 1;
-
-    let races = match part {
-        Part::One => lines
-            .next()
-            .unwrap()
-            .unwrap()
-            .split(':')
-            .collect::<Vec<_>>()[1]
-            .split_ascii_whitespace()
-            .map(|s| s.parse::<u64>().unwrap())
-            .zip(
-                lines
-                    .next()
-                    .unwrap()
-                    .unwrap()
-                    .split(':')
-                    .collect::<Vec<_>>()[1]
-                    .split_ascii_whitespace()
-                    .map(|s| s.parse::<u64>().unwrap()),
-            )
-            .collect::<Vec<_>>(),
-        Part::Two => {
-            let mut times = lines
-                .next()
-                .unwrap()
-                .unwrap()
-                .split(':')
-                .collect::<Vec<_>>()[1]
-                .to_string();
-            times.retain(|c| c.is_ascii_digit());
-
-            let mut distances = lines
-                .next()
-                .unwrap()
-                .unwrap()
-                .split(':')
-                .collect::<Vec<_>>()[1]
-                .to_string();
-            distances.retain(|c| c.is_ascii_digit());
+use mohammad_day06::{parse, solution, Part};
 
-            vec![(
-                times.parse::<u64>().unwrap(),
-                distances.parse::<u64>().unwrap(),
-            )]
+fn main() {
+    let input_path = aoc_core::input_path(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input_large"));
+    let input = match aoc_core::read_input(&input_path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            process::exit(1);
         }
     };
 
-    for (time, distance) in races {
-        let mut wins = 0;
-        for t in 0..time {
-            wins += u64::from(t * (time - t) > distance);
-        }
-        result *= wins;
-    }
-
-    match part {
-        Part::One => println!("Part 1 answer: {result}"),
-        Part::Two => println!("Part 2 answer: {result}"),
+    let fail = |err: &dyn std::fmt::Display| -> ! {
+        eprintln!("Error: {}", err);
+        process::exit(1);
     };
-}
 
-fn main() {
-    solution(&Part::One);
-    solution(&Part::Two);
+    let races = parse(&input).unwrap_or_else(|err| fail(&err.in_file(&input_path)));
+    let part_1_answer = solution(&races, &Part::One).unwrap_or_else(|err| fail(&err));
+    println!("Part 1 answer: {}", part_1_answer);
+    let part_2_answer = solution(&races, &Part::Two).unwrap_or_else(|err| fail(&err));
+    println!("Part 2 answer: {}", part_2_answer);
 }
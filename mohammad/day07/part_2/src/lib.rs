@@ -0,0 +1,116 @@
+use aoc_core::{diagnostic, Answer, Diagnostic, Solution, SolutionError};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
+enum Type {
+    HighCard = 0,
+    OnePair = 1,
+    TwoPairs = 2,
+    ThreeOfAKind = 3,
+    FullHouse = 4,
+    FourOfAKind = 5,
+    FiveOfAKind = 6,
+}
+
+fn get_type(hand: &Vec<usize>) -> Type {
+    let mut map: HashMap<usize, usize> = HashMap::new();
+
+    for c in hand {
+        map.entry(*c).and_modify(|value| *value += 1).or_insert(1);
+    }
+
+    let mut values = map
+        .iter()
+        .filter(|(k, _)| **k != 1usize) // exclude the joker with value `1`
+        .map(|(_, v)| v)
+        .collect::<Vec<_>>();
+    values.sort();
+
+    // Fill up with the joker whenever possible to get the best hand
+    match values[..] {
+        [1..=5] | [] => Type::FiveOfAKind,
+        [1, 1..=4] => Type::FourOfAKind,
+        [2, 3 | 2] => Type::FullHouse,
+        [1, 1, 1..=3] => Type::ThreeOfAKind,
+        [1, 2, 2] => Type::TwoPairs,
+        [1, 1, 1, 2 | 1] => Type::OnePair,
+        [1, 1, 1, 1, 1] => Type::HighCard,
+        _ => unreachable!("Unexpected pattern"),
+    }
+}
+
+pub struct Hand {
+    cards: Vec<usize>,
+    kind: Type,
+    bid: usize,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Hand>, Diagnostic> {
+    let mut hands = Vec::new();
+    for line in input.lines() {
+        let split = line.split_ascii_whitespace().collect::<Vec<_>>();
+        let [cards, bid] = split[..] else {
+            return Err(Diagnostic::new(input, line, "expected a hand and a bid"));
+        };
+        let hand = cards
+            .char_indices()
+            .map(|(i, c)| match c {
+                'T' => Ok(10),
+                'J' => Ok(1), // Now lowest card
+                'Q' => Ok(12),
+                'K' => Ok(13),
+                'A' => Ok(14),
+                '2'..='9' => Ok(c as usize - '0' as usize),
+                _ => Err(Diagnostic::new(
+                    input,
+                    &cards[i..i + c.len_utf8()],
+                    format!("expected one of 23456789TJQKA, found `{}`", c),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if hand.len() != 5 {
+            return Err(Diagnostic::new(
+                input,
+                cards,
+                "expected a hand of five cards",
+            ));
+        }
+
+        hands.push(Hand {
+            kind: get_type(&hand),
+            cards: hand,
+            bid: diagnostic::parse(input, bid)?,
+        });
+    }
+
+    Ok(hands)
+}
+
+pub fn solution(mut hands: Vec<Hand>) -> Result<usize, SolutionError> {
+    // Sort by hand type and *then* by the hand itself.
+    hands.sort_by(|h1, h2| (&h1.kind, &h1.cards).cmp(&(&h2.kind, &h2.cards)));
+
+    hands
+        .iter()
+        .enumerate()
+        .try_fold(0usize, |acc, (idx, hand)| {
+            (idx + 1)
+                .checked_mul(hand.bid)
+                .and_then(|winnings| acc.checked_add(winnings))
+        })
+        .ok_or_else(|| SolutionError::Failed("the total winnings are too big to count".into()))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Vec<Hand>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_two(&self, hands: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(hands)?.into())
+    }
+}
@@ -0,0 +1,106 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::{delimited, pair, tag, terminated, word, Parser, Span};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+enum Direction {
+    Left,
+    Right,
+}
+
+pub struct Network {
+    directions: Vec<Direction>,
+    map: HashMap<String, (String, String)>,
+}
+
+pub fn parse(input: &str) -> Result<Network, Diagnostic> {
+    let mut lines = input.lines();
+
+    // Get Directions
+    let first_line = lines.next().unwrap_or("");
+    let directions = first_line
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(Diagnostic::new(
+                input,
+                &first_line[i..i + c.len_utf8()],
+                format!("expected L or R, found `{}`", c),
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if directions.is_empty() {
+        return Err(Diagnostic::new(
+            input,
+            first_line,
+            "expected L and R directions",
+        ));
+    }
+
+    // Get the map, skipping the directions and the empty line after them
+    let left_right = pair(terminated(word(), tag(", ")), word());
+    let node_line = pair(
+        terminated(word(), tag(" = ")),
+        delimited(tag("("), left_right, tag(")")),
+    );
+    let mut map: HashMap<String, (String, String)> = HashMap::new();
+    let mut nodes = Vec::new();
+    for line in Span::new(input).lines().skip(2) {
+        let (node, (left, right)) = node_line.parse_all(line)?;
+        map.insert(node.to_string(), (left.to_string(), right.to_string()));
+        nodes.extend([left, right]);
+    }
+
+    // Every node we can walk to must be in the map
+    if let Some(missing) = nodes.into_iter().find(|node| !map.contains_key(*node)) {
+        return Err(Diagnostic::new(
+            input,
+            missing,
+            format!("node `{}` is never defined", missing),
+        ));
+    }
+
+    if !map.contains_key("AAA") {
+        return Err(Diagnostic::at_end(input, "expected a node named AAA"));
+    }
+
+    Ok(Network { directions, map })
+}
+
+pub fn solution(network: &Network) -> usize {
+    let Network { directions, map } = network;
+
+    let mut steps = 0;
+    let mut current = String::from("AAA");
+    while current != "ZZZ" {
+        for d in directions {
+            match d {
+                Direction::Left => {
+                    current = map[&current].0.clone();
+                    steps += 1;
+                }
+                Direction::Right => {
+                    current = map[&current].1.clone();
+                    steps += 1;
+                }
+            }
+        }
+    }
+
+    steps
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Network;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, network: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&network).into())
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_core::{diagnostic, Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::Span;
+
+pub struct History<'a> {
+    line: Span<'a>,
+    values: Vec<i64>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<History<'_>>, Diagnostic> {
+    let mut histories = Vec::new();
+    for line in Span::new(input).lines() {
+        let values = line
+            .as_str()
+            .split_ascii_whitespace()
+            .map(|s| diagnostic::parse(input, s))
+            .collect::<Result<_, _>>()?;
+        histories.push(History { line, values });
+    }
+
+    Ok(histories)
+}
+
+// The first and last values of each row of differences, down to the row of zeroes
+fn edges(history: &History) -> Result<(Vec<i64>, Vec<i64>), Diagnostic> {
+    let mut values = history.values.clone();
+
+    let overflow = || history.line.error("extrapolating this history overflows");
+    let mut firsts = Vec::new();
+    let mut lasts = Vec::new();
+
+    while values.iter().any(|e| *e != 0) {
+        firsts.push(values[0]);
+        for i in 0..values.len() - 1 {
+            values[i] = values[i + 1].checked_sub(values[i]).ok_or_else(overflow)?;
+        }
+        lasts.push(values.pop().unwrap());
+    }
+
+    Ok((firsts, lasts))
+}
+
+pub fn part1(histories: &[History]) -> Result<i64, Diagnostic> {
+    let mut result: i64 = 0;
+    for history in histories {
+        let overflow = || history.line.error("extrapolating this history overflows");
+        let (_, lasts) = edges(history)?;
+        result = lasts
+            .iter()
+            .try_fold(result, |sum, e| sum.checked_add(*e))
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(result)
+}
+
+pub fn part2(histories: &[History]) -> Result<i64, Diagnostic> {
+    let mut result: i64 = 0;
+    for history in histories {
+        let overflow = || history.line.error("extrapolating this history overflows");
+        let (mut firsts, _) = edges(history)?;
+
+        let n = firsts.len();
+        for i in 1..n {
+            firsts[n - i - 1] = firsts[n - i - 1]
+                .checked_sub(firsts[n - i])
+                .ok_or_else(overflow)?;
+        }
+        result = result
+            .checked_add(*firsts.first().unwrap_or(&0))
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(result)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<History<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, histories: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&histories)?.into())
+    }
+
+    fn part_two(&self, histories: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part2(&histories)?.into())
+    }
+}
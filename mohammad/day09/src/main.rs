@@ -1,45 +1,28 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    path::PathBuf,
-};
+use std::process;
 
-fn solution() {
-    let path: PathBuf = "src/input_large".into();
-    let handle = File::open(path.clone()).unwrap();
-
-    let mut part_1_result = 0;
-    let mut part_2_result = 0;
-    for line in BufReader::new(handle).lines() {
-        let mut input: Vec<i64> = line
-            .unwrap()
-            .split_ascii_whitespace()
-            .map(|s| s.parse::<i64>().expect("only expecting i64 integers"))
-            .collect();
-
-        let mut lasts = Vec::new();
-        let mut firsts = Vec::new();
-
-        while input.iter().any(|e| *e != 0) {
-            firsts.push(input[0]);
-            for i in 0..input.len() - 1 {
-                input[i] = input[i + 1] - input[i];
-            }
-            lasts.push(input.pop().unwrap());
+fn main() {
+    let input_path = aoc_core::input_path(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input_large"));
+    let input = match aoc_core::read_input(&input_path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            process::exit(1);
         }
-        part_1_result += lasts.iter().sum::<i64>();
+    };
 
-        let n = firsts.len();
-        for i in 1..n {
-            firsts[n - i - 1] -= firsts[n - i];
+    let results = mohammad_day09::parse(&input).and_then(|histories| {
+        Ok((
+            mohammad_day09::part1(&histories)?,
+            mohammad_day09::part2(&histories)?,
+        ))
+    });
+    let (part_1_result, part_2_result) = match results {
+        Ok(results) => results,
+        Err(err) => {
+            eprintln!("Error: {}", err.in_file(&input_path));
+            process::exit(1);
         }
-        part_2_result += firsts[0];
-    }
-
+    };
     println!("part 1 answer: {part_1_result}");
     println!("part 2 answer: {part_2_result}");
 }
-
-fn main() {
-    solution();
-}
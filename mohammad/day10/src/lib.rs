@@ -0,0 +1,178 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_grid::{Direction as Step, Grid, Point};
+
+#[derive(Debug, Clone)]
+enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+    UpRight,
+    UpLeft,
+    DownLeft,
+    DownRight,
+    Finish,
+}
+
+fn connected(p: Point, grid: &Grid<char>) -> Vec<(Point, Direction)> {
+    let mut connected = Vec::new();
+    let current = grid[p];
+
+    if current == '-' || current == 'S' || current == 'L' || current == 'F' {
+        if let Some(next) = grid.step(p, Step::Right) {
+            match grid[next] {
+                '-' => connected.push((next, Direction::Right)),
+                '7' => connected.push((next, Direction::DownRight)),
+                'J' => connected.push((next, Direction::UpRight)),
+                'S' => connected.push((next, Direction::Finish)),
+                _ => {}
+            }
+        }
+    }
+    if current == '-' || current == 'S' || current == '7' || current == 'J' {
+        if let Some(next) = grid.step(p, Step::Left) {
+            match grid[next] {
+                '-' => connected.push((next, Direction::Left)),
+                'L' => connected.push((next, Direction::UpLeft)),
+                'F' => connected.push((next, Direction::DownLeft)),
+                'S' => connected.push((next, Direction::Finish)),
+                _ => {}
+            }
+        }
+    }
+    if current == '|' || current == 'S' || current == 'F' || current == '7' {
+        if let Some(next) = grid.step(p, Step::Down) {
+            match grid[next] {
+                '|' => connected.push((next, Direction::Down)),
+                'L' => connected.push((next, Direction::DownRight)),
+                'J' => connected.push((next, Direction::DownLeft)),
+                'S' => connected.push((next, Direction::Finish)),
+                _ => {}
+            }
+        }
+    }
+    if current == '|' || current == 'S' || current == 'J' || current == 'L' {
+        if let Some(next) = grid.step(p, Step::Up) {
+            match grid[next] {
+                '|' => connected.push((next, Direction::Up)),
+                '7' => connected.push((next, Direction::UpLeft)),
+                'F' => connected.push((next, Direction::UpRight)),
+                'S' => connected.push((next, Direction::Finish)),
+                _ => {}
+            }
+        }
+    }
+
+    connected
+}
+
+// The loop, traced out of the sketch
+pub struct Pipes {
+    // Copy of the grid that only contains the loop and `.` otherwise
+    simple_grid: Grid<char>,
+    // Matrix of all the directions. Use `Direction::Finish` for non-useful entries
+    directions: Grid<Direction>,
+    length: i32,
+}
+
+pub fn parse(input: &str) -> Result<Pipes, Diagnostic> {
+    let grid = Grid::parse(input)?;
+    let Some(start) = grid.position(|c| *c == 'S') else {
+        return Err(Diagnostic::at_end(input, "expected a starting point `S`"));
+    };
+    let dead_end = |p: Point| {
+        let line = input.lines().nth(p.row).unwrap_or("");
+        let tile = line.get(p.col..p.col + 1).unwrap_or(line);
+        Diagnostic::new(input, tile, "the loop does not continue from here")
+    };
+
+    let mut simple_grid = Grid::new(grid.width(), grid.height(), '.');
+    let mut directions = Grid::new(grid.width(), grid.height(), Direction::Finish);
+
+    // Starting point
+    let mut p = start;
+    let connections = connected(p, &grid);
+    simple_grid[start] = grid[start];
+
+    // Next point
+    let mut previous = p;
+    let Some(next) = connections.first() else {
+        return Err(dead_end(p));
+    };
+    p = next.0;
+    simple_grid[p] = grid[p];
+    (p, directions[p]) = next.clone();
+
+    let mut length = 1;
+    while p != start {
+        // Traverse the loop by looking at the connections, until we go back to `S`
+        let mut connections = connected(p, &grid);
+        connections.retain(|c| previous != c.0); // Make sure not to go back to `previous`
+        let Some(next) = connections.first() else {
+            return Err(dead_end(p));
+        };
+        previous = p;
+        p = next.0;
+        simple_grid[p] = grid[p];
+        (p, directions[p]) = next.clone();
+
+        length += 1;
+    }
+
+    Ok(Pipes {
+        simple_grid,
+        directions,
+        length,
+    })
+}
+
+pub fn part1(pipes: &Pipes) -> i32 {
+    pipes.length / 2
+}
+
+pub fn part2(pipes: &Pipes) -> i32 {
+    let Pipes {
+        simple_grid,
+        directions,
+        ..
+    } = pipes;
+    let mut result = 0;
+
+    for i in 0..simple_grid.height() {
+        let mut inside = false; // Indicates when we're going inside the loop
+        let mut row_area: i32 = 0;
+        for j in 0..simple_grid.width() {
+            use Direction::{Down, DownLeft, DownRight, Up, UpLeft, UpRight};
+            let p = Point::new(i, j);
+            match (simple_grid[p], &directions[p]) {
+                ('|', Up) | ('7' | 'L', UpLeft) | ('J' | 'F', UpRight) => inside = true,
+                ('|', Down) | ('7' | 'L', DownRight) | ('J' | 'F', DownLeft) => inside = false,
+                ('.', _) if inside => {
+                    row_area += 1;
+                }
+                _ => {}
+            }
+        }
+        result += row_area;
+    }
+
+    result
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Pipes;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, pipes: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&pipes).into())
+    }
+
+    fn part_two(&self, pipes: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part2(&pipes).into())
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_grid::Grid;
+
+pub struct Image {
+    empty_rows: Vec<i64>,
+    empty_cols: Vec<i64>,
+    stars: Vec<(i64, i64)>,
+}
+
+pub fn parse(input: &str) -> Result<Image, Diagnostic> {
+    if input.lines().next().is_none() {
+        return Err(Diagnostic::at_end(input, "expected an image of the galaxy"));
+    }
+    let galaxy = Grid::parse(input)?;
+
+    let empty_rows = galaxy
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| row.iter().all(|c| *c == '.'))
+        .map(|(idx, _)| idx as i64)
+        .collect::<Vec<_>>();
+    let empty_cols = galaxy
+        .columns()
+        .enumerate()
+        .filter_map(|(j, mut col)| col.all(|c| *c == '.').then_some(j as i64))
+        .collect::<Vec<_>>();
+
+    let stars = galaxy
+        .iter()
+        .filter(|(_, c)| **c == '#')
+        .map(|(p, _)| (p.row as i64, p.col as i64))
+        .collect::<Vec<_>>();
+
+    Ok(Image {
+        empty_rows,
+        empty_cols,
+        stars,
+    })
+}
+
+// Sum of the distances between every pair of stars, with each empty row and column
+// counted `expansion` times
+fn sum_of_distances(image: &Image, expansion: i64) -> i64 {
+    let Image {
+        empty_rows,
+        empty_cols,
+        stars,
+    } = image;
+    let mut result = 0;
+
+    for i in 0..stars.len() {
+        for j in i + 1..stars.len() {
+            let empty_rows_between = empty_rows.iter().fold(0, |acc, r| {
+                acc + i64::from(
+                    stars[i].0 < *r && stars[j].0 > *r || stars[i].0 > *r && stars[j].0 < *r,
+                )
+            });
+            let empty_cols_between = empty_cols.iter().fold(0, |acc, c| {
+                acc + i64::from(
+                    stars[i].1 < *c && stars[j].1 > *c || stars[i].1 > *c && stars[j].1 < *c,
+                )
+            });
+            result += (stars[i].0 - stars[j].0).abs() - empty_rows_between
+                + empty_rows_between * expansion
+                + (stars[i].1 - stars[j].1).abs()
+                - empty_cols_between
+                + empty_cols_between * expansion;
+        }
+    }
+
+    result
+}
+
+pub fn part1(image: &Image) -> i64 {
+    sum_of_distances(image, 2)
+}
+
+pub fn part2(image: &Image) -> i64 {
+    sum_of_distances(image, 1_000_000)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Image;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, image: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&image).into())
+    }
+
+    fn part_two(&self, image: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part2(&image).into())
+    }
+}
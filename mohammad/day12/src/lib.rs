@@ -0,0 +1,108 @@
+use aoc_core::{diagnostic, Answer, Diagnostic, Solution, SolutionError};
+use itertools::Itertools;
+
+fn is_valid(candidate: &[char], amounts: &Vec<usize>) -> bool {
+    &candidate
+        .iter()
+        .collect::<String>()
+        .split('.')
+        .filter_map(|s| if s.is_empty() { None } else { Some(s.len()) })
+        .collect::<Vec<_>>()
+        == amounts
+}
+
+fn compute_arrangements(record: &[char], amounts: &Vec<usize>) -> i64 {
+    let mut unknowns = Vec::new();
+    let mut damaged_count = 0;
+    for (idx, c) in record.iter().enumerate() {
+        if *c == '?' {
+            unknowns.push(idx);
+        } else if *c == '#' {
+            damaged_count += 1;
+        }
+    }
+
+    // No record is long enough for groups whose sizes overflow
+    let Some(amounts_sum) = amounts
+        .iter()
+        .try_fold(0usize, |sum, a| sum.checked_add(*a))
+    else {
+        return 0;
+    };
+    if amounts_sum < damaged_count || amounts_sum - damaged_count > unknowns.len() {
+        return 0;
+    }
+
+    let combinations = unknowns
+        .iter()
+        .combinations(amounts_sum - damaged_count)
+        .collect::<Vec<_>>();
+
+    let mut arrangements: i64 = 0;
+    for c in combinations {
+        let mut candidate = record.to_owned();
+        for i in 0..c.len() {
+            candidate[*c[i]] = '#';
+        }
+        for r in &mut candidate {
+            if *r == '?' {
+                *r = '.';
+            }
+        }
+        if is_valid(&candidate, amounts) {
+            arrangements += 1;
+        }
+    }
+    arrangements
+}
+
+// A spring record, along with the sizes of its damaged groups
+pub type Record = (Vec<char>, Vec<usize>);
+
+pub fn parse(input: &str) -> Result<Vec<Record>, Diagnostic> {
+    let mut records = Vec::new();
+
+    for line in input.lines() {
+        let split = line.split_ascii_whitespace().collect::<Vec<_>>();
+        let [record, amounts] = split[..] else {
+            return Err(Diagnostic::new(
+                input,
+                line,
+                "expected a record and the group sizes",
+            ));
+        };
+        let record = record.chars().collect::<Vec<_>>();
+        let amounts = amounts
+            .split(',')
+            .map(|c| diagnostic::parse::<usize>(input, c))
+            .collect::<Result<Vec<_>, _>>()?;
+        records.push((record, amounts));
+    }
+
+    Ok(records)
+}
+
+pub fn solution(records: &[Record]) -> i64 {
+    let mut part_1_result = 0;
+
+    for (record, amounts) in records {
+        let a = compute_arrangements(record, amounts);
+        part_1_result += a;
+    }
+
+    part_1_result
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Vec<Record>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, records: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&records).into())
+    }
+}
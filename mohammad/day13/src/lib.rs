@@ -0,0 +1,93 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_grid::{Grid, Point};
+
+#[derive(Debug, PartialEq)]
+enum CheckResult {
+    Valid,
+    AlmostValid,
+    Bad,
+}
+
+// Mirrors between columns are found by looking for mirrors between the rows of the transposed pattern
+fn check_mirror_at_row(pattern: &Grid<char>, row: usize) -> CheckResult {
+    let mut smudges = 0;
+    let rows = pattern.height();
+    for j in 0..pattern.width() {
+        for i in 0..std::cmp::min(rows - row - 1, row + 1) {
+            if pattern[Point::new(row + i + 1, j)] != pattern[Point::new(row - i, j)] {
+                if smudges == 1 {
+                    return CheckResult::Bad;
+                }
+                smudges += 1;
+            }
+        }
+    }
+    if smudges == 1 {
+        CheckResult::AlmostValid
+    } else if smudges == 0 {
+        CheckResult::Valid
+    } else {
+        CheckResult::Bad
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Grid<char>>, Diagnostic> {
+    let mut all_patterns: Vec<Grid<char>> = Vec::new();
+    for pattern in input.split("\n\n") {
+        if pattern.trim().is_empty() {
+            continue;
+        }
+        let grid = Grid::parse(pattern).map_err(|err| err.within(input, pattern))?;
+        all_patterns.push(grid);
+    }
+
+    Ok(all_patterns)
+}
+
+// Sum of the mirrors found with the given result: columns to the left of vertical ones,
+// plus 100 times the rows above horizontal ones
+fn summarize(all_patterns: &[Grid<char>], wanted: CheckResult) -> usize {
+    let mut result = 0;
+
+    for p in all_patterns {
+        let transposed = p.transposed();
+        for i in 0..transposed.height() - 1 {
+            if check_mirror_at_row(&transposed, i) == wanted {
+                result += i + 1;
+            }
+        }
+        for i in 0..p.height() - 1 {
+            if check_mirror_at_row(p, i) == wanted {
+                result += (i + 1) * 100;
+            }
+        }
+    }
+
+    result
+}
+
+pub fn part1(all_patterns: &[Grid<char>]) -> usize {
+    summarize(all_patterns, CheckResult::Valid)
+}
+
+pub fn part2(all_patterns: &[Grid<char>]) -> usize {
+    summarize(all_patterns, CheckResult::AlmostValid)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Grid<char>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, patterns: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&patterns).into())
+    }
+
+    fn part_two(&self, patterns: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part2(&patterns).into())
+    }
+}
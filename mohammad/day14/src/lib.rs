@@ -0,0 +1,202 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_grid::{Grid, Point};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn tilt_north(grid: &mut Grid<char>, col: usize) {
+    let mut square_loc = 0;
+    let mut idx = 0;
+    let rows = grid.height();
+    while idx < rows {
+        match grid[Point::new(idx, col)] {
+            'O' if idx > 0 => {
+                for i in (square_loc..idx).rev() {
+                    match grid[Point::new(i, col)] {
+                        'O' | '#' => {
+                            grid.swap(Point::new(idx, col), Point::new(i + 1, col));
+                            idx = i + 1;
+                            break;
+                        }
+                        '.' if i == 0 => {
+                            grid.swap(Point::new(idx, col), Point::new(0, col));
+                            idx = i + 1;
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            '#' => square_loc = idx,
+            _ => {}
+        }
+        idx += 1;
+    }
+}
+
+fn tilt_south(grid: &mut Grid<char>, col: usize) {
+    let mut ridx = 0;
+    let rows = grid.height();
+    let mut square_loc = rows - 1;
+    while ridx < rows {
+        let idx = rows - 1 - ridx;
+        match grid[Point::new(idx, col)] {
+            'O' if idx < rows => {
+                for i in idx + 1..=square_loc {
+                    match grid[Point::new(i, col)] {
+                        'O' | '#' => {
+                            grid.swap(Point::new(idx, col), Point::new(i - 1, col));
+                            break;
+                        }
+                        '.' if i == rows - 1 => {
+                            grid.swap(Point::new(idx, col), Point::new(rows - 1, col));
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            '#' => square_loc = idx,
+            _ => {}
+        }
+        ridx += 1;
+    }
+}
+
+fn tilt_west(grid: &mut Grid<char>, row: usize) {
+    let mut square_loc = 0;
+    let mut idx = 0;
+    let cols = grid.width();
+    while idx < cols {
+        match grid[Point::new(row, idx)] {
+            'O' if idx > 0 => {
+                for j in (square_loc..idx).rev() {
+                    match grid[Point::new(row, j)] {
+                        'O' | '#' => {
+                            grid.swap(Point::new(row, idx), Point::new(row, j + 1));
+                            break;
+                        }
+                        '.' if j == 0 => {
+                            grid.swap(Point::new(row, idx), Point::new(row, 0));
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            '#' => square_loc = idx,
+            _ => {}
+        }
+        idx += 1;
+    }
+}
+
+fn tilt_east(grid: &mut Grid<char>, row: usize) {
+    let mut ridx = 0;
+    let cols = grid.width();
+    let mut square_loc = cols - 1;
+    while ridx < cols {
+        let idx = cols - 1 - ridx;
+        match grid[Point::new(row, idx)] {
+            'O' if idx < cols => {
+                for j in idx + 1..=square_loc {
+                    match grid[Point::new(row, j)] {
+                        'O' | '#' => {
+                            grid.swap(Point::new(row, idx), Point::new(row, j - 1));
+                            break;
+                        }
+                        '.' if j == cols - 1 => {
+                            grid.swap(Point::new(row, idx), Point::new(row, cols - 1));
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            '#' => square_loc = idx,
+            _ => {}
+        }
+        ridx += 1;
+    }
+}
+
+fn cycle(grid: &mut Grid<char>) {
+    for i in 0..grid.width() {
+        tilt_north(grid, i);
+    }
+    for i in 0..grid.height() {
+        tilt_west(grid, i);
+    }
+    for i in 0..grid.width() {
+        tilt_south(grid, i);
+    }
+    for i in 0..grid.height() {
+        tilt_east(grid, i);
+    }
+}
+
+fn calculate_load(grid: &Grid<char>) -> usize {
+    let rows = grid.height();
+    grid.iter()
+        .filter(|(_, c)| **c == 'O')
+        .map(|(p, _)| rows - p.row)
+        .sum()
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>, Diagnostic> {
+    Grid::parse(input)
+}
+
+pub fn part1(original_grid: &Grid<char>) -> usize {
+    let mut grid = original_grid.clone();
+    for i in 0..grid.width() {
+        tilt_north(&mut grid, i);
+    }
+    calculate_load(&grid)
+}
+
+pub fn part2(original_grid: &Grid<char>) -> usize {
+    let mut grid = original_grid.clone();
+    let mut hashes = Vec::new(); // to help look for patterns
+    let mut repeat: (usize, usize) = (0, 0);
+    for j in 0..1_000_000_000 {
+        let mut s = DefaultHasher::new();
+        grid.hash(&mut s);
+        let grid_hash = s.finish();
+        if let Some(start) = hashes.iter().position(|h| *h == grid_hash) {
+            repeat.0 = start; // Start of pattern
+            repeat.1 = j - start; // Length of pattern
+            break;
+        }
+        hashes.push(grid_hash);
+        cycle(&mut grid);
+    }
+
+    grid = original_grid.clone();
+    for _j in 0..repeat.0 {
+        cycle(&mut grid);
+    }
+
+    for _j in 0..(1_000_000_000 - repeat.0) % repeat.1 {
+        cycle(&mut grid);
+    }
+
+    calculate_load(&grid)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Grid<char>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, grid: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&grid).into())
+    }
+
+    fn part_two(&self, grid: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part2(&grid).into())
+    }
+}
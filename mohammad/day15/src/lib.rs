@@ -0,0 +1,88 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::{either, pair, preceded, tag, uint, word, Parser, Span};
+
+pub struct Step<'a> {
+    text: &'a str,
+    label: &'a str,
+    // `None` removes the lens
+    focal_length: Option<usize>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step<'_>>, Diagnostic> {
+    // `label-` removes a lens, `label=N` puts one in
+    let operation = either(tag("-").map(|_| None), preceded(tag("="), uint()).map(Some));
+    let step = pair(word(), operation);
+    let first_line = Span::new(input).lines().next().unwrap_or(Span::new(""));
+    let mut steps = Vec::new();
+    for s in first_line.split(',') {
+        let (label, focal_length) = step.parse_all(s)?;
+        steps.push(Step {
+            text: s.as_str(),
+            label,
+            focal_length,
+        });
+    }
+    Ok(steps)
+}
+
+fn hash(chars: &[char]) -> usize {
+    chars
+        .iter()
+        .fold(0, |acc, c| (17 * (acc + *c as usize)) % 256)
+}
+
+pub fn part1(steps: &[Step]) -> usize {
+    steps.iter().fold(0, |acc, s| {
+        acc + s
+            .text
+            .chars()
+            .fold(0, |acc, c| (17 * (acc + c as usize)) % 256)
+    })
+}
+
+pub fn part2(steps: &[Step]) -> Result<usize, SolutionError> {
+    let mut table: Vec<Vec<(&str, usize)>> = Vec::new();
+    table.resize(256, Vec::new());
+    for step in steps {
+        let label = step.label;
+        let hash = hash(&label.chars().collect::<Vec<_>>());
+        if let Some(focal_length) = step.focal_length {
+            if let Some(pos) = table[hash].iter().position(|(l, _)| *l == label) {
+                table[hash][pos] = (label, focal_length);
+            } else {
+                table[hash].push((label, focal_length));
+            }
+        } else {
+            table[hash].retain(|(l, _)| *l != label);
+        }
+    }
+
+    let mut power: usize = 0;
+    for (r#box, lenses) in table.iter().enumerate() {
+        for (slot, (_, focal_length)) in lenses.iter().enumerate() {
+            power = ((r#box + 1) * (slot + 1))
+                .checked_mul(*focal_length)
+                .and_then(|lens_power| power.checked_add(lens_power))
+                .ok_or_else(|| SolutionError::Failed("the focusing power overflows".into()))?;
+        }
+    }
+    Ok(power)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Step<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, steps: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&steps).into())
+    }
+
+    fn part_two(&self, steps: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part2(&steps)?.into())
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_grid::{Direction, Grid, Point};
+use std::{cmp::max, collections::HashSet};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Beam {
+    loc: Point,
+    dir: Direction,
+}
+
+fn energized(grid: &Grid<char>, start: &mut Beam) -> usize {
+    use Direction::{Down, Left, Right, Up};
+    let mut beams = vec![start.clone()];
+    let mut energized = HashSet::new();
+    let mut visited = HashSet::new();
+    while let Some(mut beam) = beams.pop() {
+        if visited.contains(&beam) {
+            continue;
+        }
+        loop {
+            visited.insert(beam.clone());
+            let loc = beam.loc;
+            energized.insert(loc);
+            match grid[loc] {
+                '\\' => {
+                    beam.dir = match beam.dir {
+                        Left => Up,
+                        Right => Down,
+                        Up => Left,
+                        Down => Right,
+                    };
+                }
+                '/' => {
+                    beam.dir = match beam.dir {
+                        Left => Down,
+                        Right => Up,
+                        Up => Right,
+                        Down => Left,
+                    };
+                }
+                '-' => {
+                    if let Up | Down = beam.dir {
+                        beams.push(Beam {
+                            loc: beam.loc,
+                            dir: Left,
+                        });
+                        beams.push(Beam {
+                            loc: beam.loc,
+                            dir: Right,
+                        });
+                        break;
+                    }
+                }
+                '|' => {
+                    if let Left | Right = beam.dir {
+                        beams.push(Beam {
+                            loc: beam.loc,
+                            dir: Up,
+                        });
+                        beams.push(Beam {
+                            loc: beam.loc,
+                            dir: Down,
+                        });
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            match grid.step(beam.loc, beam.dir) {
+                Some(next) => beam.loc = next,
+                None => break,
+            }
+        }
+    }
+    energized.len()
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>, Diagnostic> {
+    Grid::parse(input)
+}
+
+pub fn part1(grid: &Grid<char>) -> usize {
+    energized(
+        grid,
+        &mut Beam {
+            loc: Point::new(0, 0),
+            dir: Direction::Right,
+        },
+    )
+}
+
+pub fn part2(grid: &Grid<char>) -> usize {
+    let rows = grid.height();
+    let cols = grid.width();
+
+    let mut part_2_result = 0;
+    for i in 0..cols {
+        part_2_result = max(
+            part_2_result,
+            energized(
+                grid,
+                &mut Beam {
+                    loc: Point::new(0, i),
+                    dir: Direction::Down,
+                },
+            ),
+        );
+        part_2_result = max(
+            part_2_result,
+            energized(
+                grid,
+                &mut Beam {
+                    loc: Point::new(rows - 1, i),
+                    dir: Direction::Up,
+                },
+            ),
+        );
+    }
+    for i in 0..rows {
+        part_2_result = max(
+            part_2_result,
+            energized(
+                grid,
+                &mut Beam {
+                    loc: Point::new(i, 0),
+                    dir: Direction::Right,
+                },
+            ),
+        );
+        part_2_result = max(
+            part_2_result,
+            energized(
+                grid,
+                &mut Beam {
+                    loc: Point::new(i, cols - 1),
+                    dir: Direction::Left,
+                },
+            ),
+        );
+    }
+
+    part_2_result
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Grid<char>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, grid: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&grid).into())
+    }
+
+    fn part_two(&self, grid: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part2(&grid).into())
+    }
+}
@@ -0,0 +1,206 @@
+use aoc_core::{diagnostic, Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::Span;
+
+#[derive(Debug)]
+enum Direction {
+    R,
+    D,
+    U,
+    L,
+}
+
+#[derive(Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+struct Step<'a> {
+    line: Span<'a>,
+    direction: Direction,
+    distance: i64,
+}
+
+pub struct DigPlan<'a> {
+    steps: Vec<Step<'a>>,
+    // The colour is really five hex digits of distance and one of direction
+    colour_steps: Vec<Step<'a>>,
+}
+
+pub fn parse(input: &str) -> Result<DigPlan<'_>, Diagnostic> {
+    let mut steps = vec![];
+    let mut colour_steps = vec![];
+    for line in Span::new(input).lines() {
+        let split = line.as_str().split_ascii_whitespace().collect::<Vec<_>>();
+        let [direction, distance, colour] = split[..] else {
+            return Err(line.error("expected a direction, a distance and a colour"));
+        };
+
+        let direction = match direction {
+            "U" => Direction::U,
+            "D" => Direction::D,
+            "L" => Direction::L,
+            "R" => Direction::R,
+            _ => {
+                return Err(Diagnostic::new(
+                    input,
+                    direction,
+                    format!("expected U, D, L or R, found `{}`", direction),
+                ))
+            }
+        };
+        let distance: i64 = diagnostic::parse(input, distance)?;
+        steps.push(Step {
+            line,
+            direction,
+            distance,
+        });
+
+        let hex = colour
+            .strip_prefix("(#")
+            .and_then(|hex| hex.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.is_ascii());
+        let Some(hex) = hex else {
+            return Err(Diagnostic::new(
+                input,
+                colour,
+                "expected a colour like `(#70c710)`",
+            ));
+        };
+        let direction = match &hex[5..] {
+            "0" => Direction::R,
+            "1" => Direction::D,
+            "2" => Direction::L,
+            "3" => Direction::U,
+            found => {
+                return Err(Diagnostic::new(
+                    input,
+                    found,
+                    format!("expected 0, 1, 2 or 3, found `{}`", found),
+                ))
+            }
+        };
+        let distance = i64::from_str_radix(&hex[..5], 16).map_err(|err| {
+            Diagnostic::new(
+                input,
+                &hex[..5],
+                format!("unable to parse `{}`: {}", &hex[..5], err),
+            )
+        })?;
+        colour_steps.push(Step {
+            line,
+            direction,
+            distance,
+        });
+    }
+
+    if steps.is_empty() {
+        return Err(Diagnostic::at_end(input, "expected a dig plan"));
+    }
+
+    Ok(DigPlan {
+        steps,
+        colour_steps,
+    })
+}
+
+pub fn solution(dig_plan: &DigPlan, part: &Part) -> Result<i64, SolutionError> {
+    let plan = match part {
+        Part::One => &dig_plan.steps,
+        Part::Two => &dig_plan.colour_steps,
+    };
+
+    // Every step has to turn left or right from the one before it
+    let no_turn = |i: usize| {
+        plan[i]
+            .line
+            .error("expected a turn from the step before this one")
+    };
+    let too_far = |i: usize| {
+        plan[i]
+            .line
+            .error("this step goes too far from where the dig started")
+    };
+
+    let mut corners: Vec<(i64, i64)> = Vec::new();
+    let mut outer_corners: Vec<(i64, i64)> = Vec::new();
+    corners.push((0, 0));
+    outer_corners.push((0, 0));
+    let mut area: i64 = 0;
+    for i in 0..plan.len() - 1 {
+        let prev = *corners.last().unwrap();
+        let distance = plan[i].distance;
+        let add = |a: i64, b: i64| a.checked_add(b).ok_or_else(|| too_far(i));
+        let sub = |a: i64, b: i64| a.checked_sub(b).ok_or_else(|| too_far(i));
+        match &plan[i].direction {
+            Direction::R => {
+                let current = (prev.0, add(prev.1, distance)?);
+                corners.push(current);
+                match plan[i + 1].direction {
+                    Direction::D => outer_corners.push((current.0, add(current.1, 1)?)),
+                    Direction::U => outer_corners.push((current.0, current.1)),
+                    _ => return Err(no_turn(i + 1).into()),
+                }
+            }
+            Direction::L => {
+                let current = (prev.0, sub(prev.1, distance)?);
+                corners.push(current);
+                match plan[i + 1].direction {
+                    Direction::U => outer_corners.push((add(current.0, 1)?, current.1)),
+                    Direction::D => outer_corners.push((add(current.0, 1)?, add(current.1, 1)?)),
+                    _ => return Err(no_turn(i + 1).into()),
+                }
+            }
+            Direction::U => {
+                let current = (sub(prev.0, distance)?, prev.1);
+                corners.push(current);
+                match plan[i + 1].direction {
+                    Direction::R => outer_corners.push((current.0, current.1)),
+                    Direction::L => outer_corners.push((add(current.0, 1)?, current.1)),
+                    _ => return Err(no_turn(i + 1).into()),
+                }
+            }
+            Direction::D => {
+                let current = (add(prev.0, distance)?, prev.1);
+                corners.push(current);
+                match plan[i + 1].direction {
+                    Direction::L => outer_corners.push((add(current.0, 1)?, add(current.1, 1)?)),
+                    Direction::R => outer_corners.push((current.0, add(current.1, 1)?)),
+                    _ => return Err(no_turn(i + 1).into()),
+                }
+            }
+        };
+    }
+
+    // The corners can all fit while the area between them doesn't
+    let too_big = || SolutionError::Failed("the lagoon is too big to measure".into());
+    for i in 0..outer_corners.len() - 1 {
+        let (a, b) = (outer_corners[i], outer_corners[i + 1]);
+        area =
+            a.0.checked_mul(b.1)
+                .zip(b.0.checked_mul(a.1))
+                .and_then(|(ab, ba)| ab.checked_sub(ba))
+                .and_then(|term| area.checked_add(term))
+                .ok_or_else(too_big)?;
+    }
+
+    Ok(area.checked_abs().ok_or_else(too_big)? / 2)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = DigPlan<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, dig_plan: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&dig_plan, &Part::One)?.into())
+    }
+
+    fn part_two(&self, dig_plan: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(solution(&dig_plan, &Part::Two)?.into())
+    }
+}
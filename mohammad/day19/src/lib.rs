@@ -0,0 +1,169 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_parse::{
+    delimited, either, one_of, opt, pair, record, separated, tag, terminated, uint, word, Parser,
+    Span,
+};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum Category {
+    Cx,
+    Cm,
+    Ca,
+    Cs,
+}
+
+#[derive(Debug)]
+enum Rule {
+    LessThan {
+        lhs: Category,
+        rhs: usize,
+        next: String,
+    },
+    GreaterThan {
+        lhs: Category,
+        rhs: usize,
+        next: String,
+    },
+    Always(String),
+}
+
+const CATEGORIES: &[(&str, Category)] = &[
+    ("x", Category::Cx),
+    ("m", Category::Cm),
+    ("a", Category::Ca),
+    ("s", Category::Cs),
+];
+
+pub struct System {
+    workflows: HashMap<String, Vec<Rule>>,
+    parts: Vec<HashMap<Category, usize>>,
+}
+
+pub fn parse(input: &str) -> Result<System, Diagnostic> {
+    // `px{a<2006:qkq,m>2090:A,rfg}`
+    let comparison = either(tag("<"), tag(">"));
+    let condition = pair(
+        pair(one_of(CATEGORIES), comparison),
+        terminated(uint(), tag(":")),
+    );
+    let rule = pair(opt(condition), word());
+    let workflow = pair(
+        word(),
+        delimited(tag("{"), separated(rule, tag(",")), tag("}")),
+    );
+
+    let mut workflows: HashMap<String, Vec<Rule>> = HashMap::new();
+    let mut destinations = Vec::new();
+    let mut lines = Span::new(input).lines();
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        let (name, rules) = workflow.parse_all(line)?;
+
+        workflows.insert(
+            name.to_string(),
+            rules
+                .into_iter()
+                .map(|(condition, next)| {
+                    destinations.push(next);
+                    let next = next.to_string();
+                    match condition {
+                        Some(((lhs, "<"), rhs)) => Rule::LessThan { lhs, rhs, next },
+                        Some(((lhs, _), rhs)) => Rule::GreaterThan { lhs, rhs, next },
+                        None => Rule::Always(next),
+                    }
+                })
+                .collect(),
+        );
+    }
+
+    // Parts can only be sent to workflows that exist
+    if !workflows.contains_key("in") {
+        return Err(Diagnostic::at_end(input, "expected a workflow named `in`"));
+    }
+    if let Some(missing) = destinations
+        .into_iter()
+        .find(|next| !matches!(*next, "A" | "R") && !workflows.contains_key(*next))
+    {
+        return Err(Diagnostic::new(
+            input,
+            missing,
+            format!("workflow `{}` is never defined", missing),
+        ));
+    }
+
+    // `{x=787,m=2655,a=1222,s=2876}`
+    let ratings = delimited(tag("{"), record(one_of(CATEGORIES), uint()), tag("}"));
+    let mut all_parts = Vec::new();
+    for line in lines {
+        let parts: HashMap<Category, usize> = ratings.parse_all(line)?.into_iter().collect();
+        if parts.len() != 4 {
+            return Err(line.error("expected ratings for each of x, m, a and s"));
+        }
+        all_parts.push(parts);
+    }
+
+    Ok(System {
+        workflows,
+        parts: all_parts,
+    })
+}
+
+pub fn part1(system: &System) -> Result<usize, SolutionError> {
+    let System {
+        workflows,
+        parts: all_parts,
+    } = system;
+
+    let mut part_1_result: usize = 0;
+    for parts in all_parts {
+        let mut current = "in";
+        let mut visited = HashSet::new(); // A part sent back to a workflow would never leave
+        loop {
+            if current == "A" {
+                part_1_result = parts
+                    .values()
+                    .try_fold(part_1_result, |sum, rating| sum.checked_add(*rating))
+                    .ok_or_else(|| {
+                        SolutionError::Failed("the ratings are too big to add up".into())
+                    })?;
+                break;
+            } else if current == "R" {
+                break;
+            }
+            if !visited.insert(current) {
+                return Err(SolutionError::Failed(
+                    format!("a part is sent back to workflow `{}` forever", current).into(),
+                ));
+            }
+            let next = workflows[current].iter().find_map(|r| match r {
+                Rule::GreaterThan { lhs, rhs, next } => (parts[lhs] > *rhs).then_some(next),
+                Rule::LessThan { lhs, rhs, next } => (parts[lhs] < *rhs).then_some(next),
+                Rule::Always(next) => Some(next),
+            });
+            current = next.ok_or_else(|| {
+                SolutionError::Failed(
+                    format!("no rule in workflow `{}` applies to a part", current).into(),
+                )
+            })?;
+        }
+    }
+
+    Ok(part_1_result)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = System;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, system: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&system)?.into())
+    }
+}
@@ -0,0 +1,52 @@
+use aoc_core::{Answer, Diagnostic, Solution, SolutionError};
+use aoc_grid::{Grid, Point};
+use std::collections::HashSet;
+
+const STEPS: usize = 64;
+
+fn neighbors(p: Point, grid: &Grid<char>) -> HashSet<Point> {
+    grid.neighbors4(p).filter(|n| grid[*n] != '#').collect()
+}
+
+pub struct Garden {
+    grid: Grid<char>,
+    start: Point,
+}
+
+pub fn parse(input: &str) -> Result<Garden, Diagnostic> {
+    let grid = Grid::parse(input)?;
+    let Some(start) = grid.position(|c| *c == 'S') else {
+        return Err(Diagnostic::at_end(input, "expected a starting point `S`"));
+    };
+
+    Ok(Garden { grid, start })
+}
+
+pub fn part1(garden: &Garden) -> usize {
+    let Garden { grid, start } = garden;
+
+    let mut front = neighbors(*start, grid);
+    for _s in 1..STEPS {
+        let mut next_front = HashSet::new();
+        for f in front {
+            next_front.extend(neighbors(f, grid));
+        }
+        front = next_front;
+    }
+
+    front.len()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Garden;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, SolutionError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, garden: Self::Input<'_>) -> Result<Answer, SolutionError> {
+        Ok(part1(&garden).into())
+    }
+}